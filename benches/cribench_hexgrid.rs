@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate criterion;
+extern crate chickenwire;
+
+use criterion::Criterion;
+use criterion::black_box;
+
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+/// Builds a hexagon of the given radius, walling about the given percentage
+/// of hexes.
+fn maze(radius: u32, percent: u64) -> HexGrid<bool> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut grid = HexGrid::new(Tilt::Flat, Parity::Odd, CoordSys::Cube);
+
+    for cube in Cube::<i32>::ORIGIN.spiral(radius) {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        grid.set(MultiCoord::from(cube), state % 100 >= percent);
+    }
+
+    grid
+}
+
+fn bench_paths(c: &mut Criterion, name: &str, percent: u64) {
+    let start = MultiCoord::cube(-40, 20, 20).unwrap();
+    let goal = MultiCoord::cube(40, -20, -20).unwrap();
+    let mut grid = maze(40, percent);
+
+    grid.set(goal, true);
+
+    let copy = grid.map(|_, &open| open);
+
+    c.bench_function(&format!("find_path {}", name), move |b| {
+        b.iter(|| {
+            copy.find_path(black_box(start), black_box(goal), |_, &open| {
+                if open {
+                    Some(1)
+                } else {
+                    None
+                }
+            })
+        })
+    });
+
+    c.bench_function(&format!("find_path_uniform {}", name), move |b| {
+        b.iter(|| {
+            grid.find_path_uniform(
+                black_box(start),
+                black_box(goal),
+                |_, &open| open,
+            )
+        })
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_paths(c, "open", 0);
+    bench_paths(c, "scattered", 15);
+    bench_paths(c, "dense", 30);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,3 +1,42 @@
-fn main() {
-    unimplemented!();
-}
\ No newline at end of file
+//! Renders a small island map to `render_example.ppm`, and also to
+//! `render_example.png` when built with `--features image`.
+
+use std::fs::File;
+use std::io::BufWriter;
+
+use chickenwire::hexgrid::Tilt;
+use chickenwire::prelude::*;
+use chickenwire::raster::Layout;
+
+fn main() -> std::io::Result<()> {
+    let mut grid = HexGrid::new_radial(5, 0u32);
+
+    grid.tilt = Tilt::Sharp;
+
+    for coord in Cube::<i32>::ORIGIN.spiral(4) {
+        let dist = Cube::<i32>::ORIGIN.dist(coord) as u32;
+
+        grid.set(MultiCoord::from(coord), 4 - dist.min(4));
+    }
+
+    let layout = Layout::new(24.0, (240.0, 220.0));
+    let raster = grid.render_raster(480, 440, &layout, |&height| {
+        match height {
+            0 => [30, 80, 160, 255],
+            1 => [220, 200, 140, 255],
+            2 | 3 => [70, 140, 60, 255],
+            _ => [120, 120, 120, 255],
+        }
+    });
+
+    let ppm = BufWriter::new(File::create("render_example.ppm")?);
+
+    raster.write_ppm(ppm, [255, 255, 255])?;
+
+    #[cfg(feature = "image")]
+    raster
+        .save_png("render_example.png")
+        .map_err(std::io::Error::other)?;
+
+    Ok(())
+}
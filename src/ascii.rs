@@ -0,0 +1,283 @@
+//! ASCII Art
+//!
+//! A `HexGrid` can be drawn as staggered text with one character per hex,
+//! which makes small grids easy to inspect and to write as test fixtures.
+//!
+//! Hexes in a `Tilt::Sharp` grid sit two characters apart along each line,
+//! and each row matching the grid's `Parity` is shoved one character to the
+//! right. Hexes in a `Tilt::Flat` grid sit two lines apart down each column,
+//! and each column matching the grid's `Parity` is shoved one line down.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//! use chickenwire::hexgrid::{Parity, Tilt};
+//!
+//! let text = "\
+//! a b c
+//!  d . f
+//! ";
+//!
+//! let grid = HexGrid::from_ascii(text, Tilt::Sharp, Parity::Odd, |c| {
+//!     if c == '.' { None } else { Some(c) }
+//! }).unwrap();
+//!
+//! assert_eq!(grid.get(MultiCoord::offset(2, 1)), Some(&'f'));
+//! assert!(!grid.contains_coord(MultiCoord::offset(1, 1)));
+//! assert_eq!(grid.to_ascii(|&c| c), text);
+//! ```
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+/// Character drawn by `HexGrid::to_ascii` wherever the grid has no hex.
+const EMPTY: char = '.';
+
+/// Returns `true` if the row or column at `index` is shoved under `parity`.
+fn shoved(index: i64, parity: Parity) -> bool {
+    (index & 1 == 1) == (parity == Parity::Odd)
+}
+
+/// Returns the (line, character) position at which the hex at `col` and
+/// `row` is drawn, relative to the given minimum column and row.
+fn position(
+    tilt: Tilt,
+    parity: Parity,
+    (col, row): (i64, i64),
+    (min_col, min_row): (i64, i64),
+) -> (usize, usize) {
+    let (line, column) = match tilt {
+        Tilt::Flat => {
+            let shift = shoved(col, parity) as i64;
+
+            (2 * (row - min_row) + shift, 2 * (col - min_col))
+        }
+        Tilt::Sharp => {
+            let shift = shoved(row, parity) as i64;
+
+            (row - min_row, 2 * (col - min_col) + shift)
+        }
+    };
+
+    (line as usize, column as usize)
+}
+
+impl<T> HexGrid<T> {
+    /// Draws the calling instance as staggered text, using `cell` to pick
+    /// the character for each hex. Positions within the grid's bounds that
+    /// hold no hex are drawn as `.`, and every line ends with a newline.
+    ///
+    /// The top-left hex of the output is the grid's minimum `Offset`
+    /// coordinate, rounded down to an even index along the staggered axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::hexgrid::Parity;
+    ///
+    /// let mut grid = HexGrid::new_boxy(3, 2, 0);
+    ///
+    /// grid.parity = Parity::Even;
+    /// grid.set(MultiCoord::offset(1, 1), 1);
+    ///
+    /// assert_eq!(
+    ///     grid.to_ascii(|&value| if value == 0 { 'o' } else { '#' }),
+    ///     "  o\no   o\n  #\no   o\n"
+    /// );
+    /// ```
+    pub fn to_ascii<F>(&self, cell: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let hexes: Vec<((i64, i64), char)> = self
+            .iter()
+            .map(|(coord, value)| {
+                let offset = Offset::from(
+                    self.convert(coord, CoordSys::Offset)
+                );
+
+                ((i64::from(offset.col), i64::from(offset.row)), cell(value))
+            })
+            .collect();
+
+        if hexes.is_empty() {
+            return String::new();
+        }
+
+        let cols = hexes.iter().map(|&((col, _), _)| col);
+        let rows = hexes.iter().map(|&((_, row), _)| row);
+        let (min_col, max_col) = (cols.clone().min(), cols.max());
+        let (min_row, max_row) = (rows.clone().min(), rows.max());
+        let (min_col, max_col) = (min_col.unwrap(), max_col.unwrap());
+        let (min_row, max_row) = (min_row.unwrap(), max_row.unwrap());
+
+        // Starting on an even row or column keeps the stagger intact, and
+        // spanning at least two columns keeps a flat grid's first line from
+        // being blank.
+        let (min_col, max_col, min_row) = match self.tilt {
+            Tilt::Flat => {
+                let min_col = min_col - (min_col & 1);
+
+                (min_col, max_col.max(min_col + 1), min_row)
+            }
+            Tilt::Sharp => (min_col, max_col, min_row - (min_row & 1)),
+        };
+
+        let mut lines: Vec<Vec<char>> = Vec::new();
+        let mut draw = |(line, column): (usize, usize), c: char| {
+            if lines.len() <= line {
+                lines.resize(line + 1, Vec::new());
+            }
+            if lines[line].len() <= column {
+                lines[line].resize(column + 1, ' ');
+            }
+            lines[line][column] = c;
+        };
+
+        let origin = (min_col, min_row);
+
+        for col in min_col..=max_col {
+            for row in min_row..=max_row {
+                let at = position(self.tilt, self.parity, (col, row), origin);
+
+                draw(at, EMPTY);
+            }
+        }
+
+        for (at, c) in hexes {
+            draw(position(self.tilt, self.parity, at, origin), c);
+        }
+
+        let mut text = String::new();
+
+        for line in lines {
+            text.extend(line);
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Reads a `HexGrid` from staggered text in the form drawn by
+    /// `to_ascii`, using `cell` to turn each character into a value. The
+    /// top-left hex of the text becomes `Offset` coordinate (0, 0), and the
+    /// returned grid uses `CoordSys::Offset`.
+    ///
+    /// Blank leading and trailing lines are ignored, as is indentation
+    /// common to every line, which is removed two characters at a time so
+    /// that rows keep their stagger. Whitespace, or any character for which
+    /// `cell` returns `None`, leaves its hex empty.
+    ///
+    /// Returns a `Result::Err(String)` if a non-whitespace character sits
+    /// between hex positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::hexgrid::{Parity, Tilt};
+    ///
+    /// let grid = HexGrid::from_ascii("
+    ///     1   3
+    ///       2
+    /// ", Tilt::Flat, Parity::Odd, |c| c.to_digit(10)).unwrap();
+    ///
+    /// assert_eq!(grid.get(MultiCoord::offset(1, 0)), Some(&2));
+    /// assert_eq!(grid.get(MultiCoord::offset(2, 0)), Some(&3));
+    ///
+    /// assert!(
+    ///     HexGrid::from_ascii("1 2\n 3x4", Tilt::Sharp, Parity::Odd, |c| {
+    ///         c.to_digit(10)
+    ///     }).is_err()
+    /// );
+    /// ```
+    pub fn from_ascii<F>(
+        text: &str,
+        tilt: Tilt,
+        parity: Parity,
+        cell: F,
+    ) -> Result<Self, String>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut grid = Self::new(tilt, parity, CoordSys::Offset);
+        let lines: Vec<&str> = text.lines().collect();
+        let is_blank = |line: &&str| line.trim().is_empty();
+
+        let first = match lines.iter().position(|line| !is_blank(line)) {
+            Some(first) => first,
+            None => return Result::Ok(grid),
+        };
+        let last = lines.iter().rposition(|line| !is_blank(line)).unwrap();
+
+        let indent = lines[first..=last]
+            .iter()
+            .filter(|line| !is_blank(line))
+            .map(|line| line.chars().take_while(|c| c.is_whitespace()).count())
+            .min()
+            .unwrap();
+        let indent = indent - indent % 2;
+
+        for (line_index, line) in lines[first..=last].iter().enumerate() {
+            for (char_index, c) in line.chars().skip(indent).enumerate() {
+                if c.is_whitespace() {
+                    continue;
+                }
+
+                let line_at = line_index as i64;
+                let char_at = char_index as i64;
+
+                let hex = match tilt {
+                    Tilt::Flat => {
+                        let col = char_at / 2;
+                        let shift = shoved(col, parity) as i64;
+
+                        if char_at % 2 == 0
+                            && line_at >= shift
+                            && (line_at - shift) % 2 == 0
+                        {
+                            Some((col, (line_at - shift) / 2))
+                        } else {
+                            None
+                        }
+                    }
+                    Tilt::Sharp => {
+                        let shift = shoved(line_at, parity) as i64;
+
+                        if char_at >= shift && (char_at - shift) % 2 == 0 {
+                            Some(((char_at - shift) / 2, line_at))
+                        } else {
+                            None
+                        }
+                    }
+                };
+
+                match hex {
+                    Some((col, row)) => {
+                        if let Some(value) = cell(c) {
+                            let offset = MultiCoord::offset(
+                                col as i32,
+                                row as i32,
+                            );
+
+                            grid.set(offset, value);
+                        }
+                    }
+                    None => {
+                        return Result::Err(format!(
+                            "Unexpected {:?} between hexes at line {}, \
+                             column {}",
+                            c,
+                            first + line_index + 1,
+                            indent + char_index + 1,
+                        ));
+                    }
+                }
+            }
+        }
+
+        Result::Ok(grid)
+    }
+}
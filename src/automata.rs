@@ -0,0 +1,264 @@
+//! Cellular Automata
+//!
+//! `HexGrid::step` advances every hex of a grid at once, computing each
+//! hex's next value from its current value and those of its neighbors. All
+//! next values are computed before any are written, so a rule only ever sees
+//! the previous generation.
+//!
+//! Neighbors are given in the order of `Cube::neighbors`, so neighbor `i`
+//! lies in the direction `Compass::from_neighbor(grid.tilt, i)`. Rules can
+//! also see the second ring of `Cube::diagonals`, and hexes missing from the
+//! grid can be treated as absent, as a constant, or wrapped around the
+//! grid's edges.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! // Fire spreads to any tree next to a burning hex, and burns out.
+//! let mut forest = HexGrid::new_radial(3, 'T');
+//!
+//! forest.set(MultiCoord::axial(0, 0), '*');
+//!
+//! let rule = |&hex: &char, neighbors: &[Option<&char>; 6]| match hex {
+//!     '*' => '.',
+//!     'T' if neighbors.iter().any(|&n| n == Some(&'*')) => '*',
+//!     other => other,
+//! };
+//!
+//! forest.step(rule);
+//! assert_eq!(forest.iter().filter(|&(_, &c)| c == '*').count(), 6);
+//!
+//! forest.step(rule);
+//! assert_eq!(forest.iter().filter(|&(_, &c)| c == '*').count(), 12);
+//! assert_eq!(forest.iter().filter(|&(_, &c)| c == '.').count(), 7);
+//! ```
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Options
+//////////////////////////////////////////////////////////////////////////////
+
+/// How neighbors missing from a grid are seen by a rule.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Boundary<T> {
+    /// Missing neighbors are `None`.
+    #[default]
+    Absent,
+    /// Missing neighbors hold the given value.
+    Constant(T),
+    /// Missing neighbors wrap around the grid's `Offset` bounding box, as on
+    /// a torus. Neighbors still missing after wrapping are `None`. Wrapping
+    /// only keeps hexes adjacent across the seam when the grid spans an
+    /// even number of columns (`Tilt::Flat`) or rows (`Tilt::Sharp`).
+    Wrap,
+}
+
+/// Options for `HexGrid::step_with`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StepOptions<T> {
+    pub boundary: Boundary<T>,
+    /// Whether rules see the six diagonal hexes as well.
+    pub diagonals: bool,
+}
+
+impl<T> Default for StepOptions<T> {
+    fn default() -> Self {
+        Self {
+            boundary: Boundary::default(),
+            diagonals: false,
+        }
+    }
+}
+
+/// The hexes around the one being stepped.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Neighborhood<'a, T> {
+    /// Adjacent hexes, in the order of `Cube::neighbors`.
+    pub neighbors: [Option<&'a T>; 6],
+    /// Diagonal hexes, in the order of `Cube::diagonals`. Always `None`
+    /// unless diagonals are enabled.
+    pub diagonals: [Option<&'a T>; 6],
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    /// Returns the number of adjacent and diagonal hexes for which `pred`
+    /// returns `true`.
+    pub fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.neighbors
+            .iter()
+            .chain(self.diagonals.iter())
+            .filter(|hex| hex.is_some_and(&mut pred))
+            .count()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Stepping
+//////////////////////////////////////////////////////////////////////////////
+
+/// The `Offset` bounding box of a grid, as its minimum and size.
+struct Bounds {
+    min: (i32, i32),
+    size: (i32, i32),
+}
+
+impl<T> HexGrid<T> {
+    /// Advances every hex by one generation, treating missing neighbors as
+    /// absent. Changes are tracked and raise events just like `set`.
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: Fn(&T, &[Option<&T>; 6]) -> T,
+    {
+        self.step_with(&StepOptions::default(), |hex, around| {
+            rule(hex, &around.neighbors)
+        });
+    }
+
+    /// Advances every hex by one generation with the given options. Changes
+    /// are tracked and raise events just like `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::automata::{Boundary, StepOptions};
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_boxy(4, 4, 0);
+    /// let options = StepOptions {
+    ///     boundary: Boundary::Wrap,
+    ///     diagonals: true,
+    /// };
+    ///
+    /// grid.step_with(&options, |_, around| around.count(|_| true));
+    ///
+    /// // Wrapping gives every hex a full neighborhood.
+    /// assert!(grid.iter().all(|(_, &count)| count == 12));
+    /// ```
+    pub fn step_with<F>(&mut self, options: &StepOptions<T>, rule: F)
+    where
+        F: Fn(&T, &Neighborhood<T>) -> T,
+    {
+        let bounds = match options.boundary {
+            Boundary::Wrap => self.offset_bounds(),
+            _ => None,
+        };
+        let next: Vec<(MultiCoord, T)> = self
+            .iter()
+            .map(|(coord, value)| {
+                let cube = self.cube_of(coord);
+                let ring = |hexes: Vec<Cube>| {
+                    let mut ring = [None; 6];
+
+                    for (slot, hex) in ring.iter_mut().zip(hexes) {
+                        *slot = self.boundary_get(hex, options, &bounds);
+                    }
+
+                    ring
+                };
+                let around = Neighborhood {
+                    neighbors: ring(cube.neighbors()),
+                    diagonals: if options.diagonals {
+                        ring(cube.diagonals())
+                    } else {
+                        [None; 6]
+                    },
+                };
+
+                (coord, rule(value, &around))
+            })
+            .collect();
+
+        for (coord, value) in next {
+            self.set(coord, value);
+        }
+    }
+
+    /// Advances every hex by the given number of generations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::automata::StepOptions;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_radial(4, 0u32);
+    ///
+    /// grid.set(MultiCoord::axial(0, 0), 1);
+    /// grid.run(3, &StepOptions::default(), |&hex, around| {
+    ///     let lit = around.count(|&n| n == 1);
+    ///
+    ///     if lit > 0 { 1 } else { hex }
+    /// });
+    ///
+    /// assert_eq!(grid[MultiCoord::axial(3, 0)], 1);
+    /// assert_eq!(grid[MultiCoord::axial(4, 0)], 0);
+    /// ```
+    pub fn run<F>(&mut self, steps: usize, options: &StepOptions<T>, rule: F)
+    where
+        F: Fn(&T, &Neighborhood<T>) -> T,
+    {
+        for _ in 0..steps {
+            self.step_with(options, &rule);
+        }
+    }
+
+    /// Returns the bounding box of the grid's `Offset` coordinates, or
+    /// `None` for an empty grid.
+    fn offset_bounds(&self) -> Option<Bounds> {
+        let mut offsets = self.iter().map(|(coord, _)| {
+            Offset::from(self.convert(coord, CoordSys::Offset))
+        });
+        let first = offsets.next()?;
+        let mut min = (first.col, first.row);
+        let mut max = min;
+
+        for Offset { col, row } in offsets {
+            min = (min.0.min(col), min.1.min(row));
+            max = (max.0.max(col), max.1.max(row));
+        }
+
+        Some(Bounds {
+            min,
+            size: (max.0 - min.0 + 1, max.1 - min.1 + 1),
+        })
+    }
+
+    /// Returns the value a rule sees for a hex, applying the boundary policy
+    /// if it's missing.
+    fn boundary_get<'a>(
+        &'a self,
+        hex: Cube,
+        options: &'a StepOptions<T>,
+        bounds: &Option<Bounds>,
+    ) -> Option<&'a T> {
+        let coord = MultiCoord::from(hex);
+
+        if let Some(value) = self.get(coord) {
+            return Some(value);
+        }
+
+        match (&options.boundary, bounds) {
+            (Boundary::Constant(value), _) => Some(value),
+            (Boundary::Wrap, Some(bounds)) => {
+                let Offset { col, row } =
+                    Offset::from(self.convert(coord, CoordSys::Offset));
+                let wrapped = MultiCoord::offset(
+                    (col - bounds.min.0).rem_euclid(bounds.size.0)
+                        + bounds.min.0,
+                    (row - bounds.min.1).rem_euclid(bounds.size.1)
+                        + bounds.min.1,
+                );
+
+                self.get(wrapped)
+            }
+            _ => None,
+        }
+    }
+}
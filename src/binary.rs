@@ -0,0 +1,694 @@
+//! Binary Map Format
+//!
+//! A compact, versioned encoding for `HexGrid`s. Values are written by a
+//! user-supplied `Codec`, and runs of equal values are stored once.
+//!
+//! # Layout
+//!
+//! All fixed-width integers are little-endian, and `varint`s are unsigned
+//! LEB128. Signed `varint`s are zigzag encoded first.
+//!
+//! | Field     | Encoding                                                  |
+//! |-----------|-----------------------------------------------------------|
+//! | magic     | the bytes `CHWM`                                          |
+//! | version   | `u8`, currently 1                                         |
+//! | tilt      | `u8`: 0 for `Flat`, 1 for `Sharp`                         |
+//! | parity    | `u8`: 0 for `Even`, 1 for `Odd`                           |
+//! | sys       | `u8`: 0 to 3 for `Axial`, `Cube`, `Double`, and `Offset`  |
+//! | shape     | `u8`, followed by the shape's bounds (see below)          |
+//! | values    | runs of a `varint` length followed by one encoded value   |
+//!
+//! Grids filling a rectangle of `Offset` coordinates use the boxy shape (1),
+//! bounded by the `i32` minimum column and row and the `u32` number of
+//! columns and rows. Their values run row by row. Grids filling a hexagon
+//! use the radial shape (2), bounded by the `i32` x, y, and z of the center
+//! and the `u32` radius. Their values run in `Cube::spiral` order. Any other
+//! grid uses the sparse shape (0), bounded by a `varint` number of hexes and
+//! followed by each hex's coordinate in the grid's `sys`, stored as signed
+//! `varint` differences from the previous coordinate. Their values run in
+//! the same order as the coordinates.
+//!
+//! A map holds at most `u32::MAX` hexes. `HexGrid::from_bytes` decodes at
+//! most `MAX_HEXES`, since the boxy and radial shapes can claim billions of
+//! hexes in a few bytes; `HexGrid::from_bytes_limited` takes another limit.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::binary::IntCodec;
+//! use chickenwire::prelude::*;
+//!
+//! let grid = HexGrid::new_boxy(40, 30, 7u16);
+//! let bytes = grid.to_bytes(&IntCodec);
+//!
+//! assert_eq!(bytes.len(), 29);
+//!
+//! let decoded: HexGrid<u16> =
+//!     HexGrid::from_bytes(&bytes, &IntCodec).unwrap();
+//!
+//! assert_eq!(decoded.iter().count(), 1200);
+//! assert_eq!(decoded.get(MultiCoord::offset(39, 29)), Some(&7));
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+/// Bytes opening every encoded map.
+const MAGIC: &[u8; 4] = b"CHWM";
+
+/// Version of the format written by `HexGrid::to_bytes`.
+pub const VERSION: u8 = 1;
+
+/// Most hexes `HexGrid::from_bytes` decodes before rejecting a map.
+pub const MAX_HEXES: u64 = 1 << 24;
+
+/// Shape tags.
+const SPARSE: u8 = 0;
+const BOXY: u8 = 1;
+const RADIAL: u8 = 2;
+
+//////////////////////////////////////////////////////////////////////////////
+// Codecs
+//////////////////////////////////////////////////////////////////////////////
+
+/// Converts hex values to and from bytes.
+pub trait Codec<T> {
+    /// Appends the encoding of `value` to `out`.
+    fn encode(&self, value: &T, out: &mut Vec<u8>);
+
+    /// Decodes a value from the front of `input`, advancing it past the
+    /// bytes used. Returns `None` if the bytes don't hold a valid value.
+    fn decode(&self, input: &mut &[u8]) -> Option<T>;
+}
+
+/// A `Codec` storing primitive integers as little-endian bytes.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntCodec;
+
+macro_rules! impl_int_codec {
+    ($($int:ty),*) => {$(
+        impl Codec<$int> for IntCodec {
+            fn encode(&self, value: &$int, out: &mut Vec<u8>) {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+
+            fn decode(&self, input: &mut &[u8]) -> Option<$int> {
+                const SIZE: usize = std::mem::size_of::<$int>();
+
+                if input.len() < SIZE {
+                    return None;
+                }
+
+                let mut bytes = [0; SIZE];
+
+                bytes.copy_from_slice(&input[..SIZE]);
+                *input = &input[SIZE..];
+
+                Some(<$int>::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+impl_int_codec!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+//////////////////////////////////////////////////////////////////////////////
+// Shapes
+//////////////////////////////////////////////////////////////////////////////
+
+/// The layout of a grid's hexes, which decides the order of its values.
+enum Shape {
+    Sparse(Vec<MultiCoord>),
+    Boxy { min_col: i32, min_row: i32, cols: u32, rows: u32 },
+    Radial { center: Cube, radius: u32 },
+}
+
+/// Returns the values making up a coordinate in its own system.
+fn components(coord: MultiCoord) -> Vec<i32> {
+    match CoordSys::from(coord) {
+        CoordSys::Axial => {
+            let axial = Axial::from(coord);
+
+            vec![axial.q, axial.r]
+        }
+        CoordSys::Cube => {
+            let (x, y, z) = Cube::from(coord).to_tuple();
+
+            vec![x, y, z]
+        }
+        CoordSys::Double => {
+            let double = Double::from(coord);
+
+            vec![double.col(), double.row()]
+        }
+        CoordSys::Offset => {
+            let offset = Offset::from(coord);
+
+            vec![offset.col, offset.row]
+        }
+    }
+}
+
+/// Returns the smallest and largest of the given values.
+fn span<I: Iterator<Item = i32>>(values: I) -> (i64, i64) {
+    values.fold((i64::MAX, i64::MIN), |(min, max), value| {
+        (min.min(i64::from(value)), max.max(i64::from(value)))
+    })
+}
+
+/// Returns the coordinate `index` columns or rows past `min`. The result
+/// must fit in `i32`.
+fn nth(min: i32, index: u32) -> i32 {
+    (i64::from(min) + i64::from(index)) as i32
+}
+
+/// Returns the number of hexes in a hexagon of the given radius.
+fn radial_count(radius: u64) -> u64 {
+    3 * radius * (radius + 1) + 1
+}
+
+impl<T> HexGrid<T> {
+    /// Picks the most compact shape for the grid's hexes.
+    fn shape(&self) -> Shape {
+        let cubes: Vec<Cube> = self
+            .iter()
+            .map(|(coord, _)| self.cube_of(coord))
+            .collect();
+        let count = cubes.len() as u64;
+
+        if count == 0 {
+            return Shape::Sparse(Vec::new());
+        }
+
+        let offsets: Vec<Offset> = cubes
+            .iter()
+            .map(|&cube| {
+                Offset::from(
+                    self.convert(MultiCoord::from(cube), CoordSys::Offset)
+                )
+            })
+            .collect();
+        let (min_col, max_col) = span(offsets.iter().map(|o| o.col));
+        let (min_row, max_row) = span(offsets.iter().map(|o| o.row));
+        let (cols, rows) = (max_col - min_col + 1, max_row - min_row + 1);
+
+        if (cols as u64).checked_mul(rows as u64) == Some(count) {
+            return Shape::Boxy {
+                min_col: min_col as i32,
+                min_row: min_row as i32,
+                cols: cols as u32,
+                rows: rows as u32,
+            };
+        }
+
+        let (min_x, max_x) = span(cubes.iter().map(|c| c.x()));
+        let (min_y, max_y) = span(cubes.iter().map(|c| c.y()));
+        let (min_z, max_z) = span(cubes.iter().map(|c| c.z()));
+        let diameter = max_x - min_x;
+
+        // A hexagon spans the same, even distance along every axis, and its
+        // bounds hold exactly as many hexes as it does.
+        if diameter % 2 == 0
+            && max_y - min_y == diameter
+            && max_z - min_z == diameter
+            && radial_count(diameter as u64 / 2) == count
+        {
+            let center = Cube::from_coords(
+                ((min_x + max_x) / 2) as i32,
+                ((min_y + max_y) / 2) as i32,
+                ((min_z + max_z) / 2) as i32,
+            );
+
+            if let Ok(center) = center {
+                return Shape::Radial {
+                    center,
+                    radius: (diameter / 2) as u32,
+                };
+            }
+        }
+
+        let mut coords: Vec<MultiCoord> =
+            self.iter().map(|(coord, _)| coord).collect();
+
+        coords.sort_by_key(|&coord| components(coord));
+        Shape::Sparse(coords)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Writing
+//////////////////////////////////////////////////////////////////////////////
+
+fn push_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn push_signed(out: &mut Vec<u8>, value: i64) {
+    push_varint(out, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+impl<T> HexGrid<T> {
+    /// Encodes the calling instance in the binary map format, using `codec`
+    /// to encode each value. Equal grids always produce equal bytes.
+    pub fn to_bytes<C: Codec<T>>(&self, codec: &C) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+
+        out.push(VERSION);
+        out.push(match self.tilt {
+            Tilt::Flat => 0,
+            Tilt::Sharp => 1,
+        });
+        out.push(match self.parity {
+            Parity::Even => 0,
+            Parity::Odd => 1,
+        });
+        out.push(match self.sys {
+            CoordSys::Axial => 0,
+            CoordSys::Cube => 1,
+            CoordSys::Double => 2,
+            CoordSys::Offset => 3,
+        });
+
+        let order: Vec<MultiCoord> = match self.shape() {
+            Shape::Sparse(coords) => {
+                out.push(SPARSE);
+                push_varint(&mut out, coords.len() as u64);
+
+                let mut previous = [0i64; 3];
+
+                for &coord in coords.iter() {
+                    for (i, value) in components(coord).into_iter().enumerate()
+                    {
+                        let delta = i64::from(value) - previous[i];
+
+                        push_signed(&mut out, delta);
+                        previous[i] = i64::from(value);
+                    }
+                }
+
+                coords
+            }
+            Shape::Boxy { min_col, min_row, cols, rows } => {
+                out.push(BOXY);
+                out.extend_from_slice(&min_col.to_le_bytes());
+                out.extend_from_slice(&min_row.to_le_bytes());
+                out.extend_from_slice(&cols.to_le_bytes());
+                out.extend_from_slice(&rows.to_le_bytes());
+
+                let mut coords = Vec::new();
+
+                for row in 0..rows {
+                    for col in 0..cols {
+                        coords.push(MultiCoord::offset(
+                            nth(min_col, col),
+                            nth(min_row, row),
+                        ));
+                    }
+                }
+
+                coords
+            }
+            Shape::Radial { center, radius } => {
+                out.push(RADIAL);
+                for &value in [center.x(), center.y(), center.z()].iter() {
+                    out.extend_from_slice(&value.to_le_bytes());
+                }
+                out.extend_from_slice(&radius.to_le_bytes());
+
+                let coords = center.spiral(radius).into_iter();
+
+                coords.map(MultiCoord::from).collect()
+            }
+        };
+
+        // Offset coordinates are only meaningful with the grid's layout, so
+        // every coordinate is resolved through `get`.
+        let mut run: Option<(u64, Vec<u8>)> = None;
+
+        for coord in order {
+            let value = self.get(coord).expect("shape covers only held hexes");
+            let mut encoded = Vec::new();
+
+            codec.encode(value, &mut encoded);
+
+            run = match run {
+                Some((length, ref bytes)) if *bytes == encoded => {
+                    Some((length + 1, encoded))
+                }
+                Some((length, bytes)) => {
+                    push_varint(&mut out, length);
+                    out.extend_from_slice(&bytes);
+                    Some((1, encoded))
+                }
+                None => Some((1, encoded)),
+            };
+        }
+
+        if let Some((length, bytes)) = run {
+            push_varint(&mut out, length);
+            out.extend_from_slice(&bytes);
+        }
+
+        out
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Reading
+//////////////////////////////////////////////////////////////////////////////
+
+/// A cursor over encoded bytes which reports errors with their position.
+struct Reader<'a> {
+    input: &'a [u8],
+    len: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn position(&self) -> usize {
+        self.len - self.input.len()
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{} at byte {}", message, self.position())
+    }
+
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        if self.input.len() < count {
+            return Err(self.error("Unexpected end of map"));
+        }
+
+        let (taken, rest) = self.input.split_at(count);
+
+        self.input = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        let mut bytes = [0; 4];
+
+        bytes.copy_from_slice(self.take(4)?);
+        Ok(i32::from_le_bytes(bytes))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let mut bytes = [0; 4];
+
+        bytes.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value: u64 = 0;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            let bits = u64::from(byte & 0x7f);
+
+            if shift == 63 && bits > 1 {
+                break;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(self.error("Oversized varint"))
+    }
+
+    fn signed(&mut self) -> Result<i64, String> {
+        let value = self.varint()?;
+
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+}
+
+impl<T: Clone> HexGrid<T> {
+    /// Decodes a `HexGrid` in the binary map format, using `codec` to decode
+    /// each value.
+    ///
+    /// Returns a `Result::Err(String)` describing the problem and its byte
+    /// position if the data is truncated, corrupt, of an unsupported
+    /// version, or holds coordinates violating their system's constraints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::binary::IntCodec;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let bytes = HexGrid::new_radial(2, 1u8).to_bytes(&IntCodec);
+    ///
+    /// assert!(HexGrid::<u8>::from_bytes(&bytes, &IntCodec).is_ok());
+    /// assert_eq!(
+    ///     HexGrid::<u8>::from_bytes(&bytes[..10], &IntCodec).unwrap_err(),
+    ///     "Unexpected end of map at byte 9"
+    /// );
+    /// ```
+    pub fn from_bytes<C: Codec<T>>(
+        bytes: &[u8],
+        codec: &C,
+    ) -> Result<Self, String> {
+        Self::from_bytes_limited(bytes, codec, MAX_HEXES)
+    }
+
+    /// Decodes a `HexGrid` like `from_bytes`, but rejects maps of more than
+    /// `max_hexes` hexes instead of `MAX_HEXES`, before decoding any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::binary::IntCodec;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let bytes = HexGrid::new_boxy(10, 10, 1u8).to_bytes(&IntCodec);
+    ///
+    /// assert!(HexGrid::<u8>::from_bytes_limited(&bytes, &IntCodec, 100)
+    ///     .is_ok());
+    /// assert_eq!(
+    ///     HexGrid::<u8>::from_bytes_limited(&bytes, &IntCodec, 99)
+    ///         .unwrap_err(),
+    ///     "Too many hexes at byte 25"
+    /// );
+    /// ```
+    pub fn from_bytes_limited<C: Codec<T>>(
+        bytes: &[u8],
+        codec: &C,
+        max_hexes: u64,
+    ) -> Result<Self, String> {
+        let mut reader = Reader { input: bytes, len: bytes.len() };
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err("Not a chickenwire map".to_string());
+        }
+
+        let version = reader.u8()?;
+
+        if version != VERSION {
+            return Err(format!("Unsupported map version {}", version));
+        }
+
+        let tilt = match reader.u8()? {
+            0 => Tilt::Flat,
+            1 => Tilt::Sharp,
+            _ => return Err(reader.error("Invalid tilt")),
+        };
+        let parity = match reader.u8()? {
+            0 => Parity::Even,
+            1 => Parity::Odd,
+            _ => return Err(reader.error("Invalid parity")),
+        };
+        let sys = match reader.u8()? {
+            0 => CoordSys::Axial,
+            1 => CoordSys::Cube,
+            2 => CoordSys::Double,
+            3 => CoordSys::Offset,
+            _ => return Err(reader.error("Invalid coordinate system")),
+        };
+
+        let shape = match reader.u8()? {
+            SPARSE => {
+                let count = reader.varint()?;
+                let arity = if sys == CoordSys::Cube { 3 } else { 2 };
+
+                // Every component takes at least one byte, so larger counts
+                // can't be genuine.
+                if count > (reader.input.len() / arity) as u64 {
+                    return Err(reader.error("Hex count exceeds map size"));
+                }
+
+                let mut coords = Vec::with_capacity(count as usize);
+                let mut previous = [0i64; 3];
+
+                for _ in 0..count {
+                    let mut values = [0i32; 3];
+
+                    for i in 0..arity {
+                        let delta = reader.signed()?;
+                        let value = match previous[i]
+                            .checked_add(delta)
+                            .and_then(|value| i32::try_from(value).ok())
+                        {
+                            Some(value) => value,
+                            None => {
+                                let msg = "Coordinate out of range";
+
+                                return Err(reader.error(msg));
+                            }
+                        };
+
+                        previous[i] = i64::from(value);
+                        values[i] = value;
+                    }
+
+                    let coord = match sys {
+                        CoordSys::Axial => {
+                            Ok(MultiCoord::axial(values[0], values[1]))
+                        }
+                        CoordSys::Cube => {
+                            MultiCoord::cube(values[0], values[1], values[2])
+                        }
+                        CoordSys::Double => {
+                            MultiCoord::double(values[0], values[1])
+                        }
+                        CoordSys::Offset => {
+                            Ok(MultiCoord::offset(values[0], values[1]))
+                        }
+                    };
+
+                    match coord {
+                        Ok(coord) => coords.push(coord),
+                        Err(_) => {
+                            let msg = "Coordinate violates its constraint";
+
+                            return Err(reader.error(msg));
+                        }
+                    }
+                }
+
+                Shape::Sparse(coords)
+            }
+            BOXY => {
+                let min_col = reader.i32()?;
+                let min_row = reader.i32()?;
+                let cols = reader.u32()?;
+                let rows = reader.u32()?;
+                let fits = |min: i32, len: u32| {
+                    i64::from(min) + i64::from(len) - 1 <= i64::from(i32::MAX)
+                };
+
+                if !fits(min_col, cols) || !fits(min_row, rows) {
+                    return Err(reader.error("Bounds out of range"));
+                }
+
+                Shape::Boxy { min_col, min_row, cols, rows }
+            }
+            RADIAL => {
+                let (x, y, z) = (reader.i32()?, reader.i32()?, reader.i32()?);
+                let radius = reader.u32()?;
+                let center = match Cube::from_coords(x, y, z) {
+                    Ok(center) => center,
+                    Err(_) => {
+                        let msg = "Center violates its constraint";
+
+                        return Err(reader.error(msg));
+                    }
+                };
+
+                // Every hex in the spiral must fit in `i32`.
+                let fits = |value: i32| {
+                    let value = i64::from(value);
+                    let radius = i64::from(radius);
+
+                    value - radius >= i64::from(i32::MIN)
+                        && value + radius <= i64::from(i32::MAX)
+                };
+
+                if !fits(x) || !fits(y) || !fits(z) {
+                    return Err(reader.error("Bounds out of range"));
+                }
+
+                Shape::Radial { center, radius }
+            }
+            _ => return Err(reader.error("Invalid shape")),
+        };
+
+        let count = match shape {
+            Shape::Sparse(ref coords) => coords.len() as u64,
+            Shape::Boxy { cols, rows, .. } => {
+                u64::from(cols) * u64::from(rows)
+            }
+            Shape::Radial { radius, .. } => radial_count(u64::from(radius)),
+        };
+
+        if count > u64::from(u32::MAX).min(max_hexes) {
+            return Err(reader.error("Too many hexes"));
+        }
+
+        let mut coords: Box<dyn Iterator<Item = MultiCoord>> = match shape {
+            Shape::Sparse(coords) => Box::new(coords.into_iter()),
+            Shape::Boxy { min_col, min_row, cols, rows } => {
+                Box::new((0..rows).flat_map(move |row| {
+                    (0..cols).map(move |col| {
+                        let col = nth(min_col, col);
+
+                        MultiCoord::offset(col, nth(min_row, row))
+                    })
+                }))
+            }
+            Shape::Radial { center, radius } => {
+                Box::new((0..=radius).flat_map(move |r| {
+                    center.ring(r).into_iter().map(MultiCoord::from)
+                }))
+            }
+        };
+
+        let mut grid = Self::new(tilt, parity, sys);
+        let mut remaining = count;
+
+        while remaining > 0 {
+            let length = reader.varint()?;
+
+            if length == 0 || length > remaining {
+                return Err(reader.error("Invalid run length"));
+            }
+
+            let start = reader.position();
+            let value = match codec.decode(&mut reader.input) {
+                Some(value) => value,
+                None => {
+                    return Err(format!("Invalid value at byte {}", start));
+                }
+            };
+
+            for _ in 0..length {
+                let coord = coords.next().expect("shape yields count hexes");
+
+                if grid.contains_coord(coord) {
+                    return Err(format!(
+                        "Duplicate hex {} at byte {}",
+                        coord, start
+                    ));
+                }
+                grid.set(coord, value.clone());
+            }
+            remaining -= length;
+        }
+
+        if !reader.input.is_empty() {
+            return Err(reader.error("Unexpected trailing bytes"));
+        }
+
+        Ok(grid)
+    }
+}
@@ -0,0 +1,331 @@
+//! Cooperative Pathfinding
+//!
+//! Plans paths for several agents moving at once, so no two occupy the same
+//! hex at the same time or swap places by passing through each other.
+//! Agents are planned one at a time with A* over space and time, and each
+//! path is written to a reservation table that later agents plan around.
+//! Agents may wait in place, and stay at their goals once they arrive.
+//!
+//! Each step, whether a move or a wait, takes one unit of time. Moving costs
+//! the same as entering the hex in `HexGrid::find_path`, and waiting costs
+//! `CooperativePlanner::wait_cost`.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! let grid = HexGrid::new_boxy(5, 1, ());
+//! let (west, east) = (MultiCoord::offset(0, 0), MultiCoord::offset(4, 0));
+//!
+//! // Two agents in a corridor one hex wide can't pass each other.
+//! let paths = grid.plan_paths(&[(west, east), (east, west)], |_, _| Some(1));
+//!
+//! assert!(paths[0].is_some());
+//! assert!(paths[1].is_none());
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Timed Paths
+//////////////////////////////////////////////////////////////////////////////
+
+/// A path through space and time, starting at time 0.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TimedPath {
+    /// The hex occupied at each time, in the grid's `sys`. Waits repeat the
+    /// previous hex.
+    pub coords: Vec<MultiCoord>,
+    /// The total cost of every move and wait.
+    pub cost: u64,
+}
+
+impl TimedPath {
+    /// Returns the hex occupied at the given time, which is the last hex of
+    /// the path once it's complete.
+    pub fn at(&self, time: usize) -> Option<MultiCoord> {
+        self.coords
+            .get(time)
+            .or_else(|| self.coords.last())
+            .copied()
+    }
+
+    /// Returns the time the path arrives at its goal.
+    pub fn arrival(&self) -> usize {
+        self.coords.len().saturating_sub(1)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Planner
+//////////////////////////////////////////////////////////////////////////////
+
+/// Plans paths for agents one at a time, reserving each so later agents
+/// avoid it.
+#[derive(Debug)]
+pub struct CooperativePlanner<'a, T, F> {
+    grid: &'a HexGrid<T>,
+    cost: F,
+    /// Cost of waiting in place for one unit of time.
+    pub wait_cost: u32,
+    /// Latest time a path may arrive at its goal.
+    pub max_time: u32,
+    /// Hexes reserved at each time.
+    cells: HashSet<(Cube, u32)>,
+    /// Moves reserved from one hex to another, starting at each time.
+    moves: HashSet<(Cube, Cube, u32)>,
+    /// Latest time each hex is reserved.
+    latest: HashMap<Cube, u32>,
+    /// Hexes reserved from a time onward, by agents at their goals.
+    parked: HashMap<Cube, u32>,
+}
+
+impl<'a, T, F> CooperativePlanner<'a, T, F>
+where
+    F: FnMut(MultiCoord, &T) -> Option<u32>,
+{
+    /// Creates a planner with an empty reservation table, a `wait_cost` of
+    /// 1, and a `max_time` of 256. `cost` gives the cost of entering a hex,
+    /// or `None` if it can't be entered. Costs below 1 are raised to 1.
+    pub fn new(grid: &'a HexGrid<T>, cost: F) -> Self {
+        Self {
+            grid,
+            cost,
+            wait_cost: 1,
+            max_time: 256,
+            cells: HashSet::new(),
+            moves: HashSet::new(),
+            latest: HashMap::new(),
+            parked: HashMap::new(),
+        }
+    }
+
+    /// Reserves a hex at the given time.
+    pub fn reserve(&mut self, coord: MultiCoord, time: u32) {
+        let cube = self.grid.cube_of(coord);
+
+        self.reserve_cube(cube, time);
+    }
+
+    /// Reserves every hex and move along a path, and its last hex from its
+    /// arrival onward.
+    pub fn reserve_path(&mut self, path: &TimedPath) {
+        let cubes: Vec<Cube> = path
+            .coords
+            .iter()
+            .map(|&coord| self.grid.cube_of(coord))
+            .collect();
+
+        for (time, &cube) in cubes.iter().enumerate() {
+            self.reserve_cube(cube, time as u32);
+        }
+
+        for (time, pair) in cubes.windows(2).enumerate() {
+            if pair[0] != pair[1] {
+                self.moves.insert((pair[0], pair[1], time as u32));
+            }
+        }
+
+        if let Some(&last) = cubes.last() {
+            self.parked.insert(last, path.arrival() as u32);
+        }
+    }
+
+    /// Returns `true` if a hex is reserved at the given time.
+    pub fn is_reserved(&self, coord: MultiCoord, time: u32) -> bool {
+        self.blocked(self.grid.cube_of(coord), time)
+    }
+
+    /// Clears the reservation table.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.moves.clear();
+        self.latest.clear();
+        self.parked.clear();
+    }
+
+    /// Returns the cheapest path from `start` to `goal` that avoids every
+    /// reservation and can stay at `goal` once it arrives, then reserves
+    /// it. Returns `None` if there's no such path arriving by `max_time`.
+    pub fn plan(
+        &mut self,
+        start: MultiCoord,
+        goal: MultiCoord,
+    ) -> Option<TimedPath> {
+        let path = self.search(start, goal)?;
+
+        self.reserve_path(&path);
+        Some(path)
+    }
+
+    /// Searches space and time for a path without reserving it.
+    fn search(
+        &mut self,
+        start: MultiCoord,
+        goal: MultiCoord,
+    ) -> Option<TimedPath> {
+        if !self.grid.contains_coord(start) || !self.grid.contains_coord(goal)
+        {
+            return None;
+        }
+
+        let start = self.grid.cube_of(start);
+        let goal = self.grid.cube_of(goal);
+
+        if self.blocked(start, 0) {
+            return None;
+        }
+
+        let estimate = |cube: Cube| u64::from(cube.dist(goal).unsigned_abs());
+        let mut best: HashMap<(Cube, u32), u64> = HashMap::new();
+        let mut came_from: HashMap<(Cube, u32), Cube> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+
+        best.insert((start, 0), 0);
+        frontier.push(Reverse((estimate(start), 0, 0, start)));
+
+        while let Some(Reverse((_, spent, time, cube))) = frontier.pop() {
+            if cube == goal && self.can_park(goal, time) {
+                let mut cubes = vec![goal];
+
+                for at in (1..=time).rev() {
+                    cubes.push(came_from[&(cubes[cubes.len() - 1], at)]);
+                }
+
+                let coords = cubes
+                    .into_iter()
+                    .rev()
+                    .map(|cube| self.grid.coord_of(cube))
+                    .collect();
+
+                return Some(TimedPath {
+                    coords,
+                    cost: spent,
+                });
+            }
+
+            if time >= self.max_time
+                || best.get(&(cube, time)).is_some_and(|&known| spent > known)
+            {
+                continue;
+            }
+
+            let mut steps = vec![(cube, u64::from(self.wait_cost))];
+
+            for next in cube.neighbors() {
+                if let Some(step) = self.step_cost(next) {
+                    steps.push((next, u64::from(step.max(1))));
+                }
+            }
+
+            for (next, step) in steps {
+                let later = time + 1;
+
+                if self.blocked(next, later)
+                    || self.moves.contains(&(next, cube, time))
+                {
+                    continue;
+                }
+
+                let total = spent + step;
+
+                let known = best.get(&(next, later));
+
+                if known.map_or(true, |&known| total < known) {
+                    best.insert((next, later), total);
+                    came_from.insert((next, later), cube);
+                    frontier.push(Reverse((
+                        total + estimate(next),
+                        total,
+                        later,
+                        next,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn step_cost(&mut self, cube: Cube) -> Option<u32> {
+        let coord = self.grid.coord_of(cube);
+        let value = self.grid.get(coord)?;
+
+        (self.cost)(coord, value)
+    }
+
+    fn reserve_cube(&mut self, cube: Cube, time: u32) {
+        let latest = self.latest.entry(cube).or_insert(time);
+
+        *latest = (*latest).max(time);
+        self.cells.insert((cube, time));
+    }
+
+    /// Returns `true` if a hex is reserved at the given time.
+    fn blocked(&self, cube: Cube, time: u32) -> bool {
+        self.cells.contains(&(cube, time))
+            || self.parked.get(&cube).is_some_and(|&from| time >= from)
+    }
+
+    /// Returns `true` if an agent arriving at a hex at the given time could
+    /// stay there.
+    fn can_park(&self, cube: Cube, time: u32) -> bool {
+        !self.parked.contains_key(&cube)
+            && self.latest.get(&cube).map_or(true, |&latest| latest < time)
+    }
+}
+
+impl<T> HexGrid<T> {
+    /// Plans a path for each agent, given as a start and goal, in order of
+    /// priority. Each agent avoids the paths of those before it, and is
+    /// given `None` if it can't reach its goal, in which case later agents
+    /// treat it as staying at its start. Agents starting on the same hex as
+    /// an earlier agent are also given `None`, and otherwise ignored. See
+    /// `CooperativePlanner` for more control.
+    pub fn plan_paths<F>(
+        &self,
+        agents: &[(MultiCoord, MultiCoord)],
+        cost: F,
+    ) -> Vec<Option<TimedPath>>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+    {
+        let mut planner = CooperativePlanner::new(self, cost);
+
+        // Reserve every start first, so no agent plans through another
+        // that hasn't moved yet.
+        for &(start, _) in agents {
+            planner.reserve(start, 0);
+        }
+
+        let mut starts = HashSet::new();
+
+        agents
+            .iter()
+            .map(|&(start, goal)| {
+                let cube = self.cube_of(start);
+
+                // Freeing a shared start would free it for the agent that
+                // planned from it first, too.
+                if !starts.insert(cube) {
+                    return None;
+                }
+
+                planner.cells.remove(&(cube, 0));
+
+                let path = planner.plan(start, goal);
+
+                if path.is_none() {
+                    planner.parked.insert(cube, 0);
+                }
+
+                path
+            })
+            .collect()
+    }
+}
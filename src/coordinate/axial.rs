@@ -1,6 +1,8 @@
 //! Axial Coordinates
 
+use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
 
 use super::*;
 
@@ -13,9 +15,9 @@ use super::*;
 /// (x, y, z), the third value can always be calculated when the other two are
 /// known due to the constraint x + y + z == 0.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Axial {
-    pub q: i32,
-    pub r: i32,
+pub struct Axial<T = i32> {
+    pub q: T,
+    pub r: T,
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -24,6 +26,10 @@ pub struct Axial {
 
 /// Adds two `Axial` coordinates in the same manner as vectors.
 ///
+/// # Panics
+///
+/// Panics if either value overflows.
+///
 /// # Examples
 ///
 /// ```
@@ -35,19 +41,23 @@ pub struct Axial {
 /// assert_eq!(coord_1 + coord_2, Axial::from_coords(-4, 9));
 /// assert_eq!(coord_2 + coord_1, Axial::from_coords(-4, 9));
 /// ```
-impl Add for Axial {
+impl<T: CoordInt> Add for Axial<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         Self {
-            q: self.q + other.q,
-            r: self.r + other.r,
+            q: self.q.checked_add(other.q).expect("Axial add overflowed"),
+            r: self.r.checked_add(other.r).expect("Axial add overflowed"),
         }
     }
 }
 
 /// Subtracts two `Axial` coordinates in the same manner as vectors.
 ///
+/// # Panics
+///
+/// Panics if either value overflows.
+///
 /// # Examples
 ///
 /// ```
@@ -59,18 +69,22 @@ impl Add for Axial {
 /// assert_eq!(coord_1 - coord_2, Axial::from_coords(-4, 9));
 /// assert_eq!(coord_2 - coord_1, Axial::from_coords(4, -9));
 /// ```
-impl Sub for Axial {
+impl<T: CoordInt> Sub for Axial<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         Self {
-            q: self.q - other.q,
-            r: self.r - other.r,
+            q: self.q.checked_sub(other.q).expect("Axial sub overflowed"),
+            r: self.r.checked_sub(other.r).expect("Axial sub overflowed"),
         }
     }
 }
 
-/// Multiplies an `Axial` coordinate by an `i32` scalar, like a vector.
+/// Multiplies an `Axial` coordinate by a scalar, like a vector.
+///
+/// # Panics
+///
+/// Panics if either value overflows.
 ///
 /// # Examples
 ///
@@ -83,31 +97,39 @@ impl Sub for Axial {
 /// assert_eq!(0 * coord, Axial::ORIGIN);
 /// assert_eq!(coord * 2, Axial::from_coords(2, -6));
 /// ```
-impl Mul<i32> for Axial {
+impl<T: CoordInt> Mul<T> for Axial<T> {
     type Output = Self;
 
-    fn mul(self, n: i32) -> Self {
+    fn mul(self, n: T) -> Self {
         Self {
-            q: self.q * n,
-            r: self.r * n,
+            q: self.q.checked_mul(n).expect("Axial mul overflowed"),
+            r: self.r.checked_mul(n).expect("Axial mul overflowed"),
         }
     }
 }
 
-impl Mul<Axial> for i32 {
-    type Output = Axial;
-
-    fn mul(self, coord: Axial) -> Axial {
-        coord * self
-    }
+macro_rules! impl_scalar_mul {
+    ($($int:ty),*) => {
+        $(
+            impl Mul<Axial<$int>> for $int {
+                type Output = Axial<$int>;
+
+                fn mul(self, coord: Axial<$int>) -> Axial<$int> {
+                    coord * self
+                }
+            }
+        )*
+    };
 }
 
-/// Divides an `Axial` coordinate by an `i32` scalar, like a vector. Values
-/// are truncated (i.e. rounded toward zero).
+impl_scalar_mul!(i8, i16, i32, i64);
+
+/// Divides an `Axial` coordinate by a scalar, like a vector. Values are
+/// truncated (i.e. rounded toward zero).
 ///
 /// # Panics
 ///
-/// Panics when trying to divide by zero.
+/// Panics when trying to divide by zero, or if either value overflows.
 ///
 /// # Examples
 ///
@@ -120,13 +142,13 @@ impl Mul<Axial> for i32 {
 /// assert_eq!(coord / 2, Axial::from_coords(6, -18));
 /// assert_eq!(coord / 3, Axial::from_coords(4, -12));
 /// ```
-impl Div<i32> for Axial {
+impl<T: CoordInt> Div<T> for Axial<T> {
     type Output = Self;
 
-    fn div(self, n: i32) -> Self {
+    fn div(self, n: T) -> Self {
         Self {
-            q: self.q / n,
-            r: self.r / n,
+            q: self.q.checked_div(n).expect("Axial div overflowed"),
+            r: self.r.checked_div(n).expect("Axial div overflowed"),
         }
     }
 }
@@ -135,7 +157,7 @@ impl Div<i32> for Axial {
 // Traits: From & Into
 //////////////////////////////////////////////////////////////////////////////
 
-/// Creates an `Axial` from an `(i32, i32)`.
+/// Creates an `Axial` from a `(T, T)`.
 ///
 /// # Examples
 ///
@@ -147,12 +169,9 @@ impl Div<i32> for Axial {
 ///     Axial { q: 1, r: 2 }
 /// );
 /// ```
-impl From<(i32, i32)> for Axial {
-    fn from((q, r): (i32, i32)) -> Self {
-        Self {
-            q: q,
-            r: r,
-        }
+impl<T> From<(T, T)> for Axial<T> {
+    fn from((q, r): (T, T)) -> Self {
+        Self { q, r }
     }
 }
 
@@ -171,12 +190,12 @@ impl From<(i32, i32)> for Axial {
 ///     Axial { q: 1, r: -3 }
 /// );
 /// assert_eq!(
-///     Axial::from(Cube::ORIGIN),
+///     Axial::<i32>::from(Cube::ORIGIN),
 ///     Axial::ORIGIN
 /// );
 /// ```
-impl From<Cube> for Axial {
-    fn from(coord: Cube) -> Self {
+impl<T: CoordInt> From<Cube<T>> for Axial<T> {
+    fn from(coord: Cube<T>) -> Self {
         let (x, _, z) = coord.to_tuple();
 
         Self { q: x, r: z }
@@ -200,24 +219,63 @@ impl From<MultiCoord> for Axial {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Traits: Display & FromStr
+//////////////////////////////////////////////////////////////////////////////
+
+/// Formats an `Axial` as `axial(q,r)`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::axial::Axial;
+///
+/// assert_eq!(Axial::from_coords(1, -3).to_string(), "axial(1,-3)");
+/// ```
+impl<T: CoordInt> fmt::Display for Axial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "axial({},{})", self.q, self.r)
+    }
+}
+
+/// Parses an `Axial` from the form `axial(q,r)`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::axial::Axial;
+///
+/// assert_eq!("axial(1,-3)".parse(), Ok(Axial::from_coords(1, -3)));
+/// assert!("axial(300,0)".parse::<Axial<i8>>().is_err());
+/// ```
+impl<T: CoordInt> FromStr for Axial<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = ParsedCoord::parse_sys(s, CoordSys::Axial)?;
+
+        Ok(Self::from_coords(parsed.value(0)?, parsed.value(1)?))
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 // Methods
 //////////////////////////////////////////////////////////////////////////////
 
-impl Axial {
+impl<T: CoordInt> Axial<T> {
     //////////////////////////////////
     // Constants
     //////////////////////////////////
 
     /// `Axial` coordinate origin of (0, 0).
-    pub const ORIGIN: Axial = Axial { q: 0, r: 0 };
+    pub const ORIGIN: Self = Axial { q: T::ZERO, r: T::ZERO };
 
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
 
-    /// For two unsigned 32-bit integers x and y, the corresponding axial
-    /// coordinate is (x, y).
+    /// For two integers x and y, the corresponding axial coordinate is
+    /// (x, y).
     ///
     /// # Examples
     ///
@@ -226,10 +284,194 @@ impl Axial {
     ///
     /// assert_eq!(Axial::from((1, 2)), Axial::from_coords(1, 2));
     /// ```
-    pub fn from_coords(q: i32, r: i32) -> Self {
+    pub fn from_coords(q: T, r: T) -> Self {
         Self::from((q, r))
     }
 
+    //////////////////////////////////
+    // Conversion
+    //////////////////////////////////
+
+    /// Attempts to convert an `Axial` to a different integer width. Returns
+    /// an `Err` if either value doesn't fit in the target width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(300, -2);
+    ///
+    /// assert_eq!(coord.cast::<i16>(), Ok(Axial::from_coords(300, -2)));
+    /// assert!(coord.cast::<i8>().is_err());
+    /// ```
+    pub fn cast<U: CoordInt>(self) -> CoordResult<Axial<U>> {
+        let q = U::from_i128(self.q.to_i128());
+        let r = U::from_i128(self.r.to_i128());
+
+        match (q, r) {
+            (Some(q), Some(r)) => Ok(Axial { q, r }),
+            _ => Err("Axial coordinate out of range for target width"),
+        }
+    }
+
+    //////////////////////////////////
+    // Checked Arithmetic
+    //////////////////////////////////
+
+    /// Creates an `Axial` from exact `i128` values, returning `None` if
+    /// either doesn't fit in the coordinate width.
+    fn checked_from_i128s(q: i128, r: i128) -> Option<Self> {
+        Some(Axial {
+            q: T::from_i128(q)?,
+            r: T::from_i128(r)?,
+        })
+    }
+
+    /// Adds two `Axial` coordinates, returning `None` upon overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(1, -3);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_add(Axial::from_coords(2, 2)),
+    ///     Some(Axial::from_coords(3, -1))
+    /// );
+    /// assert_eq!(coord.checked_add(Axial::from_coords(i32::MAX, 0)), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.q.to_i128() + other.q.to_i128(),
+            self.r.to_i128() + other.r.to_i128(),
+        )
+    }
+
+    /// Subtracts two `Axial` coordinates, returning `None` upon overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(1, -3);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_sub(Axial::from_coords(2, 2)),
+    ///     Some(Axial::from_coords(-1, -5))
+    /// );
+    /// assert_eq!(coord.checked_sub(Axial::from_coords(0, i32::MAX)), None);
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.q.to_i128() - other.q.to_i128(),
+            self.r.to_i128() - other.r.to_i128(),
+        )
+    }
+
+    /// Multiplies an `Axial` coordinate by a scalar, returning `None` upon
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(1, -3);
+    ///
+    /// assert_eq!(coord.checked_mul(-2), Some(Axial::from_coords(-2, 6)));
+    /// assert_eq!(coord.checked_mul(i32::MAX), None);
+    /// ```
+    pub fn checked_mul(self, n: T) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.q.to_i128() * n.to_i128(),
+            self.r.to_i128() * n.to_i128(),
+        )
+    }
+
+    //////////////////////////////////
+    // Saturating Arithmetic
+    //////////////////////////////////
+
+    /// Creates an `Axial` from exact `i128` values, clamped as a `Cube` so
+    /// that the result always has a `Cube` equivalent.
+    fn saturating_from_i128s(q: i128, r: i128) -> Self {
+        Axial::from(Cube::saturating_from_i128s(q, -q - r, r))
+    }
+
+    /// Adds two `Axial` coordinates, clamping the result to one that has a
+    /// `Cube` equivalent within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let coord = Axial::from_coords(i32::MAX - 1, 0);
+    /// let sum = coord.saturating_add(Axial::from_coords(5, 5));
+    ///
+    /// assert_eq!(sum, Axial::from_coords(i32::MAX, 1));
+    /// assert_eq!(
+    ///     Cube::from(sum),
+    ///     Cube::force_from_coords(i32::MAX, i32::MIN, 1)
+    /// );
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::saturating_from_i128s(
+            self.q.to_i128() + other.q.to_i128(),
+            self.r.to_i128() + other.r.to_i128(),
+        )
+    }
+
+    /// Subtracts two `Axial` coordinates, clamping the result to one that has
+    /// a `Cube` equivalent within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(i32::MIN + 1, 0);
+    ///
+    /// assert_eq!(
+    ///     coord.saturating_sub(Axial::from_coords(5, 5)),
+    ///     Axial::from_coords(i32::MIN, 1)
+    /// );
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::saturating_from_i128s(
+            self.q.to_i128() - other.q.to_i128(),
+            self.r.to_i128() - other.r.to_i128(),
+        )
+    }
+
+    /// Multiplies an `Axial` coordinate by a scalar, clamping the result to
+    /// one that has a `Cube` equivalent within the limits of the coordinate
+    /// width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(1, -3);
+    ///
+    /// assert_eq!(
+    ///     coord.saturating_mul(i32::MAX),
+    ///     Axial::from_coords(1, i32::MIN)
+    /// );
+    /// ```
+    pub fn saturating_mul(self, n: T) -> Self {
+        Self::saturating_from_i128s(
+            self.q.to_i128() * n.to_i128(),
+            self.r.to_i128() * n.to_i128(),
+        )
+    }
+
     //////////////////////////////////
     // Neighbors
     //////////////////////////////////
@@ -238,14 +480,14 @@ impl Axial {
     pub fn neighbors(self) -> Vec<Self> {
         let cube_neighbors = Cube::from(self).neighbors();
 
-        cube_neighbors.into_iter().map(|coord| Self::from(coord)).collect()
+        cube_neighbors.into_iter().map(Self::from).collect()
     }
 
     /// [ docs missing ]
     pub fn diagonals(self) -> Vec<Self> {
         let cube_diagonals = Cube::from(self).diagonals();
 
-        cube_diagonals.into_iter().map(|coord| Self::from(coord)).collect()
+        cube_diagonals.into_iter().map(Self::from).collect()
     }
 
     //////////////////////////////////
@@ -268,7 +510,31 @@ impl Axial {
     /// assert_eq!(coord_1.dist(coord_1), 0);
     /// assert_eq!(coord_2.dist(coord_1), 9);
     /// ```
-    pub fn dist(self, other: Self) -> i32 {
+    pub fn dist(self, other: Self) -> T {
         Cube::from(self).dist(Cube::from(other))
     }
+
+    /// Determines the distance between two axial coordinates, returning
+    /// `None` if the distance can't be represented in the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::axial::Axial;
+    ///
+    /// let coord = Axial::from_coords(i32::MIN, 0);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_dist(Axial::from_coords(-1, 0)),
+    ///     Some(i32::MAX)
+    /// );
+    /// assert_eq!(coord.checked_dist(Axial::ORIGIN), None);
+    /// ```
+    pub fn checked_dist(self, other: Self) -> Option<T> {
+        let q_dist = self.q.to_i128() - other.q.to_i128();
+        let r_dist = self.r.to_i128() - other.r.to_i128();
+        let s_dist = 0 - q_dist - r_dist;
+
+        T::from_i128(q_dist.abs().max(r_dist.abs()).max(s_dist.abs()))
+    }
 }
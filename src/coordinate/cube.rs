@@ -1,7 +1,9 @@
 //! Cube Coordinates
 
 use std::cmp;
+use std::fmt;
 use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
 
 use super::*;
 
@@ -18,10 +20,10 @@ use super::*;
 /// opaque, requiring method or function calls for instantiation and
 /// modification.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Cube {
-    x: i32,
-    y: i32,
-    z: i32,
+pub struct Cube<T = i32> {
+    x: T,
+    y: T,
+    z: T,
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -30,6 +32,10 @@ pub struct Cube {
 
 /// Cube coordinates are added together like vectors.
 ///
+/// # Panics
+///
+/// Panics if any value overflows.
+///
 /// # Examples
 ///
 /// ```
@@ -41,20 +47,24 @@ pub struct Cube {
 /// assert_eq!(coord_1 + coord_2, Cube::force_from_coords(-4, -5, 9));
 /// assert_eq!(coord_2 + coord_1, Cube::force_from_coords(-4, -5, 9));
 /// ```
-impl Add for Cube {
+impl<T: CoordInt> Add for Cube<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
+            x: self.x.checked_add(other.x).expect("Cube add overflowed"),
+            y: self.y.checked_add(other.y).expect("Cube add overflowed"),
+            z: self.z.checked_add(other.z).expect("Cube add overflowed"),
         }
     }
 }
 
 /// Cube coordinates are subtracted from each other like vectors.
 ///
+/// # Panics
+///
+/// Panics if any value overflows.
+///
 /// # Examples
 ///
 /// ```
@@ -66,19 +76,23 @@ impl Add for Cube {
 /// assert_eq!(coord_1 - coord_2, Cube::force_from_coords(-4, -5, 9));
 /// assert_eq!(coord_2 - coord_1, Cube::force_from_coords(4, 5, -9));
 /// ```
-impl Sub for Cube {
+impl<T: CoordInt> Sub for Cube<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
+            x: self.x.checked_sub(other.x).expect("Cube sub overflowed"),
+            y: self.y.checked_sub(other.y).expect("Cube sub overflowed"),
+            z: self.z.checked_sub(other.z).expect("Cube sub overflowed"),
         }
     }
 }
 
-/// Cube coordinates can be multiplied by `i32` scalars, like vectors.
+/// Cube coordinates can be multiplied by scalars, like vectors.
+///
+/// # Panics
+///
+/// Panics if any value overflows.
 ///
 /// # Examples
 ///
@@ -91,47 +105,51 @@ impl Sub for Cube {
 /// assert_eq!(0 * coord, Cube::ORIGIN);
 /// assert_eq!(coord * 2, Cube::force_from_coords(2, 4, -6));
 /// ```
-impl Mul<i32> for Cube {
+impl<T: CoordInt> Mul<T> for Cube<T> {
     type Output = Self;
 
-    fn mul(self, n: i32) -> Self {
+    fn mul(self, n: T) -> Self {
         Self {
-            x: self.x * n,
-            y: self.y * n,
-            z: self.z * n,
+            x: self.x.checked_mul(n).expect("Cube mul overflowed"),
+            y: self.y.checked_mul(n).expect("Cube mul overflowed"),
+            z: self.z.checked_mul(n).expect("Cube mul overflowed"),
         }
     }
 }
 
-impl Mul<Cube> for i32 {
-    type Output = Cube;
+macro_rules! impl_scalar_mul {
+    ($($int:ty),*) => {
+        $(
+            impl Mul<Cube<$int>> for $int {
+                type Output = Cube<$int>;
 
-    fn mul(self, coord: Cube) -> Cube {
-        coord * self
-    }
+                fn mul(self, coord: Cube<$int>) -> Cube<$int> {
+                    coord * self
+                }
+            }
+        )*
+    };
 }
 
+impl_scalar_mul!(i8, i16, i32, i64);
+
 //////////////////////////////////////////////////////////////////////////////
 // Traits: From & Into
 //////////////////////////////////////////////////////////////////////////////
 
-/// Creates a `Cube` from an `(i32, i32, i32)`.
+/// Creates a `Cube` from a `(T, T, T)`.
 ///
 /// The produced `Cube`, (x, y, z), must be constrained such that
 /// x + y + z == 0.
 ///
 /// # Panics
 ///
-/// Panics upon receiving `i32` values which violate the constraint
-/// x + y + z == 0 for `Cube` (x, y, z).
-impl From<(i32, i32, i32)> for Cube {
-    fn from((x, y, z): (i32, i32, i32)) -> Self {
-        if z == 0 - x - y {
-            Self {
-                x: x,
-                y: y,
-                z: z,
-            }
+/// Panics upon receiving values which violate the constraint x + y + z == 0
+/// for `Cube` (x, y, z).
+impl<T: CoordInt> From<(T, T, T)> for Cube<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        if Self::is_valid(x, y, z) {
+            Self { x, y, z }
         } else {
             panic!("({}, {}, {}) is an invalid cube coordiante", x, y, z);
         }
@@ -142,6 +160,11 @@ impl From<(i32, i32, i32)> for Cube {
 /// solving for y based upon the constraint x + y + z == 0, where x == q and
 /// z == r.
 ///
+/// # Panics
+///
+/// Panics if y can't be represented in the coordinate width, which is only
+/// possible for axial coordinates near the limits of that width.
+///
 /// # Examples
 ///
 /// ```
@@ -150,16 +173,16 @@ impl From<(i32, i32, i32)> for Cube {
 ///
 /// let axial = Axial::from_coords(1, 2);
 ///
-/// assert_eq!(Cube::from(Axial::ORIGIN), Cube::ORIGIN);
+/// assert_eq!(Cube::<i32>::from(Axial::ORIGIN), Cube::ORIGIN);
 /// assert_eq!(Cube::from(axial), Cube::force_from_coords(1, -3, 2));
 /// ```
-impl From<Axial> for Cube {
-    fn from(coord: Axial) -> Self {
+impl<T: CoordInt> From<Axial<T>> for Cube<T> {
+    fn from(coord: Axial<T>) -> Self {
         let x = coord.q;
         let z = coord.r;
-        let y = 0 - x - z;
+        let y = Self::third(x, z).expect("Axial has no Cube equivalent");
 
-        Self { x: x, y: y, z: z }
+        Self { x, y, z }
     }
 }
 
@@ -171,9 +194,9 @@ impl From<MultiCoord> for Cube {
                 let z = coord.b;
 
                 Self {
-                    x: x,
+                    x,
                     y: 0 - x - z,
-                    z: z,
+                    z,
                 }
             }
             CoordSys::Cube => {
@@ -188,22 +211,76 @@ impl From<MultiCoord> for Cube {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Traits: Display & FromStr
+//////////////////////////////////////////////////////////////////////////////
+
+/// Formats a `Cube` as `cube(x,y,z)`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::cube::Cube;
+///
+/// let cube = Cube::force_from_coords(1, -2, 1);
+///
+/// assert_eq!(cube.to_string(), "cube(1,-2,1)");
+/// ```
+impl<T: CoordInt> fmt::Display for Cube<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cube({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses a `Cube` from the form `cube(x,y,z)`, validating the constraint
+/// x + y + z == 0.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::cube::Cube;
+/// use chickenwire::coordinate::ParseCoordErrorKind;
+///
+/// assert_eq!(
+///     "cube(1,-2,1)".parse(),
+///     Ok(Cube::force_from_coords(1, -2, 1))
+/// );
+/// assert_eq!(
+///     "cube(1,1,1)".parse::<Cube>().unwrap_err().kind(),
+///     ParseCoordErrorKind::Constraint
+/// );
+/// ```
+impl<T: CoordInt> FromStr for Cube<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = ParsedCoord::parse_sys(s, CoordSys::Cube)?;
+
+        Self::from_coords(
+            parsed.value(0)?,
+            parsed.value(1)?,
+            parsed.value(2)?,
+        )
+        .map_err(|_| parsed.constraint_error())
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 // Methods
 //////////////////////////////////////////////////////////////////////////////
 
-impl Cube {
+impl<T: CoordInt> Cube<T> {
     //////////////////////////////////
     // Constants
     //////////////////////////////////
 
     /// `Cube` coordinate origin of (0, 0, 0).
-    pub const ORIGIN: Cube = Cube { x: 0, y: 0, z: 0 };
+    pub const ORIGIN: Self = Cube { x: T::ZERO, y: T::ZERO, z: T::ZERO };
 
     /// Offset values for cube coordinate neighbors, beginning with the
     /// Northeastern side and proceeding clockwise. Can be tthought of as unit
     /// vectors for the directions of the six sides.
-    const NEIGHBOR_OFFSETS: [(i32, i32, i32); 6] = [
+    const NEIGHBOR_OFFSETS: [(i8, i8, i8); 6] = [
         (1, 0, -1),     // NE
         (1, -1, 0),
         (0, -1, 1),
@@ -214,7 +291,7 @@ impl Cube {
 
     /// Offset values for cube coordinate diagonals, beginning with the
     /// Southeastern side and proceeding clockwise.
-    const DIAGONAL_OFFSETS: [(i32, i32, i32); 6] = [
+    const DIAGONAL_OFFSETS: [(i8, i8, i8); 6] = [
         (1, -2, 1),     // SE
         (-1, -1, 2),
         (-2, 1, 1),
@@ -223,11 +300,35 @@ impl Cube {
         (2, -1, -1),
     ];
 
+    //////////////////////////////////
+    // Utilities
+    //////////////////////////////////
+
+    /// Checks the constraint x + y + z == 0 without risk of overflow.
+    fn is_valid(x: T, y: T, z: T) -> bool {
+        x.to_i128() + y.to_i128() + z.to_i128() == 0
+    }
+
+    /// Solves for the third value of a `Cube` given the other two. Returns
+    /// `None` if the result can't be represented in the coordinate width.
+    fn third(a: T, b: T) -> Option<T> {
+        T::from_i128(0 - a.to_i128() - b.to_i128())
+    }
+
+    /// Creates a unit vector from one of the offset tables.
+    fn from_offset((x, y, z): (i8, i8, i8)) -> Self {
+        Self {
+            x: T::from_i8(x),
+            y: T::from_i8(y),
+            z: T::from_i8(z),
+        }
+    }
+
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
 
-    /// Attempts to create a `Cube` from three `i32` values. If these values
+    /// Attempts to create a `Cube` from three integer values. If these values
     /// obey the `Cube` constraint, x + y + z == 0, then returns that `Cube`
     /// in an `Ok`. Otherwise, returns an `Err`.
     ///
@@ -247,19 +348,19 @@ impl Cube {
     ///
     /// assert!(cube_2.is_err());
     /// ```
-    pub fn from_coords(x: i32, y: i32, z: i32) -> CoordResult<Cube> {
-        if z == 0 - x - y {
-            Ok(Self { x: x, y: y, z: z })
+    pub fn from_coords(x: T, y: T, z: T) -> CoordResult<Self> {
+        if Self::is_valid(x, y, z) {
+            Ok(Self { x, y, z })
         } else {
             Err("invalid Cube coordinate")
         }
     }
 
-    /// Creates a `Cube` from three `i32` values.
+    /// Creates a `Cube` from three integer values.
     ///
     /// # Panics
     ///
-    /// Panics if the given `i32` values fail to obey the constraint
+    /// Panics if the given values fail to obey the constraint
     /// x + y + z == 0.
     ///
     /// # Examples
@@ -273,11 +374,11 @@ impl Cube {
     /// assert_eq!(cube_1.y(), 3);
     /// assert_eq!(cube_1.z(), -4);
     /// ```
-    pub fn force_from_coords(x: i32, y: i32, z: i32) -> Self {
+    pub fn force_from_coords(x: T, y: T, z: T) -> Self {
         Self::from_coords(x, y, z).unwrap()
     }
 
-    /// Attempts to create a `Cube` from a tuple of three `i32` values. If
+    /// Attempts to create a `Cube` from a tuple of three integer values. If
     /// these values obey the `Cube` constraint, x + y + z == 0, then returns
     /// that `Cube` in an `Ok`. Otherwise, returns an `Err`.
     ///
@@ -297,7 +398,7 @@ impl Cube {
     ///
     /// assert!(cube_2.is_err());
     /// ```
-    pub fn from_tuple((x, y, z): (i32, i32, i32)) -> CoordResult<Cube> {
+    pub fn from_tuple((x, y, z): (T, T, T)) -> CoordResult<Self> {
         Cube::from_coords(x, y, z)
     }
 
@@ -305,26 +406,240 @@ impl Cube {
     // Conversion
     //////////////////////////////////
 
-    pub fn to_tuple(self) -> (i32, i32, i32) {
+    pub fn to_tuple(self) -> (T, T, T) {
         (self.x, self.y, self.z)
     }
 
+    /// Attempts to convert a `Cube` to a different integer width. Returns an
+    /// `Err` if any value doesn't fit in the target width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let coord: Cube<i64> = Cube::force_from_coords(70_000, 0, -70_000);
+    ///
+    /// assert!(coord.cast::<i32>().is_ok());
+    /// assert!(coord.cast::<i16>().is_err());
+    /// ```
+    pub fn cast<U: CoordInt>(self) -> CoordResult<Cube<U>> {
+        let x = U::from_i128(self.x.to_i128());
+        let y = U::from_i128(self.y.to_i128());
+        let z = U::from_i128(self.z.to_i128());
+
+        match (x, y, z) {
+            (Some(x), Some(y), Some(z)) => Ok(Cube { x, y, z }),
+            _ => Err("Cube coordinate out of range for target width"),
+        }
+    }
+
     //////////////////////////////////
     // Retrieval
     //////////////////////////////////
 
-    pub fn x(self) -> i32 {
+    pub fn x(self) -> T {
         self.x
     }
 
-    pub fn y(self) -> i32 {
+    pub fn y(self) -> T {
         self.y
     }
 
-    pub fn z(self) -> i32 {
+    pub fn z(self) -> T {
         self.z
     }
 
+    //////////////////////////////////
+    // Checked Arithmetic
+    //////////////////////////////////
+
+    /// Creates a `Cube` from exact `i128` values which already obey the
+    /// `Cube` constraint, returning `None` if any doesn't fit in the
+    /// coordinate width.
+    fn checked_from_i128s(x: i128, y: i128, z: i128) -> Option<Self> {
+        Some(Cube {
+            x: T::from_i128(x)?,
+            y: T::from_i128(y)?,
+            z: T::from_i128(z)?,
+        })
+    }
+
+    /// Adds two `Cube` coordinates, returning `None` upon overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let coord = Cube::force_from_coords(1, 2, -3);
+    /// let edge = Cube::force_from_coords(i32::MAX, 0, -i32::MAX);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_add(coord),
+    ///     Some(Cube::force_from_coords(2, 4, -6))
+    /// );
+    /// assert_eq!(coord.checked_add(edge), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.x.to_i128() + other.x.to_i128(),
+            self.y.to_i128() + other.y.to_i128(),
+            self.z.to_i128() + other.z.to_i128(),
+        )
+    }
+
+    /// Subtracts two `Cube` coordinates, returning `None` upon overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let coord = Cube::force_from_coords(1, 2, -3);
+    /// let edge = Cube::force_from_coords(i32::MAX, 0, -i32::MAX);
+    ///
+    /// assert_eq!(coord.checked_sub(coord), Some(Cube::ORIGIN));
+    /// assert_eq!(coord.checked_sub(edge * -1), None);
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.x.to_i128() - other.x.to_i128(),
+            self.y.to_i128() - other.y.to_i128(),
+            self.z.to_i128() - other.z.to_i128(),
+        )
+    }
+
+    /// Multiplies a `Cube` coordinate by a scalar, returning `None` upon
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let coord = Cube::force_from_coords(1, 2, -3);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_mul(-2),
+    ///     Some(Cube::force_from_coords(-2, -4, 6))
+    /// );
+    /// assert_eq!(coord.checked_mul(i32::MAX), None);
+    /// ```
+    pub fn checked_mul(self, n: T) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.x.to_i128() * n.to_i128(),
+            self.y.to_i128() * n.to_i128(),
+            self.z.to_i128() * n.to_i128(),
+        )
+    }
+
+    //////////////////////////////////
+    // Saturating Arithmetic
+    //////////////////////////////////
+
+    /// Creates a `Cube` from exact `i128` values which obey the `Cube`
+    /// constraint. Values are clamped to the limits of the coordinate width,
+    /// and any excess introduced by clamping is moved onto the values which
+    /// still have room (preferring those which weren't clamped), so that the
+    /// result remains a valid `Cube`.
+    pub(crate) fn saturating_from_i128s(x: i128, y: i128, z: i128) -> Self {
+        let min = T::MIN.to_i128();
+        let max = T::MAX.to_i128();
+        let exact = [x, y, z];
+        let mut values = [
+            x.clamp(min, max),
+            y.clamp(min, max),
+            z.clamp(min, max),
+        ];
+        let mut excess: i128 = values.iter().sum();
+
+        for &only_unclamped in [true, false].iter() {
+            for (value, &original) in values.iter_mut().zip(exact.iter()) {
+                if only_unclamped && *value != original {
+                    continue;
+                }
+
+                let adjusted = (*value - excess).clamp(min, max);
+
+                excess -= *value - adjusted;
+                *value = adjusted;
+            }
+        }
+
+        Self::checked_from_i128s(values[0], values[1], values[2])
+            .expect("saturated Cube is always representable")
+    }
+
+    /// Adds two `Cube` coordinates, clamping the result to a valid `Cube`
+    /// within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let edge = Cube::force_from_coords(i32::MAX - 1, 0, 1 - i32::MAX);
+    /// let step = Cube::force_from_coords(5, -5, 0);
+    ///
+    /// assert_eq!(
+    ///     edge.saturating_add(step),
+    ///     Cube::force_from_coords(i32::MAX, -1, 1 - i32::MAX)
+    /// );
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::saturating_from_i128s(
+            self.x.to_i128() + other.x.to_i128(),
+            self.y.to_i128() + other.y.to_i128(),
+            self.z.to_i128() + other.z.to_i128(),
+        )
+    }
+
+    /// Subtracts two `Cube` coordinates, clamping the result to a valid
+    /// `Cube` within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let edge = Cube::force_from_coords(i32::MIN + 1, 0, i32::MAX);
+    /// let step = Cube::force_from_coords(5, -5, 0);
+    ///
+    /// assert_eq!(
+    ///     edge.saturating_sub(step),
+    ///     Cube::force_from_coords(i32::MIN, 1, i32::MAX)
+    /// );
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::saturating_from_i128s(
+            self.x.to_i128() - other.x.to_i128(),
+            self.y.to_i128() - other.y.to_i128(),
+            self.z.to_i128() - other.z.to_i128(),
+        )
+    }
+
+    /// Multiplies a `Cube` coordinate by a scalar, clamping the result to a
+    /// valid `Cube` within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let coord: Cube<i8> = Cube::force_from_coords(2, 1, -3);
+    /// let scaled = coord.saturating_mul(100);
+    ///
+    /// assert_eq!(scaled.to_tuple(), (127, 1, -128));
+    /// ```
+    pub fn saturating_mul(self, n: T) -> Self {
+        Self::saturating_from_i128s(
+            self.x.to_i128() * n.to_i128(),
+            self.y.to_i128() * n.to_i128(),
+            self.z.to_i128() * n.to_i128(),
+        )
+    }
+
     //////////////////////////////////
     // Neighbors
     //////////////////////////////////
@@ -350,11 +665,11 @@ impl Cube {
     ///     Cube::ORIGIN.neighbor(5)
     /// );
     ///
-    /// assert_eq!(Cube::ORIGIN.neighbor(0), Cube::ORIGIN.neighbor(6));
-    /// assert_eq!(Cube::ORIGIN.neighbor(1), Cube::ORIGIN.neighbor(7));
+    /// assert_eq!(Cube::<i32>::ORIGIN.neighbor(0), Cube::ORIGIN.neighbor(6));
+    /// assert_eq!(Cube::<i32>::ORIGIN.neighbor(1), Cube::ORIGIN.neighbor(7));
     /// ```
     pub fn neighbor(self, index: usize) -> Self {
-        self + Self::from(Self::NEIGHBOR_OFFSETS[index % 6])
+        self + Self::from_offset(Self::NEIGHBOR_OFFSETS[index % 6])
     }
 
     /// Produces a `Vec<Cube>` ordered beginning with the Northeastern
@@ -426,11 +741,11 @@ impl Cube {
     ///     Cube::ORIGIN.diagonal(5)
     /// );
     ///
-    /// assert_eq!(Cube::ORIGIN.diagonal(0), Cube::ORIGIN.diagonal(6));
-    /// assert_eq!(Cube::ORIGIN.diagonal(1), Cube::ORIGIN.diagonal(7));
+    /// assert_eq!(Cube::<i32>::ORIGIN.diagonal(0), Cube::ORIGIN.diagonal(6));
+    /// assert_eq!(Cube::<i32>::ORIGIN.diagonal(1), Cube::ORIGIN.diagonal(7));
     /// ```
     pub fn diagonal(self, index: usize) -> Self {
-        self + Self::from(Self::DIAGONAL_OFFSETS[index % 6])
+        self + Self::from_offset(Self::DIAGONAL_OFFSETS[index % 6])
     }
 
     /// Produces a `Vec<Cube>` ordered beginning with the Southeastern
@@ -501,7 +816,7 @@ impl Cube {
     /// assert_eq!(coord_1.dist(coord_1), 0);
     /// assert_eq!(coord_2.dist(coord_1), 9);
     /// ```
-    pub fn dist(self, other: Self) -> i32 {
+    pub fn dist(self, other: Self) -> T {
         let x_dist = (self.x - other.x).abs();
         let y_dist = (self.y - other.y).abs();
         let z_dist = (self.z - other.z).abs();
@@ -509,6 +824,27 @@ impl Cube {
         cmp::max(cmp::max(x_dist, y_dist), z_dist)
     }
 
+    /// Determines the distance between two cube coordinates, returning
+    /// `None` if the distance can't be represented in the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let edge = Cube::force_from_coords(i32::MAX, 0, -i32::MAX);
+    ///
+    /// assert_eq!(edge.checked_dist(Cube::ORIGIN), Some(i32::MAX));
+    /// assert_eq!(edge.checked_dist(edge * -1), None);
+    /// ```
+    pub fn checked_dist(self, other: Self) -> Option<T> {
+        let x_dist = (self.x.to_i128() - other.x.to_i128()).abs();
+        let y_dist = (self.y.to_i128() - other.y.to_i128()).abs();
+        let z_dist = (self.z.to_i128() - other.z.to_i128()).abs();
+
+        T::from_i128(cmp::max(cmp::max(x_dist, y_dist), z_dist))
+    }
+
     //////////////////////////////////
     // Rotation
     //////////////////////////////////
@@ -518,11 +854,11 @@ impl Cube {
         let mut vector = point - self;
 
         for _turns in 0..(num_turns % 6) {
-            let new_x = 0 - vector.z;
-            let new_y = 0 - vector.x;
-            let new_z = 0 - vector.y;
+            let new_x = T::ZERO - vector.z;
+            let new_y = T::ZERO - vector.x;
+            let new_z = T::ZERO - vector.y;
 
-            vector = Cube::force_from_coords(new_x, new_y, new_z);
+            vector = Self::force_from_coords(new_x, new_y, new_z);
         }
 
         vector + self
@@ -533,9 +869,9 @@ impl Cube {
         let mut vector = point - self;
 
         for _turns in 0..(num_turns % 6) {
-            let new_x = 0 - vector.y;
-            let new_y = 0 - vector.z;
-            let new_z = 0 - vector.x;
+            let new_x = T::ZERO - vector.y;
+            let new_y = T::ZERO - vector.z;
+            let new_z = T::ZERO - vector.x;
 
             vector = Self::force_from_coords(new_x, new_y, new_z);
         }
@@ -543,12 +879,92 @@ impl Cube {
         vector + self
     }
 
+    /// Rotates a point about the calling instance using exact arithmetic,
+    /// returning `None` if the result can't be represented in the coordinate
+    /// width. Positive turns are clockwise.
+    fn checked_rotate(self, point: Self, cw_turns: u32) -> Option<Self> {
+        let mut x = point.x.to_i128() - self.x.to_i128();
+        let mut y = point.y.to_i128() - self.y.to_i128();
+        let mut z = point.z.to_i128() - self.z.to_i128();
+
+        for _turns in 0..(cw_turns % 6) {
+            let (new_x, new_y, new_z) = (0 - z, 0 - x, 0 - y);
+
+            x = new_x;
+            y = new_y;
+            z = new_z;
+        }
+
+        Self::checked_from_i128s(
+            x + self.x.to_i128(),
+            y + self.y.to_i128(),
+            z + self.z.to_i128(),
+        )
+    }
+
+    /// Rotate a point n times clockwise about the calling instance,
+    /// returning `None` if the result can't be represented in the coordinate
+    /// width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let point = Cube::force_from_coords(1, 0, -1);
+    /// let edge = Cube::force_from_coords(i32::MAX, 0, -i32::MAX);
+    ///
+    /// assert_eq!(
+    ///     Cube::ORIGIN.checked_rotate_cw(point, 1),
+    ///     Some(Cube::force_from_coords(1, -1, 0))
+    /// );
+    /// assert_eq!(Cube::ORIGIN.checked_rotate_cw(edge, 3), Some(edge * -1));
+    /// assert_eq!((point * -1).checked_rotate_cw(edge, 3), None);
+    /// ```
+    pub fn checked_rotate_cw(
+        self,
+        point: Self,
+        num_turns: u32,
+    ) -> Option<Self> {
+        self.checked_rotate(point, num_turns)
+    }
+
+    /// Rotate a point n times counter-clockwise about the calling instance,
+    /// returning `None` if the result can't be represented in the coordinate
+    /// width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let point = Cube::force_from_coords(1, 0, -1);
+    /// let edge = Cube::force_from_coords(i32::MAX, 0, -i32::MAX);
+    ///
+    /// assert_eq!(
+    ///     Cube::ORIGIN.checked_rotate_cc(point, 1),
+    ///     Some(Cube::force_from_coords(0, 1, -1))
+    /// );
+    /// assert_eq!((point * -1).checked_rotate_cc(edge, 3), None);
+    /// ```
+    pub fn checked_rotate_cc(
+        self,
+        point: Self,
+        num_turns: u32,
+    ) -> Option<Self> {
+        self.checked_rotate(point, 6 - num_turns % 6)
+    }
+
     //////////////////////////////////
     // Rings
     //////////////////////////////////
 
     /// Return the coordinates comprising a ring with a given radisu about the
     /// calling instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring extends beyond the limits of the coordinate width.
     pub fn ring(self, radius: u32) -> Vec<Self> {
         let mut coords = Vec::new();
 
@@ -556,16 +972,16 @@ impl Cube {
             coords = vec![self];
         } else {
             let init_index = 2;
+            let scale = T::from_i128(i128::from(radius))
+                .expect("ring radius exceeds coordinate width");
 
-            for side in 0..6 {
-                let mut next_coord: Cube =
-                    (radius as i32) * Cube::from(Cube::NEIGHBOR_OFFSETS[side])
-                    + self;
+            for (side, &offset) in Self::NEIGHBOR_OFFSETS.iter().enumerate() {
+                let mut next_coord = Self::from_offset(offset) * scale + self;
                 let side_dir = (side + init_index) % 6;
 
                 for _coord in 0..radius {
                     coords.push(next_coord);
-                    next_coord = next_coord.neighbors()[side_dir];
+                    next_coord = next_coord.neighbor(side_dir);
                 }
             }
         }
@@ -573,6 +989,54 @@ impl Cube {
         coords
     }
 
+    /// Return the coordinates comprising a ring with a given radius about the
+    /// calling instance, or `None` if any of them can't be represented in
+    /// the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let edge: Cube<i8> = Cube::force_from_coords(125, 0, -125);
+    ///
+    /// assert_eq!(edge.checked_ring(2), Some(edge.ring(2)));
+    /// assert_eq!(edge.checked_ring(3), None);
+    /// ```
+    pub fn checked_ring(self, radius: u32) -> Option<Vec<Self>> {
+        let scale = T::from_i128(i128::from(radius))?;
+
+        // Every hex in a ring lies between two of its corners, so checking
+        // the corners is sufficient.
+        for &offset in Self::NEIGHBOR_OFFSETS.iter() {
+            Self::from_offset(offset)
+                .checked_mul(scale)?
+                .checked_add(self)?;
+        }
+
+        Some(self.ring(radius))
+    }
+
+    /// Return all coordinates up to and within a ring with the given radius
+    /// about the calling instance, or `None` if any of them can't be
+    /// represented in the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::cube::Cube;
+    ///
+    /// let edge: Cube<i8> = Cube::force_from_coords(-126, 63, 63);
+    ///
+    /// assert_eq!(edge.checked_spiral(2), Some(edge.spiral(2)));
+    /// assert_eq!(edge.checked_spiral(3), None);
+    /// ```
+    pub fn checked_spiral(self, radius: u32) -> Option<Vec<Self>> {
+        self.checked_ring(radius)?;
+
+        Some(self.spiral(radius))
+    }
+
     /// Return all coordinates up to and within a ring with the given radius
     /// about the calling instance. Spirals outwards, so the first element of
     /// the returned vector is the calling instance.
@@ -623,6 +1087,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cube_arithmetic_widths() {
+        let narrow: Cube<i16> = Cube::force_from_coords(1, 2, -3);
+        let wide: Cube<i64> = Cube::force_from_coords(1, 2, -3);
+
+        assert_eq!(3 * narrow, Cube::force_from_coords(3, 6, -9));
+        assert_eq!(wide * 3, Cube::force_from_coords(3, 6, -9));
+        assert_eq!(narrow - narrow, Cube::ORIGIN);
+        assert_eq!(wide + wide, Cube::force_from_coords(2, 4, -6));
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(unused_variables)]
+    fn test_cube_add_overflow() {
+        let max: Cube<i16> = Cube::force_from_coords(i16::MAX, 0, -i16::MAX);
+        let cube = max + Cube::force_from_coords(1, -1, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(unused_variables)]
+    fn test_cube_mul_overflow() {
+        let big: Cube<i8> = Cube::force_from_coords(100, -50, -50);
+        let cube = big * 2;
+    }
+
+    //////////////////////////////////
+    // Checked Arithmetic
+    //////////////////////////////////
+
+    #[test]
+    fn test_cube_checked_arithmetic() {
+        let edge: Cube<i8> = Cube::force_from_coords(127, -127, 0);
+        let unit = Cube::force_from_coords(1, -1, 0);
+
+        assert_eq!(edge.checked_add(unit), None);
+        assert_eq!(
+            edge.checked_sub(unit),
+            Some(Cube::force_from_coords(126, -126, 0))
+        );
+        assert_eq!(edge.checked_mul(-1), Some(edge * -1));
+        assert_eq!(edge.checked_mul(2), None);
+        let min: Cube<i8> = Cube::force_from_coords(-128, 64, 64);
+
+        assert_eq!(Cube::ORIGIN.checked_sub(min), None);
+    }
+
+    //////////////////////////////////
+    // Saturating Arithmetic
+    //////////////////////////////////
+
+    #[test]
+    fn test_cube_saturating_arithmetic_is_valid() {
+        let coords: Vec<Cube<i8>> = Cube::ORIGIN.spiral(3);
+
+        for &a in coords.iter() {
+            for n in [-128, -100, -1, 0, 1, 50, 127].iter() {
+                let scaled = a.saturating_mul(*n);
+                let (x, y, z) = scaled.to_tuple();
+
+                assert!(Cube::from_coords(x, y, z).is_ok(), "{:?}", scaled);
+
+                let shifted = scaled.saturating_add(a.saturating_mul(100));
+                let (x, y, z) = shifted.to_tuple();
+
+                assert!(Cube::from_coords(x, y, z).is_ok(), "{:?}", shifted);
+
+                let shifted = scaled.saturating_sub(a.saturating_mul(100));
+                let (x, y, z) = shifted.to_tuple();
+
+                assert!(Cube::from_coords(x, y, z).is_ok(), "{:?}", shifted);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cube_saturating_matches_exact_in_range() {
+        let a = Cube::force_from_coords(3, -5, 2);
+        let b = Cube::force_from_coords(-7, 1, 6);
+
+        assert_eq!(a.saturating_add(b), a + b);
+        assert_eq!(a.saturating_sub(b), a - b);
+        assert_eq!(a.saturating_mul(-4), a * -4);
+    }
+
     //////////////////////////////////
     // Traits: From & Into
     //////////////////////////////////
@@ -657,14 +1207,59 @@ mod tests {
         let cube = Cube::from((1, 0, 0));
     }
 
+    #[test]
+    fn test_cube_from_widening() {
+        let narrow: Cube<i8> = Cube::force_from_coords(-7, 3, 4);
+
+        assert_eq!(
+            Cube::<i16>::from(narrow),
+            Cube::force_from_coords(-7, 3, 4)
+        );
+        assert_eq!(
+            Cube::<i32>::from(narrow),
+            Cube::force_from_coords(-7, 3, 4)
+        );
+        assert_eq!(
+            Cube::<i64>::from(Cube::<i32>::from(narrow)),
+            Cube::force_from_coords(-7, 3, 4)
+        );
+    }
+
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
 
+    #[test]
+    fn test_cube_from_coords_extremes() {
+        assert!(Cube::from_coords(i32::MAX, 1, i32::MIN).is_ok());
+        assert!(Cube::from_coords(i32::MAX, i32::MAX, 0).is_err());
+        assert!(Cube::from_coords(i8::MIN, 0, i8::MIN).is_err());
+    }
+
     //////////////////////////////////
     // Conversion
     //////////////////////////////////
 
+    #[test]
+    fn test_cube_cast() {
+        let coord = Cube::force_from_coords(200, -100, -100);
+
+        assert_eq!(
+            coord.cast::<i16>(),
+            Ok(Cube::force_from_coords(200, -100, -100))
+        );
+        assert_eq!(
+            coord.cast::<i64>(),
+            Ok(Cube::force_from_coords(200, -100, -100))
+        );
+        assert!(coord.cast::<i8>().is_err());
+
+        let far: Cube<i64> = Cube::force_from_coords(1 << 40, 0, -(1 << 40));
+
+        assert!(far.cast::<i32>().is_err());
+        assert_eq!(far.cast::<i64>(), Ok(far));
+    }
+
     #[test]
     fn test_cube_from_integers() {
         assert_eq!(
@@ -696,7 +1291,7 @@ mod tests {
     #[test]
     fn test_cube_from_axial() {
         assert_eq!(
-            Cube::ORIGIN,
+            Cube::<i32>::ORIGIN,
             Cube::from(Axial::ORIGIN),
             "Cube::ORIGIN != Axial::ORIGIN"
         );
@@ -734,10 +1329,49 @@ mod tests {
     // Distances
     //////////////////////////////////
 
+    #[test]
+    fn test_cube_checked_dist() {
+        let a = Cube::force_from_coords(3, -5, 2);
+        let b = Cube::force_from_coords(-7, 1, 6);
+        let min: Cube<i16> = Cube::force_from_coords(i16::MIN, i16::MAX, 1);
+        let max: Cube<i16> = Cube::force_from_coords(i16::MAX, -i16::MAX, 0);
+
+        assert_eq!(a.checked_dist(b), Some(a.dist(b)));
+        assert_eq!(min.checked_dist(Cube::ORIGIN), None);
+        assert_eq!(min.checked_dist(max), None);
+        assert_eq!(max.checked_dist(Cube::ORIGIN), Some(i16::MAX));
+    }
+
     //////////////////////////////////
     // Rotation
     //////////////////////////////////
 
+    #[test]
+    fn test_cube_checked_rotation() {
+        let center = Cube::force_from_coords(2, -1, -1);
+        let point = Cube::force_from_coords(4, -5, 1);
+
+        for turns in 0..8 {
+            assert_eq!(
+                center.checked_rotate_cw(point, turns),
+                Some(center.rotate_cw(point, turns))
+            );
+            assert_eq!(
+                center.checked_rotate_cc(point, turns),
+                Some(center.rotate_cc(point, turns))
+            );
+        }
+
+        let edge: Cube<i8> = Cube::force_from_coords(120, -60, -60);
+
+        assert_eq!(
+            Cube::ORIGIN.checked_rotate_cw(edge, 2),
+            Some(Cube::force_from_coords(-60, -60, 120))
+        );
+        assert_eq!(edge.checked_rotate_cw(Cube::ORIGIN, 3), None);
+        assert_eq!(edge.checked_rotate_cc(Cube::ORIGIN, 3), None);
+    }
+
     #[test]
     fn test_cube_neighbors() {
         let exp_origin_neighbors = vec![
@@ -932,7 +1566,7 @@ mod tests {
 
     #[test]
     fn test_cube_rings() {
-        let origin_ring_0 = vec![Cube::ORIGIN];
+        let origin_ring_0: Vec<Cube> = vec![Cube::ORIGIN];
         let origin_ring_1 = vec![
             Cube::force_from_coords(1, 0, -1),
             Cube::force_from_coords(1, -1, 0),
@@ -1023,9 +1657,27 @@ mod tests {
         assert_eq!(offset_ring_2, offset_coord.ring(2), "offset ring 2");
     }
 
+    #[test]
+    fn test_cube_checked_rings() {
+        let coord = Cube::force_from_coords(2, 3, -5);
+
+        assert_eq!(coord.checked_ring(0), Some(vec![coord]));
+        assert_eq!(coord.checked_ring(4), Some(coord.ring(4)));
+        assert_eq!(coord.checked_spiral(4), Some(coord.spiral(4)));
+
+        let edge: Cube<i16> =
+            Cube::force_from_coords(0, i16::MAX - 2, 2 - i16::MAX);
+
+        assert_eq!(edge.checked_ring(2), Some(edge.ring(2)));
+        assert_eq!(edge.checked_ring(3), None);
+        assert_eq!(edge.checked_spiral(3), None);
+        assert_eq!(Cube::<i8>::ORIGIN.checked_ring(128), None);
+        assert_eq!(Cube::<i32>::ORIGIN.checked_ring(u32::MAX), None);
+    }
+
     #[test]
     fn test_cube_spirals() {
-        let origin_spiral_0 = vec![Cube::ORIGIN];
+        let origin_spiral_0: Vec<Cube> = vec![Cube::ORIGIN];
         let origin_spiral_1 = vec![
             Cube::ORIGIN,
             Cube::force_from_coords(1, 0, -1),
@@ -1,7 +1,9 @@
 //! Double Coordinates
 
 use std::cmp;
+use std::fmt;
 use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
 
 use super::*;
 
@@ -22,9 +24,9 @@ use super::*;
 ///
 /// `Double`s are opaque, to enforce constraints.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Double {
-    col: i32,
-    row: i32,
+pub struct Double<T = i32> {
+    col: T,
+    row: T,
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -33,6 +35,10 @@ pub struct Double {
 
 /// Adds two `Double` coordinates in the same manner as vectors.
 ///
+/// # Panics
+///
+/// Panics if either value overflows.
+///
 /// # Examples
 ///
 /// ```
@@ -43,19 +49,23 @@ pub struct Double {
 ///     Double::force_from_coords(1, 7)
 /// );
 /// ```
-impl Add for Double {
+impl<T: CoordInt> Add for Double<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         Self {
-            col: self.col + other.col,
-            row: self.row + other.row,
+            col: self.col.checked_add(other.col).expect("Double overflowed"),
+            row: self.row.checked_add(other.row).expect("Double overflowed"),
         }
     }
 }
 
 /// Subtracts two `Double` coordinates in the same manner as vectors.
 ///
+/// # Panics
+///
+/// Panics if either value overflows.
+///
 /// # Examples
 ///
 /// ```
@@ -66,18 +76,22 @@ impl Add for Double {
 ///     Double::force_from_coords(-3, -1)
 /// );
 /// ```
-impl Sub for Double {
+impl<T: CoordInt> Sub for Double<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         Self {
-            col: self.col - other.col,
-            row: self.row - other.row,
+            col: self.col.checked_sub(other.col).expect("Double overflowed"),
+            row: self.row.checked_sub(other.row).expect("Double overflowed"),
         }
     }
 }
 
-/// Multiplies a `Double` coordinate by an `i32` scalar, like a vector.
+/// Multiplies a `Double` coordinate by a scalar, like a vector.
+///
+/// # Panics
+///
+/// Panics if either value overflows.
 ///
 /// # Examples
 ///
@@ -97,30 +111,38 @@ impl Sub for Double {
 ///     Double::force_from_coords(-1, -3)
 /// );
 /// ```
-impl Mul<i32> for Double {
+impl<T: CoordInt> Mul<T> for Double<T> {
     type Output = Self;
 
-    fn mul(self, n: i32) -> Self {
+    fn mul(self, n: T) -> Self {
         Self {
-            col: self.col * n,
-            row: self.row * n,
+            col: self.col.checked_mul(n).expect("Double mul overflowed"),
+            row: self.row.checked_mul(n).expect("Double mul overflowed"),
         }
     }
 }
 
-impl Mul<Double> for i32 {
-    type Output = Double;
+macro_rules! impl_scalar_mul {
+    ($($int:ty),*) => {
+        $(
+            impl Mul<Double<$int>> for $int {
+                type Output = Double<$int>;
 
-    fn mul(self, coord: Double) -> Double {
-        coord * self
-    }
+                fn mul(self, coord: Double<$int>) -> Double<$int> {
+                    coord * self
+                }
+            }
+        )*
+    };
 }
 
+impl_scalar_mul!(i8, i16, i32, i64);
+
 //////////////////////////////////////////////////////////////////////////////
 // Traits: From & Into
 //////////////////////////////////////////////////////////////////////////////
 
-/// Creates a `Double` from an `(i32, i32)`.
+/// Creates a `Double` from a `(T, T)`.
 ///
 /// # Panics
 ///
@@ -137,10 +159,10 @@ impl Mul<Double> for i32 {
 /// assert_eq!(double.col(), 0);
 /// assert_eq!(double.row(), 2);
 /// ```
-impl From<(i32, i32)> for Double {
-    fn from((col, row): (i32, i32)) -> Self {
-        if (col + row) & 1 == 0 {
-            Self { col: col, row: row }
+impl<T: CoordInt> From<(T, T)> for Double<T> {
+    fn from((col, row): (T, T)) -> Self {
+        if Self::is_valid(col, row) {
+            Self { col, row }
         } else {
             panic!("({}, {}) is an invalid double coordinate", col, row);
         }
@@ -165,23 +187,75 @@ impl From<MultiCoord> for Double {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Traits: Display & FromStr
+//////////////////////////////////////////////////////////////////////////////
+
+/// Formats a `Double` as `double(col,row)`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::double::Double;
+///
+/// let double = Double::force_from_coords(-3, 5);
+///
+/// assert_eq!(double.to_string(), "double(-3,5)");
+/// ```
+impl<T: CoordInt> fmt::Display for Double<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "double({},{})", self.col, self.row)
+    }
+}
+
+/// Parses a `Double` from the form `double(col,row)`, validating the
+/// constraint (col + row) % 2 == 0.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::double::Double;
+///
+/// assert_eq!("double(-3,5)".parse(), Ok(Double::force_from_coords(-3, 5)));
+/// assert!("double(-3,4)".parse::<Double>().is_err());
+/// ```
+impl<T: CoordInt> FromStr for Double<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = ParsedCoord::parse_sys(s, CoordSys::Double)?;
+
+        Self::from_coords(parsed.value(0)?, parsed.value(1)?)
+            .map_err(|_| parsed.constraint_error())
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 // Methods
 //////////////////////////////////////////////////////////////////////////////
 
-impl Double {
+impl<T: CoordInt> Double<T> {
     //////////////////////////////////
     // Constants
     //////////////////////////////////
 
     /// `Double` coordinate origin of (0, 0).
-    pub const ORIGIN: Double = Double { col: 0, row: 0 };
+    pub const ORIGIN: Self = Double { col: T::ZERO, row: T::ZERO };
+
+    //////////////////////////////////
+    // Utilities
+    //////////////////////////////////
+
+    /// Checks the constraint (col + row) % 2 == 0 without risk of overflow.
+    fn is_valid(col: T, row: T) -> bool {
+        (col.to_i128() + row.to_i128()) & 1 == 0
+    }
 
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
 
-    /// Attempts to create a `Double` from two `i32` values. If these values
+    /// Attempts to create a `Double` from two integer values. If these values
     /// obey the `Double` constraint, (row + col) % 2 == 0, then returns that
     /// `Double` in an `Ok`. Otherwise, returns an `Err`.
     ///
@@ -200,19 +274,19 @@ impl Double {
     ///
     /// assert!(double_2.is_err());
     /// ```
-    pub fn from_coords(col: i32, row: i32) -> CoordResult<Double> {
-        if (col + row) & 1 == 0 {
-            Ok(Self { col: col, row: row })
+    pub fn from_coords(col: T, row: T) -> CoordResult<Self> {
+        if Self::is_valid(col, row) {
+            Ok(Self { col, row })
         } else {
             Err("invalid Double coordinate")
         }
     }
 
-    /// Creates a `Double` from two `i32` values.
+    /// Creates a `Double` from two integer values.
     ///
     /// # Panics
     ///
-    /// Panics if the given `i32` values fail to obey the constraint
+    /// Panics if the given values fail to obey the constraint
     /// (row + col) % 2 == 0.
     ///
     /// # Examples
@@ -225,11 +299,11 @@ impl Double {
     /// assert_eq!(double_1.col(), 1);
     /// assert_eq!(double_1.row(), 3);
     /// ```
-    pub fn force_from_coords(col: i32, row: i32) -> Double {
+    pub fn force_from_coords(col: T, row: T) -> Self {
         Double::from_coords(col, row).unwrap()
     }
 
-    /// Attempts to create a `Double` from a tuple of two `i32` values. If
+    /// Attempts to create a `Double` from a tuple of two integer values. If
     /// these values obey the `Double` constraint, (row + col) % 2 == 0, then
     /// returns that `Double` in an `Ok`. Otherwise, returns an `Err`.
     ///
@@ -248,7 +322,7 @@ impl Double {
     ///
     /// assert!(double_2.is_err());
     /// ```
-    pub fn from_tuple((col, row): (i32, i32)) -> CoordResult<Double> {
+    pub fn from_tuple((col, row): (T, T)) -> CoordResult<Self> {
         Double::from_coords(col, row)
     }
 
@@ -257,12 +331,12 @@ impl Double {
     //////////////////////////////////
 
     /// Return the column value of a `Double`.
-    pub fn col(&self) -> i32 {
+    pub fn col(&self) -> T {
         self.col
     }
 
     /// Return the row value of a `Double`.
-    pub fn row(&self) -> i32 {
+    pub fn row(&self) -> T {
         self.row
     }
 
@@ -270,36 +344,280 @@ impl Double {
     // Conversion
     //////////////////////////////////
 
+    /// Attempts to convert a `Double` to a different integer width. Returns
+    /// an `Err` if either value doesn't fit in the target width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let coord = Double::force_from_coords(-128, 130);
+    ///
+    /// assert!(coord.cast::<i16>().is_ok());
+    /// assert!(coord.cast::<i8>().is_err());
+    /// ```
+    pub fn cast<U: CoordInt>(self) -> CoordResult<Double<U>> {
+        let col = U::from_i128(self.col.to_i128());
+        let row = U::from_i128(self.row.to_i128());
+
+        match (col, row) {
+            (Some(col), Some(row)) => Ok(Double { col, row }),
+            _ => Err("Double coordinate out of range for target width"),
+        }
+    }
+
     /// Convert a `Double` to a `Cube`, assuming the grid has a `Tilt::Flat`.
-    pub fn flat_to_cube(self) -> Cube {
+    pub fn flat_to_cube(self) -> Cube<T> {
         let x = self.col;
-        let z = (self.row - self.col) / 2;
-        let y = 0 - x - z;
+        let z = (self.row - self.col) / T::TWO;
+        let y = T::ZERO - x - z;
 
         Cube::force_from_coords(x, y, z)
     }
 
     /// Convert a `Double` to a `Cube`, assuming the grid has a `Tilt::Sharp`.
-    pub fn sharp_to_cube(self) -> Cube {
-        let x = (self.col - self.row) / 2;
-        let z = self.row / 2;
-        let y = 0 - x - z;
+    pub fn sharp_to_cube(self) -> Cube<T> {
+        let x = (self.col - self.row) / T::TWO;
+        let z = self.row;
+        let y = T::ZERO - x - z;
 
         Cube::force_from_coords(x, y, z)
     }
 
+    /// Convert a `Cube` to a `Double`, assuming the grid has a `Tilt::Flat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::{Cube, Double};
+    ///
+    /// let double = Double::force_from_coords(3, -5);
+    ///
+    /// assert_eq!(Double::cube_to_flat(double.flat_to_cube()), double);
+    /// ```
+    pub fn cube_to_flat(coord: Cube<T>) -> Self {
+        let (x, _, z) = coord.to_tuple();
+
+        Double { col: x, row: z * T::TWO + x }
+    }
+
+    /// Convert a `Cube` to a `Double`, assuming the grid has a `Tilt::Sharp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::{Cube, Double};
+    ///
+    /// let double = Double::force_from_coords(3, -5);
+    ///
+    /// assert_eq!(Double::cube_to_sharp(double.sharp_to_cube()), double);
+    /// ```
+    pub fn cube_to_sharp(coord: Cube<T>) -> Self {
+        let (x, _, z) = coord.to_tuple();
+
+        Double { col: x * T::TWO + z, row: z }
+    }
+
+    //////////////////////////////////
+    // Checked Arithmetic
+    //////////////////////////////////
+
+    /// Creates a `Double` from exact `i128` values which already obey the
+    /// `Double` constraint, returning `None` if either doesn't fit in the
+    /// coordinate width.
+    fn checked_from_i128s(col: i128, row: i128) -> Option<Self> {
+        Some(Double {
+            col: T::from_i128(col)?,
+            row: T::from_i128(row)?,
+        })
+    }
+
+    /// Adds two `Double` coordinates, returning `None` upon overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let coord = Double::force_from_coords(1, 3);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_add(coord),
+    ///     Some(Double::force_from_coords(2, 6))
+    /// );
+    /// assert_eq!(
+    ///     coord.checked_add(Double::force_from_coords(i32::MAX, 1)),
+    ///     None
+    /// );
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.col.to_i128() + other.col.to_i128(),
+            self.row.to_i128() + other.row.to_i128(),
+        )
+    }
+
+    /// Subtracts two `Double` coordinates, returning `None` upon overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let coord = Double::force_from_coords(1, 3);
+    ///
+    /// assert_eq!(coord.checked_sub(coord), Some(Double::ORIGIN));
+    /// assert_eq!(
+    ///     coord.checked_sub(Double::force_from_coords(-i32::MAX, 1)),
+    ///     None
+    /// );
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.col.to_i128() - other.col.to_i128(),
+            self.row.to_i128() - other.row.to_i128(),
+        )
+    }
+
+    /// Multiplies a `Double` coordinate by a scalar, returning `None` upon
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let coord = Double::force_from_coords(1, 3);
+    ///
+    /// assert_eq!(
+    ///     coord.checked_mul(-2),
+    ///     Some(Double::force_from_coords(-2, -6))
+    /// );
+    /// assert_eq!(coord.checked_mul(i32::MAX), None);
+    /// ```
+    pub fn checked_mul(self, n: T) -> Option<Self> {
+        Self::checked_from_i128s(
+            self.col.to_i128() * n.to_i128(),
+            self.row.to_i128() * n.to_i128(),
+        )
+    }
+
+    //////////////////////////////////
+    // Saturating Arithmetic
+    //////////////////////////////////
+
+    /// Creates a `Double` from exact `i128` values which obey the `Double`
+    /// constraint. Values are clamped to the limits of the coordinate width.
+    /// If clamping breaks the constraint, a clamped value is stepped once
+    /// toward zero to restore it.
+    fn saturating_from_i128s(col: i128, row: i128) -> Self {
+        let mut sat_col = T::saturating_from_i128(col);
+        let mut sat_row = T::saturating_from_i128(row);
+
+        if !Self::is_valid(sat_col, sat_row) {
+            let toward_zero = |n: T| {
+                if n > T::ZERO {
+                    n - T::ONE
+                } else {
+                    n + T::ONE
+                }
+            };
+
+            if sat_row.to_i128() != row {
+                sat_row = toward_zero(sat_row);
+            } else {
+                sat_col = toward_zero(sat_col);
+            }
+        }
+
+        Double { col: sat_col, row: sat_row }
+    }
+
+    /// Adds two `Double` coordinates, clamping the result to a valid
+    /// `Double` within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let edge = Double::force_from_coords(i32::MAX - 3, 0);
+    ///
+    /// // i32::MAX is odd, so the column saturates one step short of it
+    /// assert_eq!(
+    ///     edge.saturating_add(Double::force_from_coords(4, 0)),
+    ///     Double::force_from_coords(i32::MAX - 1, 0)
+    /// );
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::saturating_from_i128s(
+            self.col.to_i128() + other.col.to_i128(),
+            self.row.to_i128() + other.row.to_i128(),
+        )
+    }
+
+    /// Subtracts two `Double` coordinates, clamping the result to a valid
+    /// `Double` within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let edge = Double::force_from_coords(0, i32::MIN + 2);
+    ///
+    /// assert_eq!(
+    ///     edge.saturating_sub(Double::force_from_coords(1, 5)),
+    ///     Double::force_from_coords(-1, i32::MIN + 1)
+    /// );
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::saturating_from_i128s(
+            self.col.to_i128() - other.col.to_i128(),
+            self.row.to_i128() - other.row.to_i128(),
+        )
+    }
+
+    /// Multiplies a `Double` coordinate by a scalar, clamping the result to
+    /// a valid `Double` within the limits of the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let coord: Double<i8> = Double::force_from_coords(1, 1);
+    /// let wide: Double<i8> = Double::force_from_coords(2, 0);
+    ///
+    /// assert_eq!(
+    ///     coord.saturating_mul(-128),
+    ///     Double::force_from_coords(-128, -128)
+    /// );
+    /// assert_eq!(
+    ///     wide.saturating_mul(100),
+    ///     Double::force_from_coords(126, 0)
+    /// );
+    /// ```
+    pub fn saturating_mul(self, n: T) -> Self {
+        Self::saturating_from_i128s(
+            self.col.to_i128() * n.to_i128(),
+            self.row.to_i128() * n.to_i128(),
+        )
+    }
+
     //////////////////////////////////
     // Neighbors
     //////////////////////////////////
 
-    fn offset_map(self, offsets: [[i32; 2]; 6]) -> Vec<Self> {
+    fn offset_map(self, offsets: [[i8; 2]; 6]) -> Vec<Self> {
         let mut neighbors = Vec::new();
 
-        for side in 0..6 {
-            let col = offsets[side][0] + self.col;
-            let row = offsets[side][1] + self.row;
+        for offset_pair in offsets.iter() {
+            let col = T::from_i8(offset_pair[0]) + self.col;
+            let row = T::from_i8(offset_pair[1]) + self.row;
 
-            neighbors.push(Double { col: col, row: row });
+            neighbors.push(Double { col, row });
         }
 
         neighbors
@@ -364,11 +682,33 @@ impl Double {
     ///     coord_1.sharp_dist(coord_3)
     /// );
     /// ```
-    pub fn flat_dist(self, other: Self) -> i32 {
+    pub fn flat_dist(self, other: Self) -> T {
         let x_dist = (self.col - other.col).abs();
         let y_dist = (self.row - other.row).abs();
 
-        x_dist + cmp::max(0, (y_dist - x_dist) / 2)
+        x_dist + cmp::max(T::ZERO, (y_dist - x_dist) / T::TWO)
+    }
+
+    /// Calculates the distance between two `Double` coordinates in the
+    /// context of a `HexGrid` whose hexes have the `Tilt::Flat` orientation,
+    /// returning `None` if the distance can't be represented in the
+    /// coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let edge = Double::force_from_coords(i32::MAX, 1);
+    ///
+    /// assert_eq!(edge.checked_flat_dist(edge), Some(0));
+    /// assert_eq!(edge.checked_flat_dist(edge * -1), None);
+    /// ```
+    pub fn checked_flat_dist(self, other: Self) -> Option<T> {
+        let x_dist = (self.col.to_i128() - other.col.to_i128()).abs();
+        let y_dist = (self.row.to_i128() - other.row.to_i128()).abs();
+
+        T::from_i128(x_dist + cmp::max(0, (y_dist - x_dist) / 2))
     }
 
     /// Calculates the distance between two `Double` coordinates in the
@@ -408,11 +748,33 @@ impl Double {
     ///     coord_1.flat_dist(coord_3)
     /// );
     /// ```
-    pub fn sharp_dist(self, other: Self) -> i32 {
+    pub fn sharp_dist(self, other: Self) -> T {
         let x_dist = (self.col - other.col).abs();
         let y_dist = (self.row - other.row).abs();
 
-        y_dist + cmp::max(0, (x_dist - y_dist) / 2)
+        y_dist + cmp::max(T::ZERO, (x_dist - y_dist) / T::TWO)
+    }
+
+    /// Calculates the distance between two `Double` coordinates in the
+    /// context of a `HexGrid` whose hexes have the `Tilt::Sharp`
+    /// orientation, returning `None` if the distance can't be represented in
+    /// the coordinate width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::double::Double;
+    ///
+    /// let edge = Double::force_from_coords(i32::MAX, 1);
+    ///
+    /// assert_eq!(edge.checked_sharp_dist(edge), Some(0));
+    /// assert_eq!(edge.checked_sharp_dist(edge * -1), None);
+    /// ```
+    pub fn checked_sharp_dist(self, other: Self) -> Option<T> {
+        let x_dist = (self.col.to_i128() - other.col.to_i128()).abs();
+        let y_dist = (self.row.to_i128() - other.row.to_i128()).abs();
+
+        T::from_i128(y_dist + cmp::max(0, (x_dist - y_dist) / 2))
     }
 }
 
@@ -464,6 +826,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::erasing_op, clippy::identity_op)]
     fn test_mul_trait() {
         let d1 = Double::force_from_coords(1, 3);
         let d2 = Double::force_from_coords(-2, 8);
@@ -481,6 +844,69 @@ mod tests {
         assert_eq!(14 * d3, Double::force_from_coords(-42, -14));
     }
 
+    #[test]
+    #[should_panic]
+    #[allow(unused_variables)]
+    fn test_sub_overflow() {
+        let min: Double<i16> = Double::force_from_coords(i16::MIN, 0);
+        let double = min - Double::force_from_coords(2, 0);
+    }
+
+    //////////////////////////////////
+    // Checked Arithmetic
+    //////////////////////////////////
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let edge: Double<i8> = Double::force_from_coords(126, -128);
+        let unit = Double::force_from_coords(1, 1);
+
+        assert_eq!(
+            edge.checked_add(unit),
+            Some(Double { col: 127, row: -127 })
+        );
+        assert_eq!(edge.checked_sub(unit), None);
+        assert_eq!(edge.checked_mul(1), Some(edge));
+        assert_eq!(edge.checked_mul(-1), None);
+    }
+
+    //////////////////////////////////
+    // Saturating Arithmetic
+    //////////////////////////////////
+
+    #[test]
+    fn test_saturating_arithmetic_is_valid() {
+        let coords: Vec<Double<i8>> = vec![
+            Double { col: 1, row: 1 },
+            Double { col: 1, row: -3 },
+            Double { col: 2, row: 0 },
+            Double { col: 0, row: -2 },
+            Double { col: 126, row: -128 },
+        ];
+
+        for &a in coords.iter() {
+            for &b in coords.iter() {
+                for n in [-128, -3, 0, 5, 127].iter() {
+                    let results = [
+                        a.saturating_add(b),
+                        a.saturating_sub(b),
+                        a.saturating_mul(*n),
+                    ];
+
+                    for result in results.iter() {
+                        let (col, row) = (result.col, result.row);
+
+                        assert!(
+                            Double::from_coords(col, row).is_ok(),
+                            "{:?}",
+                            result
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     //////////////////////////////////
     // Traits: From & Into
     //////////////////////////////////
@@ -492,6 +918,20 @@ mod tests {
         assert_eq!(Double::from((2, 8)), Double { col: 2, row: 8 });
     }
 
+    #[test]
+    fn test_double_from_widening() {
+        let narrow: Double<i16> = Double::force_from_coords(-301, 7);
+
+        assert_eq!(
+            Double::<i32>::from(narrow),
+            Double { col: -301, row: 7 }
+        );
+        assert_eq!(
+            Double::<i64>::from(narrow),
+            Double { col: -301, row: 7 }
+        );
+    }
+
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
@@ -592,6 +1032,25 @@ mod tests {
         assert!(err_double_5.is_err());
     }
 
+    #[test]
+    fn test_from_coords_extremes() {
+        assert!(Double::from_coords(i32::MAX, 1).is_ok());
+        assert!(Double::from_coords(i32::MAX, i32::MAX).is_ok());
+        assert!(Double::from_coords(i32::MIN, i32::MAX).is_err());
+    }
+
+    //////////////////////////////////
+    // Conversion
+    //////////////////////////////////
+
+    #[test]
+    fn test_cast() {
+        let coord = Double::force_from_coords(127, -129);
+
+        assert_eq!(coord.cast::<i16>(), Ok(Double { col: 127, row: -129 }));
+        assert!(coord.cast::<i8>().is_err());
+    }
+
     //////////////////////////////////
     // Retrieval
     //////////////////////////////////
@@ -696,6 +1155,27 @@ mod tests {
     // Distance
     //////////////////////////////////
 
+    #[test]
+    fn test_checked_dists() {
+        let coord_1 = Double { col: 1, row: 3 };
+        let coord_2 = Double { col: 12, row: 6 };
+        let min: Double<i16> = Double { col: i16::MIN, row: 0 };
+
+        assert_eq!(
+            coord_1.checked_flat_dist(coord_2),
+            Some(coord_1.flat_dist(coord_2))
+        );
+        assert_eq!(
+            coord_1.checked_sharp_dist(coord_2),
+            Some(coord_1.sharp_dist(coord_2))
+        );
+        assert_eq!(min.checked_flat_dist(Double::ORIGIN), None);
+        assert_eq!(
+            min.checked_sharp_dist(Double::ORIGIN),
+            Some(i16::MIN / -2)
+        );
+    }
+
     #[test]
     fn test_flat_dist() {
         let coord_1 = Double { col: 1, row: 3 };
@@ -61,10 +61,48 @@
 //! [The Guide](https://www.redblobgames.com/grids/hexagons) for explantations
 //! of flat/sharp orientation and even/odd offsetting.
 //!
+//! # Textual Coordinates
+//! Every coordinate type, including `MultiCoord`, implements `Display` and
+//! `FromStr` using its lowercase system name followed by its values:
+//!
+//! ```
+//! use chickenwire::coordinate::{Cube, MultiCoord, Offset};
+//!
+//! let cube = Cube::force_from_coords(1, -2, 1);
+//!
+//! assert_eq!(cube.to_string(), "cube(1,-2,1)");
+//! assert_eq!("cube(1, -2, 1)".parse(), Ok(cube));
+//! assert_eq!("offset(3,4)".parse(), Ok(Offset::from_coords(3, 4)));
+//! assert_eq!("offset(3,4)".parse(), Ok(MultiCoord::offset(3, 4)));
+//!
+//! // Constraints are validated during parsing
+//! assert!("cube(1,1,1)".parse::<Cube>().is_err());
+//! ```
+//!
+//! # Coordinate Widths
+//! Every coordinate `struct` is generic over the signed integer type backing
+//! its values, which may be any `CoordInt` (`i8`, `i16`, `i32`, or `i64`).
+//! The width defaults to `i32`, so `Cube` and `Cube<i32>` name the same type.
+//!
+//! Widening conversions are lossless and use `From`, while narrowing
+//! conversions are checked via the `cast` method:
+//!
+//! ```
+//! use chickenwire::coordinate::{Axial, Cube};
+//!
+//! let packet: Cube<i16> = Cube::force_from_coords(1, 2, -3);
+//! let local: Cube = Cube::from(packet);
+//! let galaxy: Cube<i64> = Cube::from(local);
+//!
+//! assert_eq!(galaxy.cast::<i16>(), Ok(packet));
+//! assert!(Axial::<i64>::from_coords(1 << 40, 0).cast::<i32>().is_err());
+//! ```
+//!
 //! # Arithmetic
-//! The `Add<Self>`, `Sub<Self>`, and `Mul<i32>` traits are implemented for
-//! `Axial`, `Cube`, and `Double`. These operations treat the coordinates as
-//! vectors:
+//! The `Add<Self>`, `Sub<Self>`, and `Mul<T>` traits are implemented for
+//! `Axial<T>`, `Cube<T>`, and `Double<T>`. These operations treat the
+//! coordinates as vectors, and panic on overflow regardless of whether debug
+//! assertions are enabled:
 //!
 //! ```
 //! use chickenwire::coordinate::{Axial, Cube, Double};
@@ -88,6 +126,22 @@
 //! );
 //! ```
 //!
+//! Each of these types also provides `checked_*` methods, which return
+//! `None` instead of panicking, and `saturating_*` methods, which clamp the
+//! result to a valid coordinate within the limits of its width:
+//!
+//! ```
+//! use chickenwire::coordinate::Axial;
+//!
+//! let edge = Axial::from_coords(i32::MAX, 0);
+//!
+//! assert_eq!(edge.checked_add(Axial::from_coords(1, 0)), None);
+//! assert_eq!(edge.saturating_add(Axial::from_coords(1, 1)), Axial {
+//!     q: i32::MAX,
+//!     r: 1,
+//! });
+//! ```
+//!
 //! # On Neighbors
 //! The exact rule for the ordering of neighbors is that the first position
 //! which remains in the same cardinal wedge always receives the zero index,
@@ -101,10 +155,198 @@ pub mod cube;
 pub mod double;
 pub mod offset;
 
+mod parse;
+
 pub use axial::Axial;
 pub use cube::Cube;
 pub use double::Double;
 pub use offset::Offset;
+pub use parse::{ParseCoordError, ParseCoordErrorKind};
+
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, BitAnd, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+use parse::ParsedCoord;
+
+//////////////////////////////////////////////////////////////////////////////
+// Coordinate Integers
+//////////////////////////////////////////////////////////////////////////////
+
+/// A `CoordInt` is a signed integer type capable of backing the values of a
+/// coordinate `struct`. It is implemented for `i8`, `i16`, `i32`, and `i64`.
+pub trait CoordInt:
+    Add<Output = Self>
+    + BitAnd<Output = Self>
+    + Copy
+    + Debug
+    + Default
+    + Display
+    + Div<Output = Self>
+    + Eq
+    + Hash
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + Ord
+    + Rem<Output = Self>
+    + Sub<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// The value two, used when halving or doubling coordinates.
+    const TWO: Self;
+
+    /// The smallest representable value.
+    const MIN: Self;
+
+    /// The largest representable value.
+    const MAX: Self;
+
+    /// Losslessly creates a value from an `i8`.
+    fn from_i8(n: i8) -> Self;
+
+    /// Creates a value from an `i128`, returning `None` if it doesn't fit.
+    fn from_i128(n: i128) -> Option<Self>;
+
+    /// Losslessly widens the value to an `i128`.
+    fn to_i128(self) -> i128;
+
+    /// Creates a value from an `i128`, clamping it to the representable
+    /// range.
+    fn saturating_from_i128(n: i128) -> Self {
+        match Self::from_i128(n) {
+            Some(value) => value,
+            None if n < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Addition which returns `None` upon overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Subtraction which returns `None` upon overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Multiplication which returns `None` upon overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Division which returns `None` upon overflow or division by zero.
+    fn checked_div(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_coord_int {
+    ($($int:ty),*) => {
+        $(
+            impl CoordInt for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TWO: Self = 2;
+                const MIN: Self = <$int>::MIN;
+                const MAX: Self = <$int>::MAX;
+
+                fn from_i8(n: i8) -> Self {
+                    Self::from(n)
+                }
+
+                fn from_i128(n: i128) -> Option<Self> {
+                    if n < Self::MIN as i128 || n > Self::MAX as i128 {
+                        None
+                    } else {
+                        Some(n as Self)
+                    }
+                }
+
+                fn to_i128(self) -> i128 {
+                    i128::from(self)
+                }
+
+                fn abs(self) -> Self {
+                    <$int>::abs(self)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$int>::checked_div(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord_int!(i8, i16, i32, i64);
+
+//////////////////////////////////////////////////////////////////////////////
+// Width Conversion
+//////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_widening {
+    ($($narrow:ty => $wide:ty),*) => {
+        $(
+            /// Losslessly widens an `Axial`.
+            impl From<Axial<$narrow>> for Axial<$wide> {
+                fn from(coord: Axial<$narrow>) -> Self {
+                    Axial { q: coord.q.into(), r: coord.r.into() }
+                }
+            }
+
+            /// Losslessly widens a `Cube`.
+            impl From<Cube<$narrow>> for Cube<$wide> {
+                fn from(coord: Cube<$narrow>) -> Self {
+                    Cube::force_from_coords(
+                        coord.x().into(),
+                        coord.y().into(),
+                        coord.z().into(),
+                    )
+                }
+            }
+
+            /// Losslessly widens a `Double`.
+            impl From<Double<$narrow>> for Double<$wide> {
+                fn from(coord: Double<$narrow>) -> Self {
+                    Double::force_from_coords(
+                        coord.col().into(),
+                        coord.row().into(),
+                    )
+                }
+            }
+
+            /// Losslessly widens an `Offset`.
+            impl From<Offset<$narrow>> for Offset<$wide> {
+                fn from(coord: Offset<$narrow>) -> Self {
+                    Offset { col: coord.col.into(), row: coord.row.into() }
+                }
+            }
+        )*
+    };
+}
+
+impl_widening!(
+    i8 => i16,
+    i8 => i32,
+    i8 => i64,
+    i16 => i32,
+    i16 => i64,
+    i32 => i64
+);
 
 //////////////////////////////////////////////////////////////////////////////
 // Convenience Aliases
@@ -145,29 +387,29 @@ impl From<MultiCoord> for CoordSys {
 }
 
 /// Creates a `CoordSys::Axial` from an `Axial`.
-impl From<Axial> for CoordSys {
-    fn from(_: Axial) -> Self {
+impl<T> From<Axial<T>> for CoordSys {
+    fn from(_: Axial<T>) -> Self {
         CoordSys::Axial
     }
 }
 
 /// Creates a `CoordSys::Cube` from a `Cube`.
-impl From<Cube> for CoordSys {
-    fn from(_: Cube) -> Self {
+impl<T> From<Cube<T>> for CoordSys {
+    fn from(_: Cube<T>) -> Self {
         CoordSys::Cube
     }
 }
 
 /// Creates a `CoordSys::Double` from a `Double`.
-impl From<Double> for CoordSys {
-    fn from(_: Double) -> Self {
+impl<T> From<Double<T>> for CoordSys {
+    fn from(_: Double<T>) -> Self {
         CoordSys::Double
     }
 }
 
 /// Creates a `CoordSys::Offset` from an `Offset`.
-impl From<Offset> for CoordSys {
-    fn from(_: Offset) -> Self {
+impl<T> From<Offset<T>> for CoordSys {
+    fn from(_: Offset<T>) -> Self {
         CoordSys::Offset
     }
 }
@@ -237,6 +479,50 @@ impl From<Offset> for MultiCoord {
     }
 }
 
+/// Formats a `MultiCoord` in the same manner as the coordinate it holds.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::MultiCoord;
+///
+/// assert_eq!(MultiCoord::axial(1, 2).to_string(), "axial(1,2)");
+/// assert_eq!(MultiCoord::force_cube(0, 1, -1).to_string(), "cube(0,1,-1)");
+/// ```
+impl fmt::Display for MultiCoord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.sys {
+            CoordSys::Axial => write!(f, "{}", Axial::from(*self)),
+            CoordSys::Cube => write!(f, "{}", Cube::from(*self)),
+            CoordSys::Double => write!(f, "{}", Double::from(*self)),
+            CoordSys::Offset => write!(f, "{}", Offset::from(*self)),
+        }
+    }
+}
+
+/// Parses a `MultiCoord` from the textual form of any coordinate system.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::MultiCoord;
+///
+/// assert_eq!("double(1,3)".parse(), Ok(MultiCoord::force_double(1, 3)));
+/// assert!("double(1,2)".parse::<MultiCoord>().is_err());
+/// ```
+impl FromStr for MultiCoord {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match ParsedCoord::parse(s)?.sys {
+            CoordSys::Axial => s.parse::<Axial>().map(Self::from),
+            CoordSys::Cube => s.parse::<Cube>().map(Self::from),
+            CoordSys::Double => s.parse::<Double>().map(Self::from),
+            CoordSys::Offset => s.parse::<Offset>().map(Self::from),
+        }
+    }
+}
+
 impl MultiCoord {
     //////////////////////////////////
     // Instantiation
@@ -268,7 +554,7 @@ impl MultiCoord {
     /// Attempt to instantiate a `MultiCoord` using `Double` coordinates.
     pub fn double(col: i32, row: i32) -> CoordResult<Self> {
         match Double::from_coords(col, row) {
-            Ok (double) => Ok(Self::from(double)),
+            Ok(double) => Ok(Self::from(double)),
             Err(msg) => Err(msg),
         }
     }
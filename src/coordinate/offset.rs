@@ -1,5 +1,8 @@
 //! Offset Coordinates
 
+use std::fmt;
+use std::str::FromStr;
+
 use super::*;
 
 //////////////////////////////////////////////////////////////////////////////
@@ -9,22 +12,19 @@ use super::*;
 /// `Offset` coordinates treat the `HexGrid` as a square grid with offsetting
 /// indentations on the rows/columns.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Offset {
-    pub col: i32,
-    pub row: i32,
+pub struct Offset<T = i32> {
+    pub col: T,
+    pub row: T,
 }
 
 //////////////////////////////////////////////////////////////////////////////
 // Traits: From & Into
 //////////////////////////////////////////////////////////////////////////////
 
-/// Create an `Offset` from an `(i32, i32)`.
-impl From<(i32, i32)> for Offset {
-    fn from((col, row): (i32, i32)) -> Self {
-        Self {
-            col: col,
-            row: row,
-        }
+/// Create an `Offset` from a `(T, T)`.
+impl<T> From<(T, T)> for Offset<T> {
+    fn from((col, row): (T, T)) -> Self {
+        Self { col, row }
     }
 }
 
@@ -43,23 +43,62 @@ impl From<MultiCoord> for Offset {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Traits: Display & FromStr
+//////////////////////////////////////////////////////////////////////////////
+
+/// Formats an `Offset` as `offset(col,row)`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::offset::Offset;
+///
+/// assert_eq!(Offset::from_coords(3, 4).to_string(), "offset(3,4)");
+/// ```
+impl<T: CoordInt> fmt::Display for Offset<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "offset({},{})", self.col, self.row)
+    }
+}
+
+/// Parses an `Offset` from the form `offset(col,row)`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::offset::Offset;
+///
+/// assert_eq!("offset(3,4)".parse(), Ok(Offset::from_coords(3, 4)));
+/// assert!("axial(3,4)".parse::<Offset>().is_err());
+/// ```
+impl<T: CoordInt> FromStr for Offset<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = ParsedCoord::parse_sys(s, CoordSys::Offset)?;
+
+        Ok(Self::from_coords(parsed.value(0)?, parsed.value(1)?))
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 // Methods
 //////////////////////////////////////////////////////////////////////////////
 
-impl Offset {
+impl<T: CoordInt> Offset<T> {
     //////////////////////////////////
     // Constants
     //////////////////////////////////
 
     /// `Offset` coordinate origin of (0, 0).
-    pub const ORIGIN: Offset = Offset { col: 0, row: 0 };
+    pub const ORIGIN: Self = Offset { col: T::ZERO, row: T::ZERO };
 
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
 
-    /// Create an `Offset` from two `i32` values.
+    /// Create an `Offset` from two integer values.
     ///
     /// # Examples
     ///
@@ -71,7 +110,7 @@ impl Offset {
     ///     Offset { col: 1, row: 2 }
     /// );
     /// ```
-    pub fn from_coords(col: i32, row: i32) -> Self {
+    pub fn from_coords(col: T, row: T) -> Self {
         Self::from((col, row))
     }
 
@@ -79,65 +118,166 @@ impl Offset {
     // Conversion
     //////////////////////////////////
 
+    /// Attempts to convert an `Offset` to a different integer width. Returns
+    /// an `Err` if either value doesn't fit in the target width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::offset::Offset;
+    ///
+    /// let coord: Offset<i16> = Offset::from_coords(-1, 200);
+    ///
+    /// assert_eq!(coord.cast::<i64>(), Ok(Offset::from_coords(-1, 200)));
+    /// assert!(coord.cast::<i8>().is_err());
+    /// ```
+    pub fn cast<U: CoordInt>(self) -> CoordResult<Offset<U>> {
+        let col = U::from_i128(self.col.to_i128());
+        let row = U::from_i128(self.row.to_i128());
+
+        match (col, row) {
+            (Some(col), Some(row)) => Ok(Offset { col, row }),
+            _ => Err("Offset coordinate out of range for target width"),
+        }
+    }
+
     /// Converts an `Offset` to a `Cube`, assuming the `HexGrid` has
     /// `Parity::Odd` and `Tilt::Flat` parameters.
-    pub fn oflat_to_cube(self) -> Cube {
+    pub fn oflat_to_cube(self) -> Cube<T> {
         let x = self.col;
-        let z = self.row - (self.col - (self.col & 1)) / 2;
-        let y = 0 - x - z;
+        let z = self.row - (self.col - (self.col & T::ONE)) / T::TWO;
+        let y = T::ZERO - x - z;
 
         Cube::force_from_coords(x, y, z)
     }
 
     /// Converts an `Offset` to a `Cube`, assuming the `HexGrid` has
     /// `Parity::Even` and `Tilt::Flat` parameters.
-    pub fn eflat_to_cube(self) -> Cube {
+    pub fn eflat_to_cube(self) -> Cube<T> {
         let x = self.col;
-        let z = self.row - (self.col + (self.col & 1)) / 2;
-        let y = 0 - x - z;
+        let z = self.row - (self.col + (self.col & T::ONE)) / T::TWO;
+        let y = T::ZERO - x - z;
 
         Cube::force_from_coords(x, y, z)
     }
 
     /// Converts an `Offset` to a `Cube`, assuming the `HexGrid` has
     /// `Parity::Odd` and `Tilt::Sharp` parameters.
-    pub fn osharp_to_cube(self) -> Cube {
-        let x = self.col - (self.row - (self.row & 1)) / 2;
+    pub fn osharp_to_cube(self) -> Cube<T> {
+        let x = self.col - (self.row - (self.row & T::ONE)) / T::TWO;
         let z = self.row;
-        let y = 0 - x - z;
+        let y = T::ZERO - x - z;
 
         Cube::force_from_coords(x, y, z)
     }
 
     /// Converts an `Offset` to a `Cube`, assuming the `HexGrid` has
     /// `Parity::Even` and `Tilt::Sharp` parameters.
-    pub fn esharp_to_cube(self) -> Cube {
-        let x = self.col - (self.row + (self.row & 1)) / 2;
+    pub fn esharp_to_cube(self) -> Cube<T> {
+        let x = self.col - (self.row + (self.row & T::ONE)) / T::TWO;
         let z = self.row;
-        let y = 0 - x - z;
+        let y = T::ZERO - x - z;
 
         Cube::force_from_coords(x, y, z)
     }
 
+    /// Converts a `Cube` to an `Offset`, assuming the `HexGrid` has
+    /// `Parity::Odd` and `Tilt::Flat` parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::{Cube, Offset};
+    ///
+    /// let offset = Offset::from_coords(3, -2);
+    ///
+    /// assert_eq!(Offset::cube_to_oflat(offset.oflat_to_cube()), offset);
+    /// ```
+    pub fn cube_to_oflat(coord: Cube<T>) -> Self {
+        let (x, _, z) = coord.to_tuple();
+        let col = x;
+        let row = z + (x - (x & T::ONE)) / T::TWO;
+
+        Offset { col, row }
+    }
+
+    /// Converts a `Cube` to an `Offset`, assuming the `HexGrid` has
+    /// `Parity::Even` and `Tilt::Flat` parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::{Cube, Offset};
+    ///
+    /// let offset = Offset::from_coords(3, -2);
+    ///
+    /// assert_eq!(Offset::cube_to_eflat(offset.eflat_to_cube()), offset);
+    /// ```
+    pub fn cube_to_eflat(coord: Cube<T>) -> Self {
+        let (x, _, z) = coord.to_tuple();
+        let col = x;
+        let row = z + (x + (x & T::ONE)) / T::TWO;
+
+        Offset { col, row }
+    }
+
+    /// Converts a `Cube` to an `Offset`, assuming the `HexGrid` has
+    /// `Parity::Odd` and `Tilt::Sharp` parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::{Cube, Offset};
+    ///
+    /// let offset = Offset::from_coords(-1, 5);
+    ///
+    /// assert_eq!(Offset::cube_to_osharp(offset.osharp_to_cube()), offset);
+    /// ```
+    pub fn cube_to_osharp(coord: Cube<T>) -> Self {
+        let (x, _, z) = coord.to_tuple();
+        let col = x + (z - (z & T::ONE)) / T::TWO;
+        let row = z;
+
+        Offset { col, row }
+    }
+
+    /// Converts a `Cube` to an `Offset`, assuming the `HexGrid` has
+    /// `Parity::Even` and `Tilt::Sharp` parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::{Cube, Offset};
+    ///
+    /// let offset = Offset::from_coords(-1, 5);
+    ///
+    /// assert_eq!(Offset::cube_to_esharp(offset.esharp_to_cube()), offset);
+    /// ```
+    pub fn cube_to_esharp(coord: Cube<T>) -> Self {
+        let (x, _, z) = coord.to_tuple();
+        let col = x + (z + (z & T::ONE)) / T::TWO;
+        let row = z;
+
+        Offset { col, row }
+    }
+
     //////////////////////////////////
     // Neighbors
     //////////////////////////////////
 
     fn offset_map(
         self,
-        offsets: [[[i32; 2]; 6]; 2],
-        parity_check: i32
+        offsets: [[[i8; 2]; 6]; 2],
+        parity_check: T
     ) -> Vec<Self> {
         let mut neighbors = Vec::new();
+        let parity = usize::from(parity_check & T::ONE != T::ZERO);
 
-        for side in 0..6 {
-            let parity: usize = (parity_check & 1) as usize;
-            let offset_pair = offsets[parity][side];
-
-            let col = self.col + offset_pair[0];
-            let row = self.row + offset_pair[1];
+        for offset_pair in offsets[parity].iter() {
+            let col = self.col + T::from_i8(offset_pair[0]);
+            let row = self.row + T::from_i8(offset_pair[1]);
 
-            neighbors.push(Offset { col: col, row: row });
+            neighbors.push(Offset { col, row });
         }
 
         neighbors
@@ -196,7 +336,7 @@ impl Offset {
     //////////////////////////////////
 
     /// Calculates the distance between two `Offset` coordinates.
-    pub fn dist(self, other: Self) -> i32 {
+    pub fn dist(self, other: Self) -> T {
         self.eflat_to_cube().dist(other.eflat_to_cube())
     }
 }
@@ -0,0 +1,351 @@
+//! Coordinate Parsing
+//!
+//! Every coordinate has a textual form consisting of its lowercase system
+//! name followed by its values in parentheses, e.g. `cube(1,-2,1)` or
+//! `offset(3,4)`. Whitespace is permitted between tokens, and values may
+//! carry a leading `+` or `-` sign.
+
+use std::error::Error;
+use std::fmt;
+
+use super::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Errors
+//////////////////////////////////////////////////////////////////////////////
+
+/// The reason a coordinate failed to parse.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseCoordErrorKind {
+    /// The input ended before the coordinate was complete.
+    UnexpectedEnd,
+    /// A character which doesn't belong at that position was encountered.
+    UnexpectedChar(char),
+    /// The coordinate system name isn't one of `axial`, `cube`, `double`,
+    /// or `offset`.
+    UnknownSystem,
+    /// The coordinate system is valid, but isn't the one being parsed.
+    WrongSystem(CoordSys),
+    /// A value doesn't fit in the coordinate width.
+    OutOfRange,
+    /// The values violate the coordinate system's constraint.
+    Constraint,
+}
+
+/// An error encountered while parsing a coordinate, along with the byte
+/// position in the input at which it occurred.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::{Cube, ParseCoordErrorKind};
+///
+/// let err = "cube(1,2,x)".parse::<Cube>().unwrap_err();
+///
+/// assert_eq!(err.kind(), ParseCoordErrorKind::UnexpectedChar('x'));
+/// assert_eq!(err.position(), 9);
+/// assert_eq!(
+///     err.to_string(),
+///     "unexpected character 'x' at position 9"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseCoordError {
+    kind: ParseCoordErrorKind,
+    position: usize,
+}
+
+impl ParseCoordError {
+    fn new(kind: ParseCoordErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Returns the reason parsing failed.
+    pub fn kind(&self) -> ParseCoordErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte position in the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseCoordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseCoordErrorKind::UnexpectedEnd => {
+                write!(f, "unexpected end of input")?
+            }
+            ParseCoordErrorKind::UnexpectedChar(c) => {
+                write!(f, "unexpected character {:?}", c)?
+            }
+            ParseCoordErrorKind::UnknownSystem => {
+                write!(f, "unknown coordinate system")?
+            }
+            ParseCoordErrorKind::WrongSystem(sys) => {
+                write!(f, "unexpected {:?} coordinate", sys)?
+            }
+            ParseCoordErrorKind::OutOfRange => {
+                write!(f, "value out of range")?
+            }
+            ParseCoordErrorKind::Constraint => {
+                write!(f, "values violate the coordinate constraint")?
+            }
+        }
+
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl Error for ParseCoordError {}
+
+//////////////////////////////////////////////////////////////////////////////
+// Parsed Coordinates
+//////////////////////////////////////////////////////////////////////////////
+
+/// The system and raw values of a successfully tokenized coordinate.
+pub(crate) struct ParsedCoord {
+    pub(crate) sys: CoordSys,
+    values: Vec<(i128, usize)>,
+    open_position: usize,
+}
+
+impl ParsedCoord {
+    /// Parses a coordinate of any system.
+    pub(crate) fn parse(input: &str) -> Result<Self, ParseCoordError> {
+        Parser { input, position: 0 }.coord()
+    }
+
+    /// Parses a coordinate which must belong to the given system.
+    pub(crate) fn parse_sys(
+        input: &str,
+        sys: CoordSys,
+    ) -> Result<Self, ParseCoordError> {
+        let parsed = Self::parse(input)?;
+
+        if parsed.sys == sys {
+            Ok(parsed)
+        } else {
+            let start = input.len() - input.trim_start().len();
+
+            Err(ParseCoordError::new(
+                ParseCoordErrorKind::WrongSystem(parsed.sys),
+                start,
+            ))
+        }
+    }
+
+    /// Returns the value at the given index in the requested width.
+    pub(crate) fn value<T: CoordInt>(
+        &self,
+        index: usize,
+    ) -> Result<T, ParseCoordError> {
+        let (value, position) = self.values[index];
+
+        T::from_i128(value).ok_or_else(|| {
+            ParseCoordError::new(ParseCoordErrorKind::OutOfRange, position)
+        })
+    }
+
+    /// Creates a constraint violation error, positioned at the opening
+    /// parenthesis of the value list.
+    pub(crate) fn constraint_error(&self) -> ParseCoordError {
+        ParseCoordError::new(
+            ParseCoordErrorKind::Constraint,
+            self.open_position,
+        )
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Parser
+//////////////////////////////////////////////////////////////////////////////
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn error(&self, kind: ParseCoordErrorKind) -> ParseCoordError {
+        ParseCoordError::new(kind, self.position)
+    }
+
+    fn unexpected(&self) -> ParseCoordError {
+        match self.peek() {
+            Some(c) => self.error(ParseCoordErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseCoordErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<usize, ParseCoordError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(self.position - 1)
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn sys(&mut self) -> Result<CoordSys, ParseCoordError> {
+        self.skip_whitespace();
+
+        let start = self.position;
+
+        while let Some('a'..='z') = self.peek() {
+            self.position += 1;
+        }
+
+        match &self.input[start..self.position] {
+            "axial" => Ok(CoordSys::Axial),
+            "cube" => Ok(CoordSys::Cube),
+            "double" => Ok(CoordSys::Double),
+            "offset" => Ok(CoordSys::Offset),
+            "" => Err(self.unexpected()),
+            _ => Err(ParseCoordError::new(
+                ParseCoordErrorKind::UnknownSystem,
+                start,
+            )),
+        }
+    }
+
+    fn value(&mut self) -> Result<(i128, usize), ParseCoordError> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let negative = match self.peek() {
+            Some('-') => {
+                self.position += 1;
+                true
+            }
+            Some('+') => {
+                self.position += 1;
+                false
+            }
+            _ => false,
+        };
+
+        let mut value: i128 = 0;
+        let mut digits = 0;
+        let mut overflowed = false;
+
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            let digit = i128::from(digit);
+            let signed = if negative { -digit } else { digit };
+
+            match value.checked_mul(10).and_then(|n| n.checked_add(signed)) {
+                Some(next) => value = next,
+                None => overflowed = true,
+            }
+            digits += 1;
+            self.position += 1;
+        }
+
+        if digits == 0 {
+            Err(self.unexpected())
+        } else if overflowed {
+            Err(ParseCoordError::new(ParseCoordErrorKind::OutOfRange, start))
+        } else {
+            Ok((value, start))
+        }
+    }
+
+    fn coord(mut self) -> Result<ParsedCoord, ParseCoordError> {
+        let sys = self.sys()?;
+        let arity = if sys == CoordSys::Cube { 3 } else { 2 };
+        let open_position = self.expect('(')?;
+        let mut values = Vec::with_capacity(arity);
+
+        for index in 0..arity {
+            if index > 0 {
+                self.expect(',')?;
+            }
+            values.push(self.value()?);
+        }
+
+        self.expect(')')?;
+        self.skip_whitespace();
+
+        if self.position < self.input.len() {
+            return Err(self.unexpected());
+        }
+
+        Ok(ParsedCoord { sys, values, open_position })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn err(input: &str) -> (ParseCoordErrorKind, usize) {
+        let err = ParsedCoord::parse(input).err().unwrap();
+
+        (err.kind(), err.position())
+    }
+
+    #[test]
+    fn test_parse_systems() {
+        let cases = [
+            ("axial(1,2)", CoordSys::Axial, vec![1, 2]),
+            ("cube(1,-2,1)", CoordSys::Cube, vec![1, -2, 1]),
+            ("double(-3,+5)", CoordSys::Double, vec![-3, 5]),
+            (" offset ( 3 , 4 ) ", CoordSys::Offset, vec![3, 4]),
+        ];
+
+        for (input, sys, values) in cases.iter() {
+            let parsed = ParsedCoord::parse(input).unwrap();
+            let parsed_values: Vec<i128> =
+                parsed.values.iter().map(|&(value, _)| value).collect();
+
+            assert_eq!(parsed.sys, *sys, "{}", input);
+            assert_eq!(&parsed_values, values, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use ParseCoordErrorKind::*;
+
+        assert_eq!(err(""), (UnexpectedEnd, 0));
+        assert_eq!(err("hex(1,2)"), (UnknownSystem, 0));
+        assert_eq!(err("Cube(1,2,3)"), (UnexpectedChar('C'), 0));
+        assert_eq!(err("axial 1,2)"), (UnexpectedChar('1'), 6));
+        assert_eq!(err("axial(1,2"), (UnexpectedEnd, 9));
+        assert_eq!(err("axial(1,,2)"), (UnexpectedChar(','), 8));
+        assert_eq!(err("axial(1,2,3)"), (UnexpectedChar(','), 9));
+        assert_eq!(err("cube(1,2)"), (UnexpectedChar(')'), 8));
+        assert_eq!(err("offset(1,2)x"), (UnexpectedChar('x'), 11));
+        assert_eq!(err("axial(-,2)"), (UnexpectedChar(','), 7));
+        assert_eq!(err("axial(1,2é)"), (UnexpectedChar('é'), 9));
+    }
+
+    #[test]
+    fn test_parse_huge_values() {
+        let huge = format!("axial({}0,0)", i128::MAX);
+        let tiny = format!("axial(0,{}0)", i128::MIN);
+
+        assert_eq!(err(&huge), (ParseCoordErrorKind::OutOfRange, 6));
+        assert_eq!(err(&tiny), (ParseCoordErrorKind::OutOfRange, 8));
+    }
+}
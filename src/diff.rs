@@ -0,0 +1,208 @@
+//! Grid Diffs
+//!
+//! A `HexDiff` records the hexes added, removed, and changed between two
+//! states of a `HexGrid`, so that one state can be rebuilt from the other
+//! without copying the whole grid. Hexes are identified by position, so the
+//! grids needn't share a layout.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! let before = HexGrid::new_radial(2, 'a');
+//! let mut after = HexGrid::new_radial(2, 'a');
+//!
+//! after.set(MultiCoord::axial(0, 1), 'b');
+//! after.remove(MultiCoord::axial(1, 0));
+//!
+//! let diff = before.diff(&after);
+//! let mut remote = HexGrid::new_radial(2, 'a');
+//!
+//! assert_eq!(diff.len(), 2);
+//!
+//! remote.apply(&diff).unwrap();
+//! assert!(remote.diff(&after).is_empty());
+//! ```
+
+use std::collections::HashMap;
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// HexDiff
+//////////////////////////////////////////////////////////////////////////////
+
+/// The hexes added, removed, and changed between two states of a `HexGrid`.
+/// Each list is sorted by coordinate.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct HexDiff<T> {
+    /// New hexes and their values.
+    pub added: Vec<(Cube, T)>,
+    /// Removed hexes and the values they held.
+    pub removed: Vec<(Cube, T)>,
+    /// Kept hexes with new values, as their old and then new value.
+    pub changed: Vec<(Cube, T, T)>,
+}
+
+impl<T> Default for HexDiff<T> {
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+}
+
+impl<T> HexDiff<T> {
+    /// Returns the number of hexes added, removed, or changed.
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+
+    /// Returns `true` if the diff makes no changes, else `false`.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the diff undoing the calling instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let before = HexGrid::new_radial(1, 0);
+    /// let mut grid = HexGrid::new_radial(1, 0);
+    ///
+    /// grid.set(MultiCoord::axial(2, 0), 1);
+    ///
+    /// let diff = before.diff(&grid);
+    ///
+    /// grid.apply(&diff.inverse()).unwrap();
+    /// assert!(grid.diff(&before).is_empty());
+    /// ```
+    pub fn inverse(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            added: self.removed.clone(),
+            removed: self.added.clone(),
+            changed: self
+                .changed
+                .iter()
+                .map(|(cube, old, new)| (*cube, new.clone(), old.clone()))
+                .collect(),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Diffing
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T: Clone + PartialEq> HexGrid<T> {
+    /// Returns the changes turning the calling instance into `other`.
+    pub fn diff(&self, other: &Self) -> HexDiff<T> {
+        let cubes = |grid: &Self| -> HashMap<Cube, T> {
+            grid.iter()
+                .map(|(coord, value)| {
+                    let cube = grid.cube_of(coord);
+
+                    (cube, value.clone())
+                })
+                .collect()
+        };
+        let (old, mut new) = (cubes(self), cubes(other));
+        let mut diff = HexDiff::default();
+
+        for (cube, old_value) in old {
+            match new.remove(&cube) {
+                Some(new_value) if new_value == old_value => (),
+                Some(new_value) => {
+                    diff.changed.push((cube, old_value, new_value));
+                }
+                None => diff.removed.push((cube, old_value)),
+            }
+        }
+
+        diff.added.extend(new);
+
+        diff.added.sort_by_key(|&(cube, _)| cube.to_tuple());
+        diff.removed.sort_by_key(|&(cube, _)| cube.to_tuple());
+        diff.changed.sort_by_key(|&(cube, ..)| cube.to_tuple());
+        diff
+    }
+
+    /// Applies a diff to the calling instance. Returns a
+    /// `Result::Err(String)`, leaving the grid untouched, if an added hex is
+    /// already present or a removed or changed hex doesn't hold the diff's
+    /// old value. Otherwise, returns `Result::Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::coordinate::Cube;
+    /// use chickenwire::diff::HexDiff;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_radial(1, 0);
+    /// let diff = HexDiff {
+    ///     changed: vec![(Cube::force_from_coords(0, 0, 0), 7, 8)],
+    ///     ..HexDiff::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     grid.apply(&diff).unwrap_err(),
+    ///     "Grid value at cube(0,0,0) doesn't match the diff"
+    /// );
+    /// ```
+    pub fn apply(&mut self, diff: &HexDiff<T>) -> Result<(), String> {
+        for (cube, _) in diff.added.iter() {
+            if self.contains_coord(MultiCoord::from(*cube)) {
+                return Err(format!(
+                    "Grid already contains a value at {}",
+                    cube
+                ));
+            }
+        }
+
+        let expected = diff
+            .removed
+            .iter()
+            .map(|(cube, old)| (cube, old))
+            .chain(diff.changed.iter().map(|(cube, old, _)| (cube, old)));
+
+        for (cube, old) in expected {
+            match self.get(MultiCoord::from(*cube)) {
+                Some(value) if value == old => (),
+                Some(_) => {
+                    return Err(format!(
+                        "Grid value at {} doesn't match the diff",
+                        cube
+                    ));
+                }
+                None => {
+                    return Err(format!("Grid contains no value at {}", cube));
+                }
+            }
+        }
+
+        for (cube, _) in diff.removed.iter() {
+            self.remove(MultiCoord::from(*cube));
+        }
+
+        for (cube, _, new) in diff.changed.iter() {
+            self.set(MultiCoord::from(*cube), new.clone());
+        }
+
+        for (cube, value) in diff.added.iter() {
+            self.set(MultiCoord::from(*cube), value.clone());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+//! Grid Entries
+//!
+//! `HexGrid::entry` looks a coordinate up once and returns an `Entry` for
+//! reading, modifying, inserting, or removing its hex, mirroring
+//! `HashMap::entry`. Changes made through an entry are tracked and raise
+//! events just like the equivalent `HexGrid` methods.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! let mut visits: HexGrid<u32> = HexGrid::default();
+//!
+//! for &(q, r) in [(0, 0), (1, 0), (0, 0)].iter() {
+//!     visits
+//!         .entry(MultiCoord::axial(q, r))
+//!         .and_modify(|count| *count += 1)
+//!         .or_insert(1);
+//! }
+//!
+//! assert_eq!(visits[MultiCoord::axial(0, 0)], 2);
+//! assert_eq!(visits[MultiCoord::axial(1, 0)], 1);
+//! ```
+
+use petgraph::graph::NodeIndex;
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Entry
+//////////////////////////////////////////////////////////////////////////////
+
+/// A view into a single hex of a `HexGrid`, which may be present or vacant.
+#[derive(Debug)]
+pub enum Entry<'a, T> {
+    Occupied(OccupiedEntry<'a, T>),
+    Vacant(VacantEntry<'a, T>),
+}
+
+/// A view into a present hex of a `HexGrid`.
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, T> {
+    grid: &'a mut HexGrid<T>,
+    coord: MultiCoord,
+    index: NodeIndex,
+}
+
+/// A view into a vacant hex of a `HexGrid`.
+#[derive(Debug)]
+pub struct VacantEntry<'a, T> {
+    grid: &'a mut HexGrid<T>,
+    coord: MultiCoord,
+}
+
+impl<T> HexGrid<T> {
+    /// Returns the entry for the hex at the given coordinate.
+    pub fn entry(&mut self, coord: MultiCoord) -> Entry<'_, T> {
+        match self.graph_index(coord) {
+            Some(&index) => Entry::Occupied(OccupiedEntry {
+                grid: self,
+                coord,
+                index,
+            }),
+            None => Entry::Vacant(VacantEntry { grid: self, coord }),
+        }
+    }
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Returns the coordinate of the entry's hex, as given to
+    /// `HexGrid::entry`.
+    pub fn coord(&self) -> MultiCoord {
+        match self {
+            Entry::Occupied(entry) => entry.coord(),
+            Entry::Vacant(entry) => entry.coord(),
+        }
+    }
+
+    /// Inserts `default` if the hex is vacant, then returns a mutable
+    /// reference to its value.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the hex is vacant, then returns a
+    /// mutable reference to its value.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut T
+    where
+        F: FnOnce() -> T,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the result of `default`, given the entry's coordinate, if the
+    /// hex is vacant, then returns a mutable reference to its value.
+    pub fn or_insert_with_coord<F>(self, default: F) -> &'a mut T
+    where
+        F: FnOnce(MultiCoord) -> T,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.coord);
+
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Inserts `T::default()` if the hex is vacant, then returns a mutable
+    /// reference to its value.
+    pub fn or_default(self) -> &'a mut T
+    where
+        T: Default,
+    {
+        self.or_insert_with(T::default)
+    }
+
+    /// Calls `f` on the hex's value if the hex is present.
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut T),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Returns the coordinate of the entry's hex, as given to
+    /// `HexGrid::entry`.
+    pub fn coord(&self) -> MultiCoord {
+        self.coord
+    }
+
+    /// Returns a reference to the hex's value.
+    pub fn get(&self) -> &T {
+        self.grid.value_at(self.index)
+    }
+
+    /// Returns a mutable reference to the hex's value.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.grid.value_at_mut(self.coord, self.index)
+    }
+
+    /// Returns a mutable reference to the hex's value, bound to the
+    /// lifetime of the grid.
+    pub fn into_mut(self) -> &'a mut T {
+        self.grid.value_at_mut(self.coord, self.index)
+    }
+
+    /// Replaces the hex's value, returning the old one.
+    pub fn insert(&mut self, value: T) -> T {
+        self.grid.replace_at(self.coord, self.index, value)
+    }
+
+    /// Removes the hex from the grid, returning its value.
+    pub fn remove(self) -> T {
+        match self.grid.remove(self.coord) {
+            Some(value) => value,
+            None => unreachable!("occupied entry without a hex"),
+        }
+    }
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Returns the coordinate of the entry's hex, as given to
+    /// `HexGrid::entry`.
+    pub fn coord(&self) -> MultiCoord {
+        self.coord
+    }
+
+    /// Adds the hex with the given value, returning a mutable reference to
+    /// it.
+    pub fn insert(self, value: T) -> &'a mut T {
+        let index = self.grid.insert_at(self.coord, value);
+
+        self.grid.value_at_mut(self.coord, index)
+    }
+}
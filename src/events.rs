@@ -0,0 +1,149 @@
+//! Grid Events
+//!
+//! Listeners subscribed to a `HexGrid` are called whenever a hex is added,
+//! changed, or removed, making it easy to keep render meshes, caches, and
+//! other derived state in sync with the grid. Edits made through
+//! `HexGrid::get_mut` aren't reported.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::{Arc, Mutex};
+//!
+//! use chickenwire::events::GridEvent;
+//! use chickenwire::prelude::*;
+//!
+//! let mut grid = HexGrid::new_radial(1, 0);
+//! let log = Arc::new(Mutex::new(Vec::new()));
+//! let sink = Arc::clone(&log);
+//!
+//! grid.subscribe(move |event: &GridEvent<i32>| {
+//!     let entry = match event {
+//!         GridEvent::CellAdded { coord, value } => {
+//!             format!("added {} at {}", value, coord)
+//!         }
+//!         GridEvent::CellChanged { coord, old, new } => {
+//!             format!("changed {} to {} at {}", old, new, coord)
+//!         }
+//!         GridEvent::CellRemoved { coord, value } => {
+//!             format!("removed {} at {}", value, coord)
+//!         }
+//!     };
+//!
+//!     sink.lock().unwrap().push(entry);
+//! });
+//!
+//! grid.sys = CoordSys::Cube;
+//! grid.set(MultiCoord::axial(0, 0), 1);
+//! grid.remove(MultiCoord::axial(1, 0));
+//! grid.add(MultiCoord::axial(2, 0), 3).unwrap();
+//!
+//! assert_eq!(
+//!     *log.lock().unwrap(),
+//!     vec![
+//!         "changed 0 to 1 at cube(0,0,0)",
+//!         "removed 0 at cube(1,-1,0)",
+//!         "added 3 at cube(2,-2,0)",
+//!     ]
+//! );
+//! ```
+
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+
+use crate::coordinate::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Events
+//////////////////////////////////////////////////////////////////////////////
+
+/// A change to a single hex of a `HexGrid`. Coordinates are given in the
+/// grid's `sys`.
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub enum GridEvent<'a, T> {
+    /// A hex was added with the given value.
+    CellAdded { coord: MultiCoord, value: &'a T },
+    /// A hex's value was replaced.
+    CellChanged { coord: MultiCoord, old: &'a T, new: &'a T },
+    /// A hex holding the given value was removed.
+    CellRemoved { coord: MultiCoord, value: &'a T },
+}
+
+impl<'a, T> GridEvent<'a, T> {
+    /// Returns the coordinate of the hex the event concerns.
+    pub fn coord(&self) -> MultiCoord {
+        match *self {
+            GridEvent::CellAdded { coord, .. }
+            | GridEvent::CellChanged { coord, .. }
+            | GridEvent::CellRemoved { coord, .. } => coord,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Listeners
+//////////////////////////////////////////////////////////////////////////////
+
+/// Identifies a listener subscribed to a `HexGrid`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ListenerId(u64);
+
+/// A callback for `GridEvent`s.
+type Callback<T> = Box<dyn FnMut(&GridEvent<T>) + Send>;
+
+/// A subscribed callback. Callbacks need only be `Send`; the `Mutex` keeps
+/// grids holding them `Sync`, and is never locked, since callbacks are only
+/// called through `&mut`.
+type Listener<T> = Mutex<Callback<T>>;
+
+/// The listeners subscribed to a `HexGrid`, in order of subscription.
+pub(crate) struct Listeners<T> {
+    next: u64,
+    list: Vec<(ListenerId, Listener<T>)>,
+}
+
+impl<T> Default for Listeners<T> {
+    fn default() -> Self {
+        Self {
+            next: 0,
+            list: Vec::new(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Listeners<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Listeners({})", self.list.len())
+    }
+}
+
+impl<T> Listeners<T> {
+    pub(crate) fn add(&mut self, listener: Callback<T>) -> ListenerId {
+        let id = ListenerId(self.next);
+
+        self.next += 1;
+        self.list.push((id, Mutex::new(listener)));
+        id
+    }
+
+    pub(crate) fn remove(&mut self, id: ListenerId) -> bool {
+        let before = self.list.len();
+
+        self.list.retain(|&(other, _)| other != id);
+        self.list.len() != before
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Calls every listener with the given event.
+    pub(crate) fn notify(&mut self, event: &GridEvent<T>) {
+        for (_, listener) in self.list.iter_mut() {
+            let listener =
+                listener.get_mut().unwrap_or_else(PoisonError::into_inner);
+
+            listener(event);
+        }
+    }
+}
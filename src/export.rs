@@ -0,0 +1,296 @@
+//! Graph Export
+//!
+//! The adjacency graph behind a `HexGrid` can be written out in Graphviz DOT
+//! or GraphML form for inspection in external graph tools. Every hex becomes
+//! a node positioned by the grid's layout, and every link between neighbors
+//! becomes a directed edge labeled with its `Compass` direction. Neighboring
+//! hexes are linked in both directions, so a lone edge between two nodes
+//! points to a broken link.
+//!
+//! Nodes are numbered in a fixed order, so exporting the same grid twice
+//! produces identical documents.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! let grid = HexGrid::new_radial(1, 0);
+//! let dot = grid.to_dot(1.0, |value| value.to_string());
+//!
+//! assert!(dot.starts_with("digraph hexgrid {"));
+//! assert_eq!(dot.matches(" -> ").count(), 24);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::coordinate::*;
+use crate::geometry;
+use crate::hexgrid::*;
+use crate::text::{escape, num, push_line};
+
+/// A hex prepared for export.
+struct Node {
+    id: String,
+    coord: MultiCoord,
+    label: String,
+    position: (f64, f64),
+}
+
+/// An edge prepared for export, referring to nodes by id.
+struct Edge<'a> {
+    from: &'a str,
+    to: &'a str,
+    direction: Compass,
+}
+
+/// Escapes text for use in a double-quoted DOT string.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl<T> HexGrid<T> {
+    /// Returns the grid's nodes, sorted by coordinate, along with the `Cube`
+    /// coordinate of each.
+    fn export_parts<F>(&self, size: f64, label: F) -> (Vec<Node>, Vec<Cube>)
+    where
+        F: Fn(&T) -> String,
+    {
+        let mut cubes: Vec<(Cube, &T)> = self
+            .iter()
+            .map(|(coord, value)| {
+                (self.cube_of(coord), value)
+            })
+            .collect();
+
+        cubes.sort_by_key(|&(cube, _)| cube.to_tuple());
+
+        let nodes = cubes
+            .iter()
+            .enumerate()
+            .map(|(index, &(cube, value))| Node {
+                id: format!("n{}", index),
+                coord: self.coord_of(cube),
+                label: label(value),
+                position: geometry::center(self.tilt, size, cube),
+            })
+            .collect();
+
+        (nodes, cubes.into_iter().map(|(cube, _)| cube).collect())
+    }
+
+    /// Pairs the grid's links with the ids of their endpoints.
+    fn export_edges<'a>(
+        &self,
+        nodes: &'a [Node],
+        cubes: &[Cube],
+    ) -> Vec<Edge<'a>> {
+        let ids: HashMap<Cube, &str> = cubes
+            .iter()
+            .zip(nodes.iter())
+            .map(|(&cube, node)| (cube, node.id.as_str()))
+            .collect();
+        let order: HashMap<Cube, usize> =
+            cubes.iter().enumerate().map(|(i, &cube)| (cube, i)).collect();
+
+        let mut links: Vec<(Cube, Cube, Compass)> = self
+            .links()
+            .into_iter()
+            .map(|(from, to, stored)| {
+                (from, to, self.direction(from, to, stored))
+            })
+            .collect();
+
+        links.sort_by_key(|&(from, to, dir)| (order[&from], order[&to], dir));
+        links
+            .into_iter()
+            .map(|(from, to, direction)| Edge {
+                from: ids[&from],
+                to: ids[&to],
+                direction,
+            })
+            .collect()
+    }
+
+    /// Returns the direction from one hex to a neighbor under the grid's
+    /// current `tilt`, which may have changed since they were linked. Falls
+    /// back to the `stored` direction for hexes that aren't neighbors.
+    fn direction(&self, from: Cube, to: Cube, stored: Compass) -> Compass {
+        from.neighbors()
+            .into_iter()
+            .position(|next| next == to)
+            .map_or(stored, |side| Compass::from_neighbor(self.tilt, side))
+    }
+
+    /// Writes the grid's adjacency graph in Graphviz DOT form. Each node is
+    /// labeled by `label`, carries its coordinate as a tooltip, and is pinned
+    /// at its hex's center, with `size` being the distance in points from a
+    /// hex's center to its corners. Use a layout engine that honors pinned
+    /// positions, such as `neato -n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_boxy(2, 1, "a");
+    ///
+    /// grid.set(MultiCoord::offset(1, 0), "b");
+    ///
+    /// let dot = grid.to_dot(10.0, |name| name.to_string());
+    /// let lines: Vec<&str> = dot.lines().collect();
+    ///
+    /// assert_eq!(lines[0], "digraph hexgrid {");
+    /// assert!(lines[2].starts_with("    n0 [label=\"a\", "));
+    /// assert!(lines[3].ends_with("\"offset(1,0)\", pos=\"15,8.66!\"];"));
+    /// assert_eq!(lines[4], "    n0 -> n1 [label=\"Northeast\"];");
+    /// assert_eq!(lines[5], "    n1 -> n0 [label=\"Southwest\"];");
+    /// ```
+    pub fn to_dot<F>(&self, size: f64, label: F) -> String
+    where
+        F: Fn(&T) -> String,
+    {
+        let (nodes, cubes) = self.export_parts(size, label);
+        let edges = self.export_edges(&nodes, &cubes);
+        let mut dot = String::new();
+
+        dot.push_str("digraph hexgrid {\n");
+        dot.push_str("    node [shape=hexagon];\n");
+
+        for node in nodes.iter() {
+            // DOT's y axis points up, unlike pixel space.
+            let (x, y) = node.position;
+
+            push_line(
+                &mut dot,
+                format_args!(
+                    "    {} [label=\"{}\", tooltip=\"{}\", pos=\"{},{}!\"];",
+                    node.id,
+                    escape_dot(&node.label),
+                    node.coord,
+                    num(x),
+                    num(-y),
+                ),
+            );
+        }
+
+        for edge in edges.iter() {
+            push_line(
+                &mut dot,
+                format_args!(
+                    "    {} -> {} [label=\"{:?}\"];",
+                    edge.from,
+                    edge.to,
+                    edge.direction,
+                ),
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Writes the grid's adjacency graph in GraphML form. Each node carries
+    /// its `label`, its coordinate, and the `x` and `y` position of its hex's
+    /// center, with `size` being the distance from a hex's center to its
+    /// corners. Each edge carries its `direction`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_radial(1, 'x');
+    /// let graphml = grid.to_graphml(1.0, |c| c.to_string());
+    ///
+    /// assert_eq!(graphml.matches("<node ").count(), 7);
+    /// assert_eq!(graphml.matches("<edge ").count(), 24);
+    /// assert!(graphml.contains("<data key=\"direction\">North</data>"));
+    /// ```
+    pub fn to_graphml<F>(&self, size: f64, label: F) -> String
+    where
+        F: Fn(&T) -> String,
+    {
+        let (nodes, cubes) = self.export_parts(size, label);
+        let edges = self.export_edges(&nodes, &cubes);
+        let mut xml = String::new();
+
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        push_line(
+            &mut xml,
+            format_args!(
+                "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">",
+            ),
+        );
+
+        let keys = [
+            ("label", "node", "string"),
+            ("coord", "node", "string"),
+            ("x", "node", "double"),
+            ("y", "node", "double"),
+            ("direction", "edge", "string"),
+        ];
+
+        for &(name, domain, kind) in keys.iter() {
+            push_line(
+                &mut xml,
+                format_args!(
+                    "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" \
+                     attr.type=\"{}\"/>",
+                    name,
+                    domain,
+                    name,
+                    kind,
+                ),
+            );
+        }
+
+        xml.push_str("  <graph id=\"hexgrid\" edgedefault=\"directed\">\n");
+
+        for node in nodes.iter() {
+            let (x, y) = node.position;
+
+            push_line(&mut xml, format_args!("    <node id=\"{}\">", node.id));
+            push_line(
+                &mut xml,
+                format_args!(
+                    "      <data key=\"label\">{}</data>",
+                    escape(&node.label),
+                ),
+            );
+            push_line(
+                &mut xml,
+                format_args!(
+                    "      <data key=\"coord\">{}</data>",
+                    node.coord,
+                ),
+            );
+            push_line(
+                &mut xml,
+                format_args!("      <data key=\"x\">{}</data>", num(x)),
+            );
+            push_line(
+                &mut xml,
+                format_args!("      <data key=\"y\">{}</data>", num(y)),
+            );
+            xml.push_str("    </node>\n");
+        }
+
+        for edge in edges.iter() {
+            push_line(
+                &mut xml,
+                format_args!(
+                    "    <edge source=\"{}\" target=\"{}\">\
+                     <data key=\"direction\">{:?}</data></edge>",
+                    edge.from,
+                    edge.to,
+                    edge.direction,
+                ),
+            );
+        }
+
+        xml.push_str("  </graph>\n");
+        xml.push_str("</graphml>\n");
+        xml
+    }
+}
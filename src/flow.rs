@@ -0,0 +1,372 @@
+//! Flow Fields
+//!
+//! A `FlowField` holds the cost of reaching the nearest goal from every hex
+//! that can reach one, so any number of agents heading for the same goals can
+//! each find their next step with a single lookup, instead of searching for
+//! a path apiece.
+//!
+//! Fields are built with `HexGrid::flow_field`, or with
+//! `HexGrid::dijkstra_map` for goals of differing appeal. When the cost of
+//! crossing a hex changes, `FlowField::set_cost` repairs only the part of
+//! the field that depends on it. `FlowField::flee` turns a field around, so
+//! agents run from its goals without cornering themselves.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::hexgrid::Compass;
+//! use chickenwire::prelude::*;
+//!
+//! let mut grid = HexGrid::new_boxy(6, 1, '.');
+//!
+//! grid.set(MultiCoord::offset(3, 0), '~');
+//!
+//! let cost = |_, &hex: &char| if hex == '~' { Some(4) } else { Some(1) };
+//! let mut field = grid.flow_field(&[MultiCoord::offset(0, 0)], cost);
+//!
+//! assert_eq!(field.distance(MultiCoord::offset(5, 0)), Some(8));
+//! assert_eq!(
+//!     field.next(MultiCoord::offset(5, 0)),
+//!     Some(MultiCoord::offset(4, 0))
+//! );
+//! assert_eq!(field.direction(MultiCoord::offset(0, 0)), None);
+//!
+//! // The river freezes over.
+//! field.set_cost(MultiCoord::offset(3, 0), Some(1));
+//! assert_eq!(field.distance(MultiCoord::offset(5, 0)), Some(5));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Flow Field
+//////////////////////////////////////////////////////////////////////////////
+
+/// The cost of reaching the nearest goal from each hex of a grid.
+#[derive(Debug)]
+pub struct FlowField {
+    /// An empty grid with the source grid's layout, for converting
+    /// coordinates.
+    frame: HexGrid<()>,
+    /// Costs of entering each passable hex.
+    costs: HashMap<Cube, u32>,
+    /// Starting values of each goal.
+    goals: HashMap<Cube, i64>,
+    dists: HashMap<Cube, i64>,
+}
+
+impl<T> HexGrid<T> {
+    /// Returns a flow field toward the nearest of the given goals. `cost`
+    /// gives the cost of entering a hex, or `None` if it can't be entered.
+    /// Costs below 1 are raised to 1. Goals that aren't hexes of the grid
+    /// are skipped.
+    pub fn flow_field<F>(&self, goals: &[MultiCoord], cost: F) -> FlowField
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+    {
+        let goals: Vec<(MultiCoord, i64)> =
+            goals.iter().map(|&goal| (goal, 0)).collect();
+
+        self.dijkstra_map(&goals, cost)
+    }
+
+    /// Returns a flow field toward goals starting at the given values, so a
+    /// goal with a lower value draws agents from farther away. Values may
+    /// be negative. Otherwise the same as `flow_field`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_boxy(9, 1, ());
+    /// let goals = [
+    ///     (MultiCoord::offset(0, 0), 0),
+    ///     (MultiCoord::offset(8, 0), -4),
+    /// ];
+    /// let field = grid.dijkstra_map(&goals, |_, _| Some(1));
+    ///
+    /// // The second goal is more appealing, so draws in the middle hex.
+    /// assert_eq!(field.distance(MultiCoord::offset(4, 0)), Some(0));
+    /// assert_eq!(
+    ///     field.next(MultiCoord::offset(3, 0)),
+    ///     Some(MultiCoord::offset(4, 0))
+    /// );
+    /// assert_eq!(
+    ///     field.next(MultiCoord::offset(1, 0)),
+    ///     Some(MultiCoord::offset(0, 0))
+    /// );
+    /// ```
+    pub fn dijkstra_map<F>(
+        &self,
+        goals: &[(MultiCoord, i64)],
+        mut cost: F,
+    ) -> FlowField
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+    {
+        let frame = HexGrid::new(self.tilt, self.parity, self.sys);
+        let mut costs = HashMap::new();
+
+        for (coord, value) in self.iter() {
+            if let Some(cost) = cost(coord, value) {
+                let cube = self.cube_of(coord);
+
+                costs.insert(cube, cost.max(1));
+            }
+        }
+
+        let goals = goals
+            .iter()
+            .filter(|&&(goal, _)| self.contains_coord(goal))
+            .map(|&(goal, value)| {
+                (self.cube_of(goal), value)
+            });
+
+        FlowField::new(frame, costs, goals)
+    }
+}
+
+impl FlowField {
+    /// Creates a field from its layout, costs, and goals, keeping the lowest
+    /// value of any repeated goal.
+    fn new<I>(frame: HexGrid<()>, costs: HashMap<Cube, u32>, goals: I) -> Self
+    where
+        I: IntoIterator<Item = (Cube, i64)>,
+    {
+        let mut field = Self {
+            frame,
+            costs,
+            goals: HashMap::new(),
+            dists: HashMap::new(),
+        };
+        let mut frontier = BinaryHeap::new();
+
+        for (goal, value) in goals {
+            let lowest = field.goals.entry(goal).or_insert(value);
+
+            *lowest = (*lowest).min(value);
+        }
+
+        for (&goal, &value) in field.goals.iter() {
+            field.dists.insert(goal, value);
+            frontier.push(Reverse((value, goal)));
+        }
+
+        field.relax(frontier);
+        field
+    }
+
+    /// Returns the cost of reaching the nearest goal from a hex, or `None`
+    /// if it can't reach one. Goals hold their starting values.
+    pub fn distance(&self, coord: MultiCoord) -> Option<i64> {
+        self.dists.get(&self.frame.cube_of(coord)).copied()
+    }
+
+    /// Returns the direction of the best step toward a goal from a hex, or
+    /// `None` if it can't reach a goal or no neighbor is closer. Among
+    /// equally close neighbors, the first in the order of `Cube::neighbors`
+    /// is chosen.
+    pub fn direction(&self, coord: MultiCoord) -> Option<Compass> {
+        self.best_side(self.frame.cube_of(coord))
+            .map(|side| Compass::from_neighbor(self.frame.tilt, side))
+    }
+
+    /// Returns the coordinate of the best step toward a goal from a hex, in
+    /// the source grid's `sys`, under the same conditions as `direction`.
+    pub fn next(&self, coord: MultiCoord) -> Option<MultiCoord> {
+        let cube = self.frame.cube_of(coord);
+
+        self.best_side(cube)
+            .map(|side| self.frame.coord_of(cube.neighbor(side)))
+    }
+
+    /// Returns a grid of the distance from each hex that can reach a goal.
+    pub fn distances(&self) -> HexGrid<i64> {
+        let mut grid =
+            HexGrid::new(self.frame.tilt, self.frame.parity, self.frame.sys);
+        let mut dists: Vec<(&Cube, &i64)> = self.dists.iter().collect();
+
+        dists.sort();
+
+        for (&cube, &dist) in dists {
+            grid.set(MultiCoord::from(cube), dist);
+        }
+
+        grid
+    }
+
+    /// Changes the cost of entering a hex, or makes it impassable if `cost`
+    /// is `None`, then updates the distances that depend on it. Costs below 1
+    /// are raised to 1.
+    pub fn set_cost(&mut self, coord: MultiCoord, cost: Option<u32>) {
+        let cube = self.frame.cube_of(coord);
+        let cost = cost.map(|cost| cost.max(1));
+        let old = match cost {
+            Some(cost) => self.costs.insert(cube, cost),
+            None => self.costs.remove(&cube),
+        };
+
+        if old == cost {
+            return;
+        }
+
+        let mut frontier = BinaryHeap::new();
+        let raised = match (old, cost) {
+            (Some(old), Some(new)) => new > old,
+            (Some(_), None) => true,
+            _ => false,
+        };
+
+        if raised && self.dists.contains_key(&cube) {
+            let stale = self.dependents(cube);
+
+            for hex in stale.iter() {
+                self.dists.remove(hex);
+            }
+
+            for &hex in stale.iter() {
+                if let Some(dist) = self.entry_cost(hex) {
+                    self.dists.insert(hex, dist);
+                    frontier.push(Reverse((dist, hex)));
+                }
+            }
+        } else if let Some(dist) = self.entry_cost(cube) {
+            if self.dists.get(&cube).map_or(true, |&old| dist < old) {
+                self.dists.insert(cube, dist);
+                frontier.push(Reverse((dist, cube)));
+            }
+        }
+
+        self.relax(frontier);
+    }
+
+    /// Returns a field for fleeing this one's goals. Each hex's distance is
+    /// multiplied by `-factor` and rounded, and the results are used as
+    /// goals of a new field, so agents head away from the old goals but
+    /// around dead ends toward open ground. A `factor` a little over 1, such
+    /// as 1.2, works well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_boxy(7, 1, ());
+    /// let goal = MultiCoord::offset(2, 0);
+    /// let field = grid.flow_field(&[goal], |_, _| Some(1));
+    /// let flee = field.flee(1.2);
+    ///
+    /// assert_eq!(
+    ///     flee.next(MultiCoord::offset(3, 0)),
+    ///     Some(MultiCoord::offset(4, 0))
+    /// );
+    /// assert_eq!(flee.distance(MultiCoord::offset(6, 0)), Some(-5));
+    /// ```
+    pub fn flee(&self, factor: f64) -> FlowField {
+        let goals: Vec<(Cube, i64)> = self
+            .dists
+            .iter()
+            .map(|(&cube, &dist)| {
+                (cube, (dist as f64 * -factor).round() as i64)
+            })
+            .collect();
+        let frame =
+            HexGrid::new(self.frame.tilt, self.frame.parity, self.frame.sys);
+
+        FlowField::new(frame, self.costs.clone(), goals)
+    }
+
+    /// Returns the side of the neighbor with the lowest distance, if lower
+    /// than the hex's own.
+    fn best_side(&self, cube: Cube) -> Option<usize> {
+        let mut best = *self.dists.get(&cube)?;
+        let mut side = None;
+
+        for (index, next) in cube.neighbors().into_iter().enumerate() {
+            if let Some(&dist) = self.dists.get(&next) {
+                if dist < best {
+                    best = dist;
+                    side = Some(index);
+                }
+            }
+        }
+
+        side
+    }
+
+    /// Returns the lower of a hex's starting value, if it's a goal, and the
+    /// lowest cost of reaching it from a neighbor with a known distance.
+    fn entry_cost(&self, cube: Cube) -> Option<i64> {
+        let value = self.goals.get(&cube).copied();
+        let reached = self.costs.get(&cube).and_then(|&cost| {
+            cube.neighbors()
+                .into_iter()
+                .filter_map(|next| self.dists.get(&next))
+                .min()
+                .map(|&dist| dist + i64::from(cost))
+        });
+
+        match (value, reached) {
+            (Some(value), Some(reached)) => Some(value.min(reached)),
+            (value, reached) => value.or(reached),
+        }
+    }
+
+    /// Returns the hexes whose distances may have been reached through the
+    /// given hex, including the hex itself.
+    fn dependents(&self, cube: Cube) -> HashSet<Cube> {
+        let mut found = HashSet::new();
+        let mut stack = vec![(cube, self.dists[&cube])];
+
+        found.insert(cube);
+
+        while let Some((hex, dist)) = stack.pop() {
+            for next in hex.neighbors() {
+                if found.contains(&next) {
+                    continue;
+                }
+
+                let (cost, next_dist) =
+                    match (self.costs.get(&next), self.dists.get(&next)) {
+                        (Some(&cost), Some(&next_dist)) => (cost, next_dist),
+                        _ => continue,
+                    };
+
+                if next_dist == dist + i64::from(cost) {
+                    found.insert(next);
+                    stack.push((next, next_dist));
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Lowers distances outward from the given hexes until none can be
+    /// lowered further.
+    fn relax(&mut self, mut frontier: BinaryHeap<Reverse<(i64, Cube)>>) {
+        while let Some(Reverse((dist, cube))) = frontier.pop() {
+            if self.dists.get(&cube).is_some_and(|&known| dist > known) {
+                continue;
+            }
+
+            for next in cube.neighbors() {
+                let cost = match self.costs.get(&next) {
+                    Some(&cost) => i64::from(cost),
+                    None => continue,
+                };
+                let next_dist = dist + cost;
+
+                if self.dists.get(&next).map_or(true, |&old| next_dist < old) {
+                    self.dists.insert(next, next_dist);
+                    frontier.push(Reverse((next_dist, next)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! Procedural Generation
+//!
+//! Seeded value, Perlin, and simplex noise, sampled at the pixel centers of
+//! hexes to build heightmaps of any shape. The same seed always produces the
+//! same noise, so worlds can be regenerated from their seed alone. Elevation
+//! and moisture can then be thresholded into biomes with `Bands`.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::generate::{biomes, Bands, Fractal, Noise, NoiseKind};
+//! use chickenwire::prelude::*;
+//!
+//! let shape = HexGrid::new_boxy(16, 12, ());
+//! let fractal = Fractal::default();
+//! let elevation = Noise::new(NoiseKind::Simplex, 7)
+//!     .heightmap(&shape, &fractal);
+//! let moisture = Noise::new(NoiseKind::Perlin, 8)
+//!     .heightmap(&shape, &fractal);
+//!
+//! let lowlands = Bands::new("forest").below(-0.2, "marsh");
+//! let table = Bands::new(Bands::new("mountain"))
+//!     .below(-0.3, Bands::new("ocean"))
+//!     .below(0.4, lowlands);
+//! let world = biomes(&elevation, &moisture, &table);
+//!
+//! assert_eq!(world.iter().count(), 16 * 12);
+//!
+//! // The same seed reproduces the same world.
+//! let again = Noise::new(NoiseKind::Simplex, 7).heightmap(&shape, &fractal);
+//!
+//! assert!(biomes(&again, &moisture, &table).diff(&world).is_empty());
+//! ```
+
+use crate::geometry;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Noise
+//////////////////////////////////////////////////////////////////////////////
+
+/// The algorithm used by a `Noise` source.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NoiseKind {
+    /// Random values at integer points, smoothly interpolated. Blocky, but
+    /// cheap.
+    Value,
+    /// Random gradients at integer points, smoothly interpolated.
+    Perlin,
+    /// Random gradients on a triangular lattice, with fewer axis-aligned
+    /// artifacts than `Perlin`.
+    Simplex,
+}
+
+/// A seeded source of 2D coherent noise.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Noise {
+    pub kind: NoiseKind,
+    seed: u64,
+    perm: Vec<u8>,
+}
+
+/// Gradients for `Perlin` and `Simplex` noise.
+const GRADIENTS: [(f64, f64); 8] = [
+    (1.0, 1.0),
+    (-1.0, 1.0),
+    (1.0, -1.0),
+    (-1.0, -1.0),
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+];
+
+impl Noise {
+    /// Creates a noise source of the given kind, shuffled by `seed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::generate::{Noise, NoiseKind};
+    ///
+    /// let noise = Noise::new(NoiseKind::Perlin, 42);
+    /// let again = Noise::new(NoiseKind::Perlin, 42);
+    ///
+    /// assert_eq!(noise.sample((1.5, 2.25)), again.sample((1.5, 2.25)));
+    /// assert_eq!(noise.sample((3.0, 4.0)), 0.0);
+    /// ```
+    pub fn new(kind: NoiseKind, seed: u64) -> Self {
+        let mut state = seed;
+        let mut perm: Vec<u8> = (0..=255).collect();
+
+        for i in (1..perm.len()).rev() {
+            let j = (split_mix(&mut state) % (i as u64 + 1)) as usize;
+
+            perm.swap(i, j);
+        }
+
+        perm.extend_from_within(..);
+
+        Self { kind, seed, perm }
+    }
+
+    /// Returns the seed the noise source was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the noise at a point, between -1 and 1. `Perlin` and
+    /// `Simplex` noise is 0 at integer points.
+    pub fn sample(&self, (x, y): (f64, f64)) -> f64 {
+        let value = match self.kind {
+            NoiseKind::Value => self.value(x, y),
+            NoiseKind::Perlin => self.perlin(x, y),
+            NoiseKind::Simplex => self.simplex(x, y),
+        };
+
+        value.clamp(-1.0, 1.0)
+    }
+
+    /// Returns the sum of several octaves of noise at a point, scaled back
+    /// to between -1 and 1.
+    pub fn fractal(&self, fractal: &Fractal, (x, y): (f64, f64)) -> f64 {
+        let mut frequency = fractal.frequency;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut max = 0.0;
+
+        for octave in 0..fractal.octaves {
+            // Shift each octave so their integer points don't line up.
+            let shift = f64::from(octave) * 31.7;
+
+            total += amplitude
+                * self.sample((x * frequency + shift, y * frequency + shift));
+            max += amplitude;
+            frequency *= fractal.lacunarity;
+            amplitude *= fractal.persistence;
+        }
+
+        if max > 0.0 {
+            total / max
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns a grid with the same layout as `shape`, holding the fractal
+    /// noise at the center of each hex. Centers are found with
+    /// `geometry::center` for hexes of size 1, so `fractal.frequency` is in
+    /// cycles per hex radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::generate::{Fractal, Noise, NoiseKind};
+    /// use chickenwire::prelude::*;
+    ///
+    /// let shape = HexGrid::new_radial(5, ());
+    /// let heights = Noise::new(NoiseKind::Value, 1)
+    ///     .heightmap(&shape, &Fractal::default());
+    ///
+    /// assert_eq!(heights.iter().count(), shape.iter().count());
+    /// assert!(heights.iter().all(|(_, h)| (-1.0..=1.0).contains(h)));
+    /// ```
+    pub fn heightmap<T>(
+        &self,
+        shape: &HexGrid<T>,
+        fractal: &Fractal,
+    ) -> HexGrid<f64> {
+        shape.map(|coord, _| {
+            let cube = shape.cube_of(coord);
+
+            self.fractal(fractal, geometry::center(shape.tilt, 1.0, cube))
+        })
+    }
+
+    /// Returns the permuted byte for a lattice point.
+    fn hash(&self, i: i64, j: i64) -> u8 {
+        let i = (i & 255) as usize;
+        let j = (j & 255) as usize;
+
+        self.perm[usize::from(self.perm[i]) + j]
+    }
+
+    /// Returns the dot product of a lattice point's gradient with an offset.
+    fn gradient(&self, i: i64, j: i64, x: f64, y: f64) -> f64 {
+        let (gx, gy) = GRADIENTS[usize::from(self.hash(i, j) & 7)];
+
+        gx * x + gy * y
+    }
+
+    fn value(&self, x: f64, y: f64) -> f64 {
+        let (i, j) = (x.floor() as i64, y.floor() as i64);
+        let (u, v) = (fade(x - x.floor()), fade(y - y.floor()));
+        let at = |di, dj| f64::from(self.hash(i + di, j + dj)) / 127.5 - 1.0;
+
+        lerp(v, lerp(u, at(0, 0), at(1, 0)), lerp(u, at(0, 1), at(1, 1)))
+    }
+
+    fn perlin(&self, x: f64, y: f64) -> f64 {
+        let (i, j) = (x.floor() as i64, y.floor() as i64);
+        let (fx, fy) = (x - x.floor(), y - y.floor());
+        let (u, v) = (fade(fx), fade(fy));
+        let at = |di: i64, dj: i64| {
+            self.gradient(i + di, j + dj, fx - di as f64, fy - dj as f64)
+        };
+
+        lerp(v, lerp(u, at(0, 0), at(1, 0)), lerp(u, at(0, 1), at(1, 1)))
+    }
+
+    fn simplex(&self, x: f64, y: f64) -> f64 {
+        let sqrt_3 = 3f64.sqrt();
+        let skew = (sqrt_3 - 1.0) / 2.0;
+        let unskew = (3.0 - sqrt_3) / 6.0;
+
+        // Find the triangle containing the point, and the offsets from its
+        // three corners.
+        let s = (x + y) * skew;
+        let (i, j) = ((x + s).floor(), (y + s).floor());
+        let t = (i + j) * unskew;
+        let (x0, y0) = (x - (i - t), y - (j - t));
+        let (di, dj) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let (i, j) = (i as i64, j as i64);
+        let corners = [
+            (0, 0, x0, y0),
+            (di, dj, x0 - di as f64 + unskew, y0 - dj as f64 + unskew),
+            (1, 1, x0 - 1.0 + 2.0 * unskew, y0 - 1.0 + 2.0 * unskew),
+        ];
+
+        let total: f64 = corners
+            .iter()
+            .map(|&(ci, cj, cx, cy)| {
+                let falloff = 0.5 - cx * cx - cy * cy;
+
+                if falloff < 0.0 {
+                    0.0
+                } else {
+                    falloff.powi(4) * self.gradient(i + ci, j + cj, cx, cy)
+                }
+            })
+            .sum();
+
+        70.0 * total
+    }
+}
+
+/// Settings for layering octaves of noise with `Noise::fractal`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fractal {
+    /// Number of octaves layered together.
+    pub octaves: u32,
+    /// Frequency of the first octave.
+    pub frequency: f64,
+    /// Factor each octave's frequency is multiplied by.
+    pub lacunarity: f64,
+    /// Factor each octave's amplitude is multiplied by.
+    pub persistence: f64,
+}
+
+impl Default for Fractal {
+    /// Four octaves, starting at a frequency of 0.1, each doubling in
+    /// frequency and halving in amplitude.
+    fn default() -> Self {
+        Self {
+            octaves: 4,
+            frequency: 0.1,
+            lacunarity: 2.0,
+            persistence: 0.5,
+        }
+    }
+}
+
+/// Advances a SplitMix64 state, returning the next random number.
+pub(crate) fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *state;
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Quintic smoothing curve, easing interpolation at lattice points.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Biomes
+//////////////////////////////////////////////////////////////////////////////
+
+/// Maps numbers to values by threshold, such as elevations to terrain.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::generate::Bands;
+///
+/// let terrain = Bands::new("peak")
+///     .below(0.0, "water")
+///     .below(0.5, "land");
+///
+/// assert_eq!(*terrain.get(-0.5), "water");
+/// assert_eq!(*terrain.get(0.0), "land");
+/// assert_eq!(*terrain.get(0.9), "peak");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bands<T> {
+    bands: Vec<(f64, T)>,
+    top: T,
+}
+
+impl<T> Bands<T> {
+    /// Creates `Bands` mapping every number to `top`.
+    pub fn new(top: T) -> Self {
+        Self {
+            bands: Vec::new(),
+            top,
+        }
+    }
+
+    /// Adds a band mapping numbers below `limit`, and at or above any lower
+    /// limit, to `value`. Bands can be added in any order.
+    pub fn below(mut self, limit: f64, value: T) -> Self {
+        let index = self.bands.partition_point(|&(other, _)| other < limit);
+
+        self.bands.insert(index, (limit, value));
+        self
+    }
+
+    /// Returns the value of the band containing `x`.
+    pub fn get(&self, x: f64) -> &T {
+        self.bands
+            .iter()
+            .find(|&&(limit, _)| x < limit)
+            .map_or(&self.top, |(_, value)| value)
+    }
+}
+
+impl HexGrid<f64> {
+    /// Returns a grid with the same layout as the calling instance, holding
+    /// the band each hex's number falls in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::generate::Bands;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let heights = HexGrid::new_radial(2, 0.7);
+    /// let terrain = heights.classify(&Bands::new('^').below(0.5, '.'));
+    ///
+    /// assert_eq!(terrain[MultiCoord::axial(1, 0)], '^');
+    /// ```
+    pub fn classify<T>(&self, bands: &Bands<T>) -> HexGrid<T>
+    where
+        T: Clone,
+    {
+        self.map(|_, &x| bands.get(x).clone())
+    }
+}
+
+/// Returns a grid with the same layout as `elevation`, choosing each hex's
+/// biome from its elevation and then its moisture. Only hexes present in
+/// both grids are included.
+pub fn biomes<T>(
+    elevation: &HexGrid<f64>,
+    moisture: &HexGrid<f64>,
+    table: &Bands<Bands<T>>,
+) -> HexGrid<T>
+where
+    T: Clone,
+{
+    elevation.zip_with(moisture, |_, &high, &wet| {
+        table.get(high).get(wet).clone()
+    })
+}
@@ -0,0 +1,169 @@
+//! Hex Geometry
+//!
+//! Conversions between hexes and the pixel space they're drawn in, following
+//! Red Blob Games' guide. Pixel space has its origin at the center of the
+//! `Cube::ORIGIN` hex, with `y` increasing downward. `size` is the distance
+//! from a hex's center to any of its corners.
+
+use crate::coordinate::*;
+use crate::hexgrid::Tilt;
+
+/// Returns the pixel position of the center of a hex.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::Cube;
+/// use chickenwire::geometry;
+/// use chickenwire::hexgrid::Tilt;
+///
+/// let northeast = Cube::force_from_coords(1, 0, -1);
+/// let (x, y) = geometry::center(Tilt::Flat, 2.0, northeast);
+///
+/// assert_eq!(x, 3.0);
+/// assert!((y + 3f64.sqrt()).abs() < 1e-9);
+/// ```
+pub fn center(tilt: Tilt, size: f64, coord: Cube) -> (f64, f64) {
+    let (x, _, z) = coord.to_tuple();
+    let (q, r) = (f64::from(x), f64::from(z));
+    let sqrt_3 = 3f64.sqrt();
+
+    match tilt {
+        Tilt::Flat => (size * 1.5 * q, size * sqrt_3 * (r + q / 2.0)),
+        Tilt::Sharp => (size * sqrt_3 * (q + r / 2.0), size * 1.5 * r),
+    }
+}
+
+/// Returns the pixel positions of the six corners of a hex centered at the
+/// given point, clockwise from the east-most corner of a `Tilt::Flat` hex or
+/// the east-northeast corner of a `Tilt::Sharp` hex.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::geometry;
+/// use chickenwire::hexgrid::Tilt;
+///
+/// let corners = geometry::corners(Tilt::Flat, 1.0, (0.0, 0.0));
+///
+/// assert_eq!(corners[0], (1.0, 0.0));
+/// assert!((corners[3].0 + 1.0).abs() < 1e-9);
+/// ```
+pub fn corners(
+    tilt: Tilt,
+    size: f64,
+    (cx, cy): (f64, f64),
+) -> [(f64, f64); 6] {
+    let start = match tilt {
+        Tilt::Flat => 0.0,
+        Tilt::Sharp => -30.0,
+    };
+    let mut corners = [(0.0, 0.0); 6];
+
+    for (i, corner) in corners.iter_mut().enumerate() {
+        let angle = (start + 60.0 * i as f64).to_radians();
+
+        *corner = (cx + size * angle.cos(), cy + size * angle.sin());
+    }
+
+    corners
+}
+
+/// Returns the hex containing the given pixel position. Positions exactly on
+/// an edge belong to either of the hexes sharing it. Returns `None` if
+/// `size` isn't finite and positive, or the hex's coordinates don't fit in
+/// `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::coordinate::Cube;
+/// use chickenwire::geometry;
+/// use chickenwire::hexgrid::Tilt;
+///
+/// let coord = Cube::force_from_coords(2, -3, 1);
+/// let point = geometry::center(Tilt::Sharp, 5.0, coord);
+///
+/// assert_eq!(geometry::hex_at(Tilt::Sharp, 5.0, point), Some(coord));
+/// assert_eq!(
+///     geometry::hex_at(Tilt::Sharp, 5.0, (point.0 + 4.0, point.1)),
+///     Some(coord)
+/// );
+/// assert_eq!(geometry::hex_at(Tilt::Sharp, 0.0, point), None);
+/// ```
+pub fn hex_at(tilt: Tilt, size: f64, (px, py): (f64, f64)) -> Option<Cube> {
+    if !size.is_finite() || size <= 0.0 {
+        return None;
+    }
+
+    let sqrt_3 = 3f64.sqrt();
+    let (x, z) = match tilt {
+        Tilt::Flat => (
+            (2.0 / 3.0 * px) / size,
+            (-px / 3.0 + sqrt_3 / 3.0 * py) / size,
+        ),
+        Tilt::Sharp => (
+            (sqrt_3 / 3.0 * px - py / 3.0) / size,
+            (2.0 / 3.0 * py) / size,
+        ),
+    };
+    let y = -x - z;
+
+    let (mut rx, mut ry, mut rz) = (x.round(), y.round(), z.round());
+    let (dx, dy, dz) = ((rx - x).abs(), (ry - y).abs(), (rz - z).abs());
+
+    if dx > dy && dx > dz {
+        rx = -ry - rz;
+    } else if dy > dz {
+        ry = -rx - rz;
+    } else {
+        rz = -rx - ry;
+    }
+
+    let range = f64::from(i32::MIN)..=f64::from(i32::MAX);
+
+    if [rx, ry, rz].iter().all(|value| range.contains(value)) {
+        Cube::from_coords(rx as i32, ry as i32, rz as i32).ok()
+    } else {
+        None
+    }
+}
+
+/// Returns the distance from a pixel position inside a hex centered at
+/// `center` to the nearest of the hex's edges. The result is negative for
+/// positions outside the hex.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::geometry;
+/// use chickenwire::hexgrid::Tilt;
+///
+/// let inradius = 3f64.sqrt() / 2.0;
+///
+/// let center = (0.0, 0.0);
+///
+/// assert_eq!(geometry::edge_dist(Tilt::Flat, 1.0, center, center), inradius);
+/// assert!(geometry::edge_dist(Tilt::Flat, 1.0, center, (0.0, 1.0)) < 0.0);
+/// ```
+pub fn edge_dist(
+    tilt: Tilt,
+    size: f64,
+    (cx, cy): (f64, f64),
+    (px, py): (f64, f64),
+) -> f64 {
+    let (dx, dy) = (px - cx, py - cy);
+    let start = match tilt {
+        Tilt::Flat => 30.0,
+        Tilt::Sharp => 0.0,
+    };
+    let mut farthest: f64 = 0.0;
+
+    for i in 0..3 {
+        let angle = (start + 60.0 * f64::from(i)).to_radians();
+
+        farthest = farthest.max((dx * angle.cos() + dy * angle.sin()).abs());
+    }
+
+    size * 3f64.sqrt() / 2.0 - farthest
+}
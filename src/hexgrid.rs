@@ -5,7 +5,8 @@
 //! instance. These parameters aren't central to the actual representation of
 //! the grid, so changing them is cheap and painless.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::{Index, IndexMut};
 
 use petgraph::graph::NodeIndex;
 use petgraph::stable_graph::StableGraph;
@@ -14,6 +15,7 @@ use crate::coordinate::*;
 use crate::coordinate::cube::*;
 use crate::coordinate::double::*;
 use crate::coordinate::offset::*;
+use crate::events::{GridEvent, ListenerId, Listeners};
 
 //////////////////////////////////////////////////////////////////////////////
 // Compass
@@ -62,6 +64,42 @@ impl Compass {
         cur_dir
     }
 
+    /// Returns the direction of the neighbor at the given index, as used by
+    /// `Cube::neighbor`, in a `HexGrid` with the given tilt. Indices wrap
+    /// around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::hexgrid::{Compass, Tilt};
+    ///
+    /// assert_eq!(Compass::from_neighbor(Tilt::Flat, 2), Compass::South);
+    /// assert_eq!(Compass::from_neighbor(Tilt::Sharp, 4), Compass::West);
+    /// assert_eq!(Compass::from_neighbor(Tilt::Sharp, 6), Compass::Northeast);
+    /// ```
+    pub fn from_neighbor(tilt: Tilt, index: usize) -> Self {
+        let directions = match tilt {
+            Tilt::Flat => [
+                Compass::Northeast,
+                Compass::Southeast,
+                Compass::South,
+                Compass::Southwest,
+                Compass::Northwest,
+                Compass::North,
+            ],
+            Tilt::Sharp => [
+                Compass::Northeast,
+                Compass::East,
+                Compass::Southeast,
+                Compass::Southwest,
+                Compass::West,
+                Compass::Northwest,
+            ],
+        };
+
+        directions[index % 6]
+    }
+
     pub fn inverse(self) -> Self {
         match self {
             Compass::North => Compass::South,
@@ -86,6 +124,7 @@ pub enum Tilt {
     Sharp,
 }
 
+/// The default `Tilt` is `Tilt::Flat`.
 impl Default for Tilt {
     fn default() -> Self {
         Tilt::Flat
@@ -120,6 +159,7 @@ pub enum Parity {
     Odd,
 }
 
+/// The default `Parity` is `Parity::Even`.
 impl Default for Parity {
     fn default() -> Self {
         Parity::Even
@@ -165,6 +205,9 @@ pub struct HexGrid<T> {
     pub sys: CoordSys,
     graph: HexGraph<T>,
     map: HexMap,
+    /// Hexes touched since changes were last taken, if tracking changes.
+    changes: Option<HashSet<Cube>>,
+    listeners: Listeners<T>,
 }
 
 impl<T> Default for HexGrid<T> {
@@ -175,6 +218,8 @@ impl<T> Default for HexGrid<T> {
             sys: CoordSys::default(),
             graph: StableGraph::new(),
             map: HashMap::new(),
+            changes: None,
+            listeners: Listeners::default(),
         }
     }
 }
@@ -218,42 +263,89 @@ impl<T> HexGrid<T> {
         }
     }
 
+    /// Convert a `Cube` to its equivalent in the given coordinate system for
+    /// the given `HexGrid`.
+    fn multi_from(&self, coord: Cube, sys: CoordSys) -> MultiCoord {
+        match sys {
+            CoordSys::Offset => {
+                let offset = match (self.tilt, self.parity) {
+                    (Tilt::Flat, Parity::Odd) => Offset::cube_to_oflat(coord),
+                    (Tilt::Flat, Parity::Even) => {
+                        Offset::cube_to_eflat(coord)
+                    }
+                    (Tilt::Sharp, Parity::Odd) => {
+                        Offset::cube_to_osharp(coord)
+                    }
+                    (Tilt::Sharp, Parity::Even) => {
+                        Offset::cube_to_esharp(coord)
+                    }
+                };
+
+                MultiCoord::from(offset)
+            }
+            CoordSys::Double => {
+                let double = match self.tilt {
+                    Tilt::Flat => Double::cube_to_flat(coord),
+                    _ => Double::cube_to_sharp(coord),
+                };
+
+                MultiCoord::from(double)
+            }
+            CoordSys::Axial => MultiCoord::from(Axial::from(coord)),
+            CoordSys::Cube => MultiCoord::from(coord),
+        }
+    }
+
     /// Return the `NodeIndex` of the node at the given coordinate if it
     /// exists.
-    fn graph_index(&self, coord: MultiCoord) -> Option<&NodeIndex> {
+    pub(crate) fn graph_index(
+        &self,
+        coord: MultiCoord,
+    ) -> Option<&NodeIndex> {
         self.map.get(&self.cube_from(coord))
     }
 
+    /// Records a hex as touched, if tracking changes.
+    fn touch(&mut self, coord: MultiCoord) {
+        let cube = self.cube_from(coord);
+
+        if let Some(changes) = self.changes.as_mut() {
+            changes.insert(cube);
+        }
+    }
+
     /// Initialize all the edges between a hex and its neighbors.
     fn nlink(&mut self, coord: MultiCoord) {
-        match self.graph_index(coord) {
-            None => (),
-            Some(&own_index) => {
-                let mut dir = Compass::Northeast;
-                let neighbor_coords = self.cube_from(coord).neighbors();
-
-                for _ in &neighbor_coords {
-                    match self.graph_index(coord) {
-                        None => (),
-                        Some(&other_index) => {
-                            self.graph.add_edge(
-                                own_index,
-                                other_index,
-                                dir
-                            );
-                            self.graph.add_edge(
-                                other_index,
-                                own_index,
-                                dir.inverse(),
-                            );
-                        }
-                    }
-                    dir = dir.rotate_cw(1);
+        let cube = self.cube_from(coord);
+
+        if let Some(&own_index) = self.map.get(&cube) {
+            for (index, neighbor) in cube.neighbors().iter().enumerate() {
+                if let Some(&other_index) = self.map.get(neighbor) {
+                    let dir = Compass::from_neighbor(self.tilt, index);
+
+                    self.graph.add_edge(own_index, other_index, dir);
+                    self.graph.add_edge(other_index, own_index, dir.inverse());
                 }
             }
         }
     }
 
+    /// Returns every edge of the grid's graph as the `Cube` coordinates of
+    /// its endpoints and its direction.
+    pub(crate) fn links(&self) -> Vec<(Cube, Cube, Compass)> {
+        let cubes: HashMap<NodeIndex, Cube> =
+            self.map.iter().map(|(&cube, &index)| (index, cube)).collect();
+
+        self.graph
+            .edge_indices()
+            .filter_map(|edge| {
+                let (from, to) = self.graph.edge_endpoints(edge)?;
+
+                Some((cubes[&from], cubes[&to], self.graph[edge]))
+            })
+            .collect()
+    }
+
     //////////////////////////////////
     // Initialization
     //////////////////////////////////
@@ -261,9 +353,9 @@ impl<T> HexGrid<T> {
     /// Creates an empty `HexGrid` with the given parameters.
     pub fn new(tilt: Tilt, parity: Parity, sys: CoordSys) -> Self {
         Self {
-            tilt: tilt,
-            parity: parity,
-            sys: sys,
+            tilt,
+            parity,
+            sys,
             ..Default::default()
         }
     }
@@ -282,9 +374,10 @@ impl<T> HexGrid<T> {
         if radius != 0 {
             let new_hexes = Cube::ORIGIN.spiral(radius);
 
-            for new_hex in new_hexes {
-                grid.set(MultiCoord::from(new_hex), blank_val);
-            }
+            grid.fill_region(
+                new_hexes.into_iter().map(MultiCoord::from),
+                blank_val,
+            );
         }
 
         grid
@@ -305,16 +398,64 @@ impl<T> HexGrid<T> {
             ..Default::default()
         };
 
-        for col in 0..(cols as i32) {
-            for row in 0..(rows as i32) {
-                let offset = Offset { col: col, row: row };
-                grid.set(MultiCoord::from(offset), blank_val);
-            }
-        }
+        let offsets = (0..(cols as i32)).flat_map(|col| {
+            (0..(rows as i32)).map(move |row| Offset { col, row })
+        });
 
+        grid.fill_region(offsets.map(MultiCoord::from), blank_val);
         grid
     }
 
+    //////////////////////////////////
+    // Conversion
+    //////////////////////////////////
+
+    /// Converts a coordinate to the given coordinate system, using the
+    /// `tilt` and `parity` of the calling instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::hexgrid::{Parity, Tilt};
+    ///
+    /// let grid: HexGrid<()> =
+    ///     HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+    ///
+    /// assert_eq!(
+    ///     grid.convert(MultiCoord::offset(0, 1), CoordSys::Cube),
+    ///     MultiCoord::force_cube(0, -1, 1)
+    /// );
+    /// ```
+    pub fn convert(&self, coord: MultiCoord, sys: CoordSys) -> MultiCoord {
+        self.multi_from(self.cube_from(coord), sys)
+    }
+
+    /// Returns the `Cube` equivalent of a coordinate, using the `tilt` and
+    /// `parity` of the calling instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::hexgrid::{Parity, Tilt};
+    ///
+    /// let grid: HexGrid<()> =
+    ///     HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+    /// let cube = Cube::from_coords(0, -1, 1).unwrap();
+    ///
+    /// assert_eq!(grid.cube_of(MultiCoord::offset(0, 1)), cube);
+    /// assert_eq!(grid.coord_of(cube), MultiCoord::offset(0, 1));
+    /// ```
+    pub fn cube_of(&self, coord: MultiCoord) -> Cube {
+        self.cube_from(coord)
+    }
+
+    /// Converts a `Cube` coordinate to the `sys` of the calling instance.
+    pub fn coord_of(&self, cube: Cube) -> MultiCoord {
+        self.multi_from(cube, self.sys)
+    }
+
     //////////////////////////////////
     // Boolean Analysis
     //////////////////////////////////
@@ -339,10 +480,11 @@ impl<T> HexGrid<T> {
     }
 
     /// Return a mutable reference to the data contained at the given
-    /// coordinate within the grid, if it exists.
+    /// coordinate within the grid, if it exists. When tracking changes, the
+    /// hex is recorded as touched whether or not it's modified.
     pub fn get_mut(&mut self, coord: MultiCoord) -> Option<&mut T> {
         match self.graph_index(coord) {
-            Some(&index) => self.graph.node_weight_mut(index),
+            Some(&index) => Some(self.value_at_mut(coord, index)),
             _ => None,
         }
     }
@@ -386,36 +528,554 @@ impl<T> HexGrid<T> {
 
     /// Either creates & adds or updates a hex value at the given coordinate.
     pub fn set(&mut self, coord: MultiCoord, data: T) {
-        match self.get_mut(coord) {
-            Some(contents) => {
-                *contents = data;
+        match self.graph_index(coord) {
+            Some(&index) => {
+                self.replace_at(coord, index, data);
             }
             _ => {
-                self.map.insert(
-                    self.cube_from(coord),
-                    self.graph.add_node(data),
-                );
+                self.insert_at(coord, data);
             }
         }
+    }
+
+    /// Replaces the value of a present hex, returning the old value.
+    pub(crate) fn replace_at(
+        &mut self,
+        coord: MultiCoord,
+        index: NodeIndex,
+        data: T,
+    ) -> T {
+        self.touch(coord);
+
+        let old = std::mem::replace(&mut self.graph[index], data);
+
+        if !self.listeners.is_empty() {
+            let event = GridEvent::CellChanged {
+                coord: self.convert(coord, self.sys),
+                old: &old,
+                new: &self.graph[index],
+            };
+
+            self.listeners.notify(&event);
+        }
+
+        old
+    }
+
+    /// Adds a hex at a vacant coordinate, returning its index in the graph.
+    pub(crate) fn insert_at(
+        &mut self,
+        coord: MultiCoord,
+        data: T,
+    ) -> NodeIndex {
+        self.touch(coord);
+
+        let index = self.graph.add_node(data);
+
+        self.map.insert(self.cube_from(coord), index);
         self.nlink(coord);
+
+        if !self.listeners.is_empty() {
+            let event = GridEvent::CellAdded {
+                coord: self.convert(coord, self.sys),
+                value: &self.graph[index],
+            };
+
+            self.listeners.notify(&event);
+        }
+
+        index
+    }
+
+    /// Returns a reference to the value of a present hex.
+    pub(crate) fn value_at(&self, index: NodeIndex) -> &T {
+        &self.graph[index]
+    }
+
+    /// Returns a mutable reference to the value of a present hex, recording
+    /// it as touched.
+    pub(crate) fn value_at_mut(
+        &mut self,
+        coord: MultiCoord,
+        index: NodeIndex,
+    ) -> &mut T {
+        self.touch(coord);
+        &mut self.graph[index]
     }
 
     /// Cleanly removes a hex from the grid. Associated data will be returned.
     /// If the coordinate had no associated data, `None` will be returned.
     pub fn remove(&mut self, coord: MultiCoord) -> Option<T> {
-        match self.graph_index(coord) {
-            Some(&index) => {
-                self.map.remove(&self.cube_from(coord));
-                self.graph.remove_node(index)
-            }
+        let cube = self.cube_from(coord);
+
+        match self.map.get(&cube) {
+            Some(&index) => Some(self.remove_at(cube, index)),
             _ => None,
         }
     }
 
+    /// Removes a present hex, returning its value.
+    fn remove_at(&mut self, cube: Cube, index: NodeIndex) -> T {
+        self.map.remove(&cube);
+        self.touch(MultiCoord::from(cube));
+
+        let value = self.graph.remove_node(index).expect("mapped hex");
+
+        if !self.listeners.is_empty() {
+            let event = GridEvent::CellRemoved {
+                coord: self.multi_from(cube, self.sys),
+                value: &value,
+            };
+
+            self.listeners.notify(&event);
+        }
+
+        value
+    }
+
+    //////////////////////////////////
+    // Change Tracking
+    //////////////////////////////////
+
+    /// Starts or stops recording the hexes touched by `set`, `remove`, and
+    /// `get_mut`, along with `add` and `update`. Starting while already
+    /// tracking keeps the hexes recorded so far, and stopping discards them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_radial(2, 0);
+    ///
+    /// grid.track_changes(true);
+    /// grid.set(MultiCoord::axial(1, 0), 4);
+    /// grid.remove(MultiCoord::axial(0, 1));
+    /// grid.remove(MultiCoord::axial(9, 9));
+    ///
+    /// assert_eq!(grid.take_changes().len(), 2);
+    /// assert!(grid.take_changes().is_empty());
+    /// ```
+    pub fn track_changes(&mut self, enabled: bool) {
+        if !enabled {
+            self.changes = None;
+        } else if self.changes.is_none() {
+            self.changes = Some(HashSet::new());
+        }
+    }
+
+    /// Returns `true` if the calling instance is recording touched hexes,
+    /// else `false`.
+    pub fn is_tracking_changes(&self) -> bool {
+        self.changes.is_some()
+    }
+
+    /// Returns the coordinates of the hexes touched since tracking started or
+    /// changes were last taken, in the grid's `sys`, and clears the record.
+    /// Hexes that were removed are included. Returns nothing when not
+    /// tracking changes.
+    pub fn take_changes(&mut self) -> Vec<MultiCoord> {
+        let mut cubes: Vec<Cube> = match self.changes.as_mut() {
+            Some(changes) => changes.drain().collect(),
+            None => Vec::new(),
+        };
+
+        cubes.sort_by_key(|cube| cube.to_tuple());
+        cubes
+            .into_iter()
+            .map(|cube| self.multi_from(cube, self.sys))
+            .collect()
+    }
+
+    //////////////////////////////////
+    // Events
+    //////////////////////////////////
+
+    /// Subscribes a listener to the calling instance's `GridEvent`s, which
+    /// are raised by `set`, `add`, `update`, and `remove`, as well as by
+    /// `fill_region`, `retain`, and `drain`. Listeners are called in order
+    /// of subscription. Listeners must be `Send`, so that grids can still
+    /// be sent between threads, but needn't be `Sync`. Returns an id for
+    /// unsubscribing the listener.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_radial(1, 0);
+    /// let count = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&count);
+    ///
+    /// let id = grid.subscribe(move |_| {
+    ///     counter.fetch_add(1, Ordering::SeqCst);
+    /// });
+    ///
+    /// grid.set(MultiCoord::axial(0, 0), 1);
+    /// assert!(grid.unsubscribe(id));
+    /// grid.set(MultiCoord::axial(0, 0), 2);
+    ///
+    /// assert_eq!(count.load(Ordering::SeqCst), 1);
+    /// ```
+    pub fn subscribe<F>(&mut self, listener: F) -> ListenerId
+    where
+        F: FnMut(&GridEvent<T>) + Send + 'static,
+    {
+        self.listeners.add(Box::new(listener))
+    }
+
+    /// Unsubscribes a listener. Returns `true` if it was subscribed, else
+    /// `false`.
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        self.listeners.remove(id)
+    }
+
+    //////////////////////////////////
+    // Bulk Operations
+    //////////////////////////////////
+
+    /// Wraps a graph sharing the calling instance's node indices in a grid
+    /// with the calling instance's layout. Hexes missing from the graph are
+    /// left out.
+    fn with_graph<U>(&self, graph: HexGraph<U>) -> HexGrid<U> {
+        let map = self
+            .map
+            .iter()
+            .filter(|&(_, &index)| graph.contains_node(index))
+            .map(|(&cube, &index)| (cube, index))
+            .collect();
+
+        HexGrid {
+            tilt: self.tilt,
+            parity: self.parity,
+            sys: self.sys,
+            graph,
+            map,
+            changes: None,
+            listeners: Listeners::default(),
+        }
+    }
+
+    /// Returns the coordinates, in the grid's `sys`, of every hex by its
+    /// index in the graph.
+    fn coords_by_index(&self) -> HashMap<NodeIndex, MultiCoord> {
+        self.map
+            .iter()
+            .map(|(&cube, &index)| (index, self.multi_from(cube, self.sys)))
+            .collect()
+    }
+
+    /// Returns a grid with the same layout and hexes as the calling
+    /// instance, holding the results of `f` for each hex's coordinate and
+    /// value. Coordinates are given in the grid's `sys`. Listeners and
+    /// change tracking aren't carried over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_boxy(3, 2, 1);
+    /// let cols = grid.map(|coord, &value| Offset::from(coord).col * value);
+    ///
+    /// assert_eq!(cols[MultiCoord::offset(2, 1)], 2);
+    /// ```
+    pub fn map<U, F>(&self, mut f: F) -> HexGrid<U>
+    where
+        F: FnMut(MultiCoord, &T) -> U,
+    {
+        let coords = self.coords_by_index();
+        let graph = self
+            .graph
+            .map(|index, value| f(coords[&index], value), |_, &dir| dir);
+
+        self.with_graph(graph)
+    }
+
+    /// Returns a grid with the same layout as the calling instance, holding
+    /// copies of the hexes for which `f` returns `true`. Listeners and
+    /// change tracking aren't carried over.
+    pub fn filter<F>(&self, mut f: F) -> Self
+    where
+        T: Clone,
+        F: FnMut(MultiCoord, &T) -> bool,
+    {
+        let coords = self.coords_by_index();
+        let graph = self.graph.filter_map(
+            |index, value| {
+                if f(coords[&index], value) {
+                    Some(value.clone())
+                } else {
+                    None
+                }
+            },
+            |_, &dir| Some(dir),
+        );
+
+        self.with_graph(graph)
+    }
+
+    /// Returns a grid with the same layout as the calling instance, holding
+    /// the results of `f` for each hex present in both the calling instance
+    /// and `other`. Hexes are matched by position, so `other` needn't share
+    /// the calling instance's layout. Listeners and change tracking aren't
+    /// carried over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let heights = HexGrid::new_radial(2, 10);
+    /// let floods = HexGrid::new_radial(1, 4);
+    /// let depths = heights.zip_with(&floods, |_, high, flood| high - flood);
+    ///
+    /// assert_eq!(depths.iter().count(), 7);
+    /// assert_eq!(depths[MultiCoord::axial(0, 0)], 6);
+    /// ```
+    pub fn zip_with<U, V, F>(&self, other: &HexGrid<U>, mut f: F) -> HexGrid<V>
+    where
+        F: FnMut(MultiCoord, &T, &U) -> V,
+    {
+        let cubes: HashMap<NodeIndex, Cube> =
+            self.map.iter().map(|(&cube, &index)| (index, cube)).collect();
+        let graph = self.graph.filter_map(
+            |index, value| {
+                let cube = cubes[&index];
+                let &other_index = other.map.get(&cube)?;
+
+                Some(f(
+                    self.multi_from(cube, self.sys),
+                    value,
+                    &other.graph[other_index],
+                ))
+            },
+            |_, &dir| Some(dir),
+        );
+
+        self.with_graph(graph)
+    }
+
+    /// Removes every hex for which `f` returns `false`. Removals are tracked
+    /// and raise events just like `remove`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_boxy(4, 4, 0);
+    ///
+    /// grid.retain(|coord, _| Offset::from(coord).row < 2);
+    ///
+    /// assert_eq!(grid.iter().count(), 8);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(MultiCoord, &T) -> bool,
+    {
+        let hexes: Vec<(Cube, NodeIndex)> =
+            self.map.iter().map(|(&cube, &index)| (cube, index)).collect();
+
+        for (cube, index) in hexes {
+            let coord = self.multi_from(cube, self.sys);
+
+            if !f(coord, &self.graph[index]) {
+                self.remove_at(cube, index);
+            }
+        }
+    }
+
+    /// Sets every given coordinate to a copy of `value`, adding hexes as
+    /// needed. New hexes are linked to their neighbors once all have been
+    /// added, rather than one at a time. Changes are tracked and raise
+    /// events just like `set`, once for each hex no matter how many times
+    /// its coordinate is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_radial(1, '.');
+    /// let ring = Cube::<i32>::ORIGIN.ring(2);
+    ///
+    /// grid.fill_region(ring.into_iter().map(MultiCoord::from), '#');
+    ///
+    /// assert_eq!(grid.iter().filter(|&(_, &c)| c == '#').count(), 12);
+    /// ```
+    pub fn fill_region<I>(&mut self, coords: I, value: T)
+    where
+        I: IntoIterator<Item = MultiCoord>,
+        T: Clone,
+    {
+        let mut added = Vec::new();
+        let mut seen = HashSet::new();
+
+        for coord in coords {
+            let cube = self.cube_from(coord);
+
+            if !seen.insert(cube) {
+                continue;
+            }
+
+            match self.map.get(&cube) {
+                Some(&index) => {
+                    self.replace_at(coord, index, value.clone());
+                }
+                None => {
+                    let index = self.graph.add_node(value.clone());
+
+                    self.map.insert(cube, index);
+                    self.touch(coord);
+                    added.push((cube, index));
+                }
+            }
+        }
+
+        let new: HashSet<Cube> = added.iter().map(|&(cube, _)| cube).collect();
+
+        for &(cube, own_index) in added.iter() {
+            for (i, neighbor) in cube.neighbors().iter().enumerate() {
+                if let Some(&other_index) = self.map.get(neighbor) {
+                    let dir = Compass::from_neighbor(self.tilt, i);
+
+                    self.graph.add_edge(own_index, other_index, dir);
+
+                    // Links between two new hexes are added from both ends.
+                    if !new.contains(neighbor) {
+                        self.graph.add_edge(
+                            other_index,
+                            own_index,
+                            dir.inverse(),
+                        );
+                    }
+                }
+            }
+        }
+
+        if !self.listeners.is_empty() {
+            for &(cube, index) in added.iter() {
+                let event = GridEvent::CellAdded {
+                    coord: self.multi_from(cube, self.sys),
+                    value: &self.graph[index],
+                };
+
+                self.listeners.notify(&event);
+            }
+        }
+    }
+
+    /// Removes every hex, returning their coordinates and values in no
+    /// particular order. Removals are tracked and raise events just like
+    /// `remove`.
+    pub fn drain(&mut self) -> impl Iterator<Item = (MultiCoord, T)> {
+        let hexes: Vec<(Cube, NodeIndex)> =
+            self.map.iter().map(|(&cube, &index)| (cube, index)).collect();
+        let mut drained = Vec::with_capacity(hexes.len());
+
+        for (cube, index) in hexes {
+            let coord = self.multi_from(cube, self.sys);
+
+            drained.push((coord, self.remove_at(cube, index)));
+        }
+
+        self.graph.clear();
+        drained.into_iter()
+    }
+
     //////////////////////////////////
     // Traversal
     //////////////////////////////////
 
-    // map
-    // iters
+    /// Returns an iterator over the coordinates and values of the calling
+    /// instance, in no particular order. Coordinates are given in the grid's
+    /// `sys`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_radial(1, 5);
+    /// let total: i32 = grid.iter().map(|(_, value)| value).sum();
+    ///
+    /// assert_eq!(total, 35);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (MultiCoord, &T)> {
+        self.map.iter().map(move |(&cube, &index)| {
+            (self.multi_from(cube, self.sys), &self.graph[index])
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Traits: Index & IndexMut
+//////////////////////////////////////////////////////////////////////////////
+
+/// Returns the data at the given coordinate.
+///
+/// # Panics
+///
+/// Panics if the grid holds no value at the coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::prelude::*;
+///
+/// let mut grid = HexGrid::new_boxy(3, 3, 0);
+///
+/// grid[MultiCoord::offset(1, 2)] += 4;
+/// grid[Offset::from_coords(1, 2)] *= 2;
+///
+/// assert_eq!(grid[MultiCoord::offset(1, 2)], 8);
+/// ```
+impl<T> Index<MultiCoord> for HexGrid<T> {
+    type Output = T;
+
+    fn index(&self, coord: MultiCoord) -> &T {
+        match self.get(coord) {
+            Some(data) => data,
+            None => panic!("Grid contains no value at {}", coord),
+        }
+    }
 }
+
+/// Returns the data at the given coordinate, recording the hex as touched
+/// when tracking changes.
+///
+/// # Panics
+///
+/// Panics if the grid holds no value at the coordinate.
+impl<T> IndexMut<MultiCoord> for HexGrid<T> {
+    fn index_mut(&mut self, coord: MultiCoord) -> &mut T {
+        match self.graph_index(coord) {
+            Some(&index) => self.value_at_mut(coord, index),
+            None => panic!("Grid contains no value at {}", coord),
+        }
+    }
+}
+
+macro_rules! impl_index {
+    ($($coord:ident),*) => {$(
+        impl<T> Index<$coord> for HexGrid<T> {
+            type Output = T;
+
+            fn index(&self, coord: $coord) -> &T {
+                &self[MultiCoord::from(coord)]
+            }
+        }
+
+        impl<T> IndexMut<$coord> for HexGrid<T> {
+            fn index_mut(&mut self, coord: $coord) -> &mut T {
+                &mut self[MultiCoord::from(coord)]
+            }
+        }
+    )*};
+}
+
+impl_index!(Axial, Cube, Double, Offset);
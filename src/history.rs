@@ -0,0 +1,299 @@
+//! Edit History
+//!
+//! `HistoryHexGrid` wraps a `HexGrid`, recording each edit made through it
+//! so that edits can be undone and redone. Edits are grouped into
+//! transactions, which are undone and redone as a whole. Edits made outside
+//! of a transaction each form their own.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::history::HistoryHexGrid;
+//! use chickenwire::prelude::*;
+//!
+//! let mut grid = HistoryHexGrid::new(HexGrid::new_radial(2, 0), 100);
+//!
+//! grid.set(MultiCoord::axial(0, 0), 1);
+//!
+//! grid.begin();
+//! grid.set(MultiCoord::axial(1, 0), 2);
+//! grid.remove(MultiCoord::axial(0, 1));
+//! grid.commit();
+//!
+//! assert!(grid.undo());
+//! assert_eq!(grid.get(MultiCoord::axial(1, 0)), Some(&0));
+//! assert_eq!(grid.get(MultiCoord::axial(0, 1)), Some(&0));
+//! assert_eq!(grid.get(MultiCoord::axial(0, 0)), Some(&1));
+//!
+//! assert!(grid.redo());
+//! assert_eq!(grid.get(MultiCoord::axial(0, 1)), None);
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+/// A single edit, as a hex's value before and after. `None` means the hex
+/// was absent.
+type Edit<T> = (Cube, Option<T>, Option<T>);
+
+/// A `HexGrid` whose edits can be undone and redone.
+#[derive(Debug)]
+pub struct HistoryHexGrid<T> {
+    grid: HexGrid<T>,
+    /// Committed transactions, oldest first.
+    undo: VecDeque<Vec<Edit<T>>>,
+    /// Undone transactions, most recently undone last.
+    redo: Vec<Vec<Edit<T>>>,
+    /// Edits of the open transaction, in order.
+    open: Vec<Edit<T>>,
+    /// Number of unmatched calls to `begin`.
+    depth: usize,
+    limit: usize,
+}
+
+impl<T: Clone> HistoryHexGrid<T> {
+    //////////////////////////////////
+    // Initialization
+    //////////////////////////////////
+
+    /// Wraps a `HexGrid`, keeping at most `limit` transactions to undo.
+    pub fn new(grid: HexGrid<T>, limit: usize) -> Self {
+        Self {
+            grid,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            open: Vec::new(),
+            depth: 0,
+            limit,
+        }
+    }
+
+    /// Returns the wrapped grid.
+    pub fn grid(&self) -> &HexGrid<T> {
+        &self.grid
+    }
+
+    /// Returns the wrapped grid, discarding its history.
+    pub fn into_grid(self) -> HexGrid<T> {
+        self.grid
+    }
+
+    /// Returns the maximum number of transactions kept to undo.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Changes the maximum number of transactions kept to undo, discarding
+    /// the oldest ones beyond it.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        self.trim();
+    }
+
+    /// Drops the oldest transactions beyond the limit.
+    fn trim(&mut self) {
+        while self.undo.len() > self.limit {
+            self.undo.pop_front();
+        }
+    }
+
+    //////////////////////////////////
+    // Retrieval
+    //////////////////////////////////
+
+    /// Return an immutable reference to the data contained at the given
+    /// coordinate within the grid, if it exists.
+    pub fn get(&self, coord: MultiCoord) -> Option<&T> {
+        self.grid.get(coord)
+    }
+
+    //////////////////////////////////
+    // Extension & Modification
+    //////////////////////////////////
+
+    /// Records an edit, committing it on its own outside of a transaction.
+    fn record(&mut self, coord: MultiCoord, before: Option<T>) {
+        let cube = self.grid.cube_of(coord);
+        let after = self.grid.get(coord).cloned();
+
+        self.open.push((cube, before, after));
+
+        if self.depth == 0 {
+            self.finish();
+        }
+    }
+
+    /// Strictly add a hex value to the grid at a given coordinate. Returns a
+    /// `Result::Err(String)` if there is already a value at the given
+    /// coordinate. Otherwise, returns `Result::Ok(())`.
+    pub fn add(&mut self, coord: MultiCoord, data: T) -> Result<(), String> {
+        self.grid.add(coord, data)?;
+        self.record(coord, None);
+        Ok(())
+    }
+
+    /// Strictly update a pre-existing hex value at a given coordinate.
+    /// Returns a `Result::Err(String)` if the position is vacant. Otherwise,
+    /// returns `Result::Ok(())`.
+    pub fn update(
+        &mut self,
+        coord: MultiCoord,
+        data: T,
+    ) -> Result<(), String> {
+        let before = self.grid.get(coord).cloned();
+
+        self.grid.update(coord, data)?;
+        self.record(coord, before);
+        Ok(())
+    }
+
+    /// Either creates & adds or updates a hex value at the given coordinate.
+    pub fn set(&mut self, coord: MultiCoord, data: T) {
+        let before = self.grid.get(coord).cloned();
+
+        self.grid.set(coord, data);
+        self.record(coord, before);
+    }
+
+    /// Cleanly removes a hex from the grid. Associated data will be returned.
+    /// If the coordinate had no associated data, `None` will be returned and
+    /// nothing is recorded.
+    pub fn remove(&mut self, coord: MultiCoord) -> Option<T> {
+        let removed = self.grid.remove(coord)?;
+
+        self.record(coord, Some(removed.clone()));
+        Some(removed)
+    }
+
+    //////////////////////////////////
+    // Transactions
+    //////////////////////////////////
+
+    /// Opens a transaction, grouping every following edit until the matching
+    /// call to `commit`. Transactions may be nested, in which case the
+    /// outermost one groups the edits of all.
+    pub fn begin(&mut self) {
+        self.depth += 1;
+    }
+
+    /// Closes the transaction opened by the latest unmatched call to
+    /// `begin`. Does nothing if no transaction is open.
+    pub fn commit(&mut self) {
+        if self.depth > 0 {
+            self.depth -= 1;
+
+            if self.depth == 0 {
+                self.finish();
+            }
+        }
+    }
+
+    /// Returns `true` if a transaction is open, else `false`.
+    pub fn in_transaction(&self) -> bool {
+        self.depth > 0
+    }
+
+    /// Moves the open transaction's edits, if any, onto the undo history.
+    fn finish(&mut self) {
+        if !self.open.is_empty() {
+            let edits = std::mem::take(&mut self.open);
+
+            self.undo.push_back(edits);
+            self.redo.clear();
+            self.trim();
+        }
+    }
+
+    //////////////////////////////////
+    // Undo & Redo
+    //////////////////////////////////
+
+    /// Returns `true` if there is a transaction to undo, else `false`.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty() || !self.open.is_empty()
+    }
+
+    /// Returns `true` if there is a transaction to redo, else `false`.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Sets a hex to the given value, or removes it for `None`.
+    fn restore(&mut self, cube: Cube, value: &Option<T>) {
+        let coord = MultiCoord::from(cube);
+
+        match value {
+            Some(value) => self.grid.set(coord, value.clone()),
+            None => {
+                self.grid.remove(coord);
+            }
+        }
+    }
+
+    /// Reverts the most recent transaction. Any open transaction is
+    /// committed first. Returns `true` if a transaction was undone, else
+    /// `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::history::HistoryHexGrid;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HistoryHexGrid::new(HexGrid::new_radial(1, 'a'), 1);
+    ///
+    /// grid.set(MultiCoord::axial(0, 0), 'b');
+    /// grid.set(MultiCoord::axial(0, 0), 'c');
+    ///
+    /// assert!(grid.undo());
+    /// assert!(!grid.undo());
+    /// assert_eq!(grid.get(MultiCoord::axial(0, 0)), Some(&'b'));
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        self.depth = 0;
+        self.finish();
+
+        match self.undo.pop_back() {
+            Some(edits) => {
+                for (cube, before, _) in edits.iter().rev() {
+                    self.restore(*cube, before);
+                }
+
+                self.redo.push(edits);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the most recently undone transaction. Any open transaction
+    /// is committed first, which discards the transactions left to redo.
+    /// Returns `true` if a transaction was redone, else `false`.
+    pub fn redo(&mut self) -> bool {
+        self.depth = 0;
+        self.finish();
+
+        match self.redo.pop() {
+            Some(edits) => {
+                for (cube, _, after) in edits.iter() {
+                    self.restore(*cube, after);
+                }
+
+                self.undo.push_back(edits);
+                self.trim();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Discards every transaction to undo and redo.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.open.clear();
+        self.depth = 0;
+    }
+}
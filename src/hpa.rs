@@ -0,0 +1,592 @@
+//! Hierarchical Pathfinding
+//!
+//! `HpaHexGrid` wraps a `HexGrid` for hierarchical pathfinding (HPA*),
+//! answering path queries on large grids far faster than a plain A* search.
+//! The grid is divided into square clusters of `Offset` coordinates, and
+//! entrances are placed along each run of passable hexes where neighboring
+//! clusters meet. The cheapest paths between entrances of the same cluster
+//! are found up front, so a query only searches the few entrances between
+//! its ends, then stitches the stored paths together.
+//!
+//! Paths found this way are nearly, but not always, the cheapest. Queries
+//! between hexes no more than a cluster apart are first searched directly
+//! near their ends, so short paths come out exact. Edits made through
+//! `HpaHexGrid::set` and `HpaHexGrid::remove` only rebuild the clusters
+//! around the edited hex.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::hpa::HpaHexGrid;
+//! use chickenwire::prelude::*;
+//!
+//! let wall = |_, &hex: &char| if hex == '#' { None } else { Some(1) };
+//! let mut grid = HpaHexGrid::new(HexGrid::new_boxy(40, 40, '.'), 8, wall);
+//!
+//! let (start, goal) = (MultiCoord::offset(2, 3), MultiCoord::offset(37, 30));
+//! let path = grid.find_path(start, goal).unwrap();
+//!
+//! assert_eq!(path.coords[0], start);
+//! assert_eq!(path.coords[path.coords.len() - 1], goal);
+//!
+//! // Walling in the goal only rebuilds the clusters around it.
+//! let ring = grid.grid().cube_of(goal).ring(1);
+//!
+//! for cube in ring {
+//!     grid.set(MultiCoord::from(cube), '#');
+//! }
+//!
+//! assert_eq!(grid.find_path(start, goal), None);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+use crate::pathfind::{astar, Path};
+
+/// Identifies a cluster by its column and row of clusters.
+type Key = (i32, i32);
+
+/// A cost function for entering a hex.
+type CostFn<T> = Box<dyn Fn(MultiCoord, &T) -> Option<u32>>;
+
+/// A path of hexes and its cost.
+type Found = (Vec<Cube>, u64);
+
+/// A stored path from one hex to another, excluding the first.
+#[derive(Clone, Debug)]
+struct Edge {
+    to: Cube,
+    cost: u64,
+    path: Vec<Cube>,
+}
+
+/// A `HexGrid` with a cluster hierarchy for fast pathfinding.
+pub struct HpaHexGrid<T> {
+    grid: HexGrid<T>,
+    cluster_size: i32,
+    cost: CostFn<T>,
+    /// Hexes of each cluster.
+    members: HashMap<Key, HashSet<Cube>>,
+    /// Entrances of each cluster, sorted.
+    nodes: HashMap<Key, Vec<Cube>>,
+    /// Paths from each entrance to the others of its cluster.
+    edges: HashMap<Cube, Vec<Edge>>,
+}
+
+impl<T> fmt::Debug for HpaHexGrid<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HpaHexGrid")
+            .field("grid", &self.grid)
+            .field("cluster_size", &self.cluster_size)
+            .field("clusters", &self.members.len())
+            .field("entrances", &self.edges.len())
+            .finish()
+    }
+}
+
+impl<T> HpaHexGrid<T> {
+    //////////////////////////////////
+    // Initialization
+    //////////////////////////////////
+
+    /// Wraps a `HexGrid`, dividing it into clusters `cluster_size` hexes
+    /// across. As in `HexGrid::find_path`, `cost` gives the cost of entering
+    /// a hex, or `None` if it can't be entered. Costs below 1 are raised to
+    /// 1, and a `cluster_size` below 1 is raised to 1.
+    pub fn new<F>(grid: HexGrid<T>, cluster_size: u32, cost: F) -> Self
+    where
+        F: Fn(MultiCoord, &T) -> Option<u32> + 'static,
+    {
+        let mut hpa = Self {
+            grid,
+            cluster_size: cluster_size.clamp(1, i32::MAX as u32) as i32,
+            cost: Box::new(cost),
+            members: HashMap::new(),
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+        };
+        let cubes: Vec<Cube> = hpa
+            .grid
+            .iter()
+            .map(|(coord, _)| hpa.grid.cube_of(coord))
+            .collect();
+
+        for cube in cubes {
+            let key = hpa.key(cube);
+
+            hpa.members.entry(key).or_default().insert(cube);
+        }
+
+        let mut keys: Vec<Key> = hpa.members.keys().copied().collect();
+
+        keys.sort();
+
+        for key in keys {
+            hpa.rebuild(key);
+        }
+
+        hpa
+    }
+
+    /// Returns the wrapped grid.
+    pub fn grid(&self) -> &HexGrid<T> {
+        &self.grid
+    }
+
+    /// Returns the wrapped grid, discarding its clusters.
+    pub fn into_grid(self) -> HexGrid<T> {
+        self.grid
+    }
+
+    /// Returns the number of hexes across each cluster.
+    pub fn cluster_size(&self) -> u32 {
+        self.cluster_size as u32
+    }
+
+    /// Returns the number of clusters holding any hexes.
+    pub fn cluster_count(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns the number of entrances across all clusters.
+    pub fn entrance_count(&self) -> usize {
+        self.nodes.values().map(Vec::len).sum()
+    }
+
+    //////////////////////////////////
+    // Retrieval
+    //////////////////////////////////
+
+    /// Return an immutable reference to the data contained at the given
+    /// coordinate within the grid, if it exists.
+    pub fn get(&self, coord: MultiCoord) -> Option<&T> {
+        self.grid.get(coord)
+    }
+
+    //////////////////////////////////
+    // Extension & Modification
+    //////////////////////////////////
+
+    /// Sets the value of a hex, adding it if needed, then rebuilds the
+    /// clusters around it.
+    pub fn set(&mut self, coord: MultiCoord, data: T) {
+        let cube = self.grid.cube_of(coord);
+        let key = self.key(cube);
+
+        self.grid.set(coord, data);
+        self.members.entry(key).or_default().insert(cube);
+        self.repair(key);
+    }
+
+    /// Removes a hex, returning its value if it was present, then rebuilds
+    /// the clusters around it.
+    pub fn remove(&mut self, coord: MultiCoord) -> Option<T> {
+        let cube = self.grid.cube_of(coord);
+        let key = self.key(cube);
+        let value = self.grid.remove(coord)?;
+
+        if let Some(members) = self.members.get_mut(&key) {
+            members.remove(&cube);
+
+            if members.is_empty() {
+                self.members.remove(&key);
+            }
+        }
+
+        self.repair(key);
+        Some(value)
+    }
+
+    //////////////////////////////////
+    // Pathfinding
+    //////////////////////////////////
+
+    /// Returns a path from `start` to `goal`, or `None` if either isn't a
+    /// hex of the grid or `goal` can't be reached.
+    pub fn find_path(
+        &self,
+        start: MultiCoord,
+        goal: MultiCoord,
+    ) -> Option<Path> {
+        if !self.grid.contains_coord(start) || !self.grid.contains_coord(goal)
+        {
+            return None;
+        }
+
+        let start = self.grid.cube_of(start);
+        let goal = self.grid.cube_of(goal);
+        let (from, to) = (self.key(start), self.key(goal));
+        let near = self.near(start, goal);
+
+        // Every step costs at least 1, so a path within the bound can't
+        // leave the area searched, and no cheaper path exists.
+        if let Some((cubes, cost)) = near.clone() {
+            if cost <= self.bound(start, goal) {
+                return Some(self.grid.path_from(cubes, cost));
+            }
+        }
+
+        if from == to {
+            if let Some(edge) = self.local(from, start, goal) {
+                let mut cubes = vec![start];
+
+                cubes.extend(edge.path);
+
+                let (cubes, cost) = cheaper(near, (cubes, edge.cost));
+
+                return Some(self.grid.path_from(cubes, cost));
+            }
+        }
+
+        let no_nodes = Vec::new();
+        let starts: Vec<Edge> = self
+            .nodes
+            .get(&from)
+            .unwrap_or(&no_nodes)
+            .iter()
+            .filter_map(|&node| self.local(from, start, node))
+            .collect();
+        let goals: HashMap<Cube, Edge> = self
+            .nodes
+            .get(&to)
+            .unwrap_or(&no_nodes)
+            .iter()
+            .filter_map(|&node| Some((node, self.local(to, node, goal)?)))
+            .collect();
+
+        // Entrances can miss a path, such as one leaving a wall it starts on,
+        // so a failed search falls back to searching the whole grid.
+        let (cubes, cost) = match self.search(start, goal, &starts, &goals) {
+            Some(found) => cheaper(near, found),
+            None => {
+                astar(start, goal, |cube| self.step_cost(cube), |_| false)?
+            }
+        };
+
+        Some(self.grid.path_from(cubes, cost))
+    }
+
+    /// Returns the total distance from `start` and `goal` that hexes
+    /// searched by `near` may lie within.
+    fn bound(&self, start: Cube, goal: Cube) -> u64 {
+        u64::from(start.dist(goal).unsigned_abs())
+            + 2 * self.cluster_size as u64
+    }
+
+    /// Searches directly from `start` to `goal` if they're no more than a
+    /// cluster apart, only entering hexes within `bound` of both.
+    fn near(&self, start: Cube, goal: Cube) -> Option<Found> {
+        if start.dist(goal).unsigned_abs() > self.cluster_size as u32 {
+            return None;
+        }
+
+        let bound = self.bound(start, goal);
+
+        astar(
+            start,
+            goal,
+            |cube| {
+                let reach = cube.dist(start).unsigned_abs()
+                    + cube.dist(goal).unsigned_abs();
+
+                if u64::from(reach) <= bound {
+                    self.step_cost(cube)
+                } else {
+                    None
+                }
+            },
+            |_| false,
+        )
+    }
+
+    /// Searches the abstract graph of entrances from `start` to `goal`,
+    /// returning the refined path and its cost.
+    fn search(
+        &self,
+        start: Cube,
+        goal: Cube,
+        starts: &[Edge],
+        goals: &HashMap<Cube, Edge>,
+    ) -> Option<Found> {
+        let estimate = |cube: Cube| u64::from(cube.dist(goal).unsigned_abs());
+        let mut best: HashMap<Cube, u64> = HashMap::new();
+        let mut came_from: HashMap<Cube, (Cube, Vec<Cube>)> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+
+        best.insert(start, 0);
+        frontier.push(Reverse((estimate(start), 0, start)));
+
+        while let Some(Reverse((_, spent, cube))) = frontier.pop() {
+            if cube == goal {
+                let mut segments = Vec::new();
+                let mut at = goal;
+
+                while at != start {
+                    let (prev, path) = &came_from[&at];
+
+                    segments.push(path);
+                    at = *prev;
+                }
+
+                let mut path = vec![start];
+
+                path.extend(segments.into_iter().rev().flatten());
+                return Some((path, spent));
+            }
+
+            if best.get(&cube).is_some_and(|&known| spent > known) {
+                continue;
+            }
+
+            let mut edges: Vec<Edge> = Vec::new();
+
+            if cube == start {
+                edges.extend(starts.iter().cloned());
+            }
+
+            if let Some(intra) = self.edges.get(&cube) {
+                edges.extend(intra.iter().cloned());
+                edges.extend(self.crossings(cube));
+            }
+
+            if let Some(edge) = goals.get(&cube) {
+                edges.push(edge.clone());
+            }
+
+            for edge in edges {
+                let total = spent + edge.cost;
+
+                if best.get(&edge.to).map_or(true, |&known| total < known) {
+                    best.insert(edge.to, total);
+                    came_from.insert(edge.to, (cube, edge.path));
+                    frontier.push(Reverse((
+                        total + estimate(edge.to),
+                        total,
+                        edge.to,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns steps from an entrance to neighboring entrances of other
+    /// clusters.
+    fn crossings(&self, cube: Cube) -> Vec<Edge> {
+        let key = self.key(cube);
+
+        cube.neighbors()
+            .into_iter()
+            .filter(|&next| {
+                let other = self.key(next);
+
+                other != key
+                    && self.nodes.get(&other).is_some_and(|nodes| {
+                        nodes.binary_search(&next).is_ok()
+                    })
+            })
+            .filter_map(|next| {
+                Some(Edge {
+                    to: next,
+                    cost: u64::from(self.step_cost(next)?),
+                    path: vec![next],
+                })
+            })
+            .collect()
+    }
+
+    //////////////////////////////////
+    // Clusters
+    //////////////////////////////////
+
+    /// Returns the key of the cluster holding a hex.
+    fn key(&self, cube: Cube) -> Key {
+        let offset = Offset::from(
+            self.grid.convert(MultiCoord::from(cube), CoordSys::Offset),
+        );
+
+        (
+            offset.col.div_euclid(self.cluster_size),
+            offset.row.div_euclid(self.cluster_size),
+        )
+    }
+
+    /// Returns the cost of entering a hex, if it can be entered.
+    fn step_cost(&self, cube: Cube) -> Option<u32> {
+        let coord = self.grid.coord_of(cube);
+
+        self.grid
+            .get(coord)
+            .and_then(|value| (self.cost)(coord, value))
+            .map(|cost| cost.max(1))
+    }
+
+    /// Returns the cheapest path between two hexes without leaving their
+    /// cluster.
+    fn local(&self, key: Key, from: Cube, to: Cube) -> Option<Edge> {
+        if from == to {
+            return Some(Edge {
+                to,
+                cost: 0,
+                path: Vec::new(),
+            });
+        }
+
+        let (mut path, cost) = astar(
+            from,
+            to,
+            |cube| {
+                if self.key(cube) == key {
+                    self.step_cost(cube)
+                } else {
+                    None
+                }
+            },
+            |_| false,
+        )?;
+
+        path.remove(0);
+        Some(Edge { to, cost, path })
+    }
+
+    /// Returns the entrances on the border of cluster `key` with cluster
+    /// `other`, as pairs of a hex of `key` and its neighbor in `other`. Each
+    /// pair of passable neighbors across the border belongs to a run of
+    /// pairs connected on both sides, and one pair is placed in the middle
+    /// of each run, so every crossing can be reached from an entrance
+    /// without leaving either cluster.
+    fn entrances(&self, key: Key, other: Key) -> Vec<(Cube, Cube)> {
+        let members = match self.members.get(&key) {
+            Some(members) => members,
+            None => return Vec::new(),
+        };
+        let mut crossings: Vec<(Cube, Cube)> = members
+            .iter()
+            .copied()
+            .filter(|&near| self.step_cost(near).is_some())
+            .flat_map(|near| {
+                near.neighbors()
+                    .into_iter()
+                    .filter(|&far| {
+                        self.key(far) == other && self.step_cost(far).is_some()
+                    })
+                    .map(move |far| (near, far))
+            })
+            .collect();
+
+        crossings.sort();
+
+        let touching = |a: Cube, b: Cube| a.dist(b) <= 1;
+        let mut unseen: HashSet<(Cube, Cube)> =
+            crossings.iter().copied().collect();
+        let mut pairs = Vec::new();
+
+        for &first in crossings.iter() {
+            if !unseen.remove(&first) {
+                continue;
+            }
+
+            let mut run = vec![first];
+            let mut stack = vec![first];
+
+            while let Some((near, far)) = stack.pop() {
+                let linked: Vec<(Cube, Cube)> = unseen
+                    .iter()
+                    .copied()
+                    .filter(|&(next_near, next_far)| {
+                        touching(near, next_near) && touching(far, next_far)
+                    })
+                    .collect();
+
+                for next in linked {
+                    unseen.remove(&next);
+                    run.push(next);
+                    stack.push(next);
+                }
+            }
+
+            run.sort();
+            pairs.push(run[run.len() / 2]);
+        }
+
+        pairs
+    }
+
+    /// Returns the keys of the clusters that may border a cluster.
+    fn around(key: Key) -> impl Iterator<Item = Key> {
+        (-1..=1).flat_map(move |dx| {
+            (-1..=1)
+                .map(move |dy| (key.0 + dx, key.1 + dy))
+                .filter(move |&other| other != key)
+        })
+    }
+
+    /// Rebuilds the entrances of a cluster and the paths between them.
+    fn rebuild(&mut self, key: Key) {
+        for node in self.nodes.remove(&key).unwrap_or_default() {
+            self.edges.remove(&node);
+        }
+
+        if !self.members.contains_key(&key) {
+            return;
+        }
+
+        let mut nodes: Vec<Cube> = Vec::new();
+
+        for other in Self::around(key) {
+            // Entrances are placed from the lower cluster's side, so both
+            // clusters agree on them.
+            let (low, high) = if key < other {
+                (key, other)
+            } else {
+                (other, key)
+            };
+
+            for (near, far) in self.entrances(low, high) {
+                nodes.push(if low == key { near } else { far });
+            }
+        }
+
+        nodes.sort();
+        nodes.dedup();
+
+        for &node in nodes.iter() {
+            let edges = nodes
+                .iter()
+                .filter(|&&other| other != node)
+                .filter_map(|&other| self.local(key, node, other))
+                .collect();
+
+            self.edges.insert(node, edges);
+        }
+
+        self.nodes.insert(key, nodes);
+    }
+
+    /// Rebuilds a cluster and those around it.
+    fn repair(&mut self, key: Key) {
+        self.rebuild(key);
+
+        for other in Self::around(key) {
+            if self.members.contains_key(&other) {
+                self.rebuild(other);
+            }
+        }
+    }
+}
+
+/// Returns `found`, or `near` if it's cheaper.
+fn cheaper(near: Option<Found>, found: Found) -> Found {
+    match near {
+        Some(near) if near.1 < found.1 => near,
+        _ => found,
+    }
+}
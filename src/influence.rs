@@ -0,0 +1,387 @@
+//! Influence Maps
+//!
+//! An `InfluenceMap` spreads the strength of sources, such as units or
+//! towers, across the hexes of a grid, fading with distance. Influence
+//! spreads between neighboring hexes, and each hex resists it by a distance
+//! of its own, so it flows around walls and slows in rough terrain.
+//!
+//! Maps are built with `HexGrid::influence_map`. `InfluenceMap::propagate`
+//! spreads the current sources at once, while `InfluenceMap::tick` blends
+//! them into the previous values by `InfluenceMap::momentum`, so influence
+//! lingers where sources have left. Maps of two sides can be compared with
+//! `InfluenceMap::balance`, `tension`, and `vulnerability`.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::influence::Falloff;
+//! use chickenwire::prelude::*;
+//!
+//! let grid = HexGrid::new_boxy(9, 1, ());
+//! let resistance = |_, _: &()| Some(1.0);
+//! let mut ours = grid.influence_map(Falloff::Linear(4.0), resistance);
+//! let mut theirs = grid.influence_map(Falloff::Linear(4.0), resistance);
+//!
+//! ours.set_source(MultiCoord::offset(0, 0), 8.0);
+//! theirs.set_source(MultiCoord::offset(8, 0), 4.0);
+//! ours.propagate();
+//! theirs.propagate();
+//!
+//! assert_eq!(ours.influence(MultiCoord::offset(2, 0)), Some(4.0));
+//! assert_eq!(ours.influence(MultiCoord::offset(4, 0)), Some(0.0));
+//!
+//! let balance = ours.balance(&theirs);
+//!
+//! assert_eq!(balance[MultiCoord::offset(1, 0)], 6.0);
+//! assert_eq!(balance[MultiCoord::offset(7, 0)], -3.0);
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Falloff
+//////////////////////////////////////////////////////////////////////////////
+
+/// How influence fades with distance from its source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Falloff {
+    /// Fades evenly, reaching nothing at the given distance.
+    Linear(f64),
+    /// Multiplied by the given factor, between 0 and 1, for each unit of
+    /// distance.
+    Exponential(f64),
+}
+
+impl Falloff {
+    /// Returns the share of a source's strength left at a distance.
+    pub fn factor(self, dist: f64) -> f64 {
+        match self {
+            Falloff::Linear(range) if range > 0.0 => {
+                (1.0 - dist / range).max(0.0)
+            }
+            Falloff::Linear(_) => {
+                if dist > 0.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            Falloff::Exponential(decay) => decay.clamp(0.0, 1.0).powf(dist),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Influence Map
+//////////////////////////////////////////////////////////////////////////////
+
+/// The influence of a set of sources over each hex of a grid.
+#[derive(Debug)]
+pub struct InfluenceMap {
+    /// An empty grid with the source grid's layout, for converting
+    /// coordinates.
+    frame: HexGrid<()>,
+    /// Resistance of each hex, or `None` if influence can't enter it.
+    resistance: HashMap<Cube, Option<f64>>,
+    falloff: Falloff,
+    /// Share of the previous influence kept by each `tick`, between 0 and 1.
+    pub momentum: f64,
+    /// Influence weaker than this, in either direction, stops spreading,
+    /// and lingering influence weaker than this fades away.
+    pub threshold: f64,
+    /// Strength of each source.
+    sources: HashMap<Cube, f64>,
+    values: HashMap<Cube, f64>,
+}
+
+impl<T> HexGrid<T> {
+    /// Returns an influence map with no sources over the hexes of the grid,
+    /// with a `momentum` of 0.5 and a `threshold` of 0.01. `resistance`
+    /// gives the distance influence travels to enter a hex, or `None` if it
+    /// can't enter. Resistances below 0 are raised to 0.
+    pub fn influence_map<F>(
+        &self,
+        falloff: Falloff,
+        mut resistance: F,
+    ) -> InfluenceMap
+    where
+        F: FnMut(MultiCoord, &T) -> Option<f64>,
+    {
+        let resistance = self
+            .iter()
+            .map(|(coord, value)| {
+                let cube = self.cube_of(coord);
+
+                (cube, resistance(coord, value).map(|r| r.max(0.0)))
+            })
+            .collect();
+
+        InfluenceMap {
+            frame: HexGrid::new(self.tilt, self.parity, self.sys),
+            resistance,
+            falloff,
+            momentum: 0.5,
+            threshold: 0.01,
+            sources: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl InfluenceMap {
+    /// Returns the current influence over a hex, or `None` if it isn't a
+    /// hex of the grid.
+    pub fn influence(&self, coord: MultiCoord) -> Option<f64> {
+        let cube = self.frame.cube_of(coord);
+
+        if self.resistance.contains_key(&cube) {
+            Some(self.value(cube))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a grid of the current influence over every hex.
+    pub fn values(&self) -> HexGrid<f64> {
+        self.combine(|&cube| self.value(cube))
+    }
+
+    /// Returns the falloff of the map.
+    pub fn falloff(&self) -> Falloff {
+        self.falloff
+    }
+
+    /// Places a source of the given strength on a hex, replacing any source
+    /// already there, or removes it if `strength` is 0. Negative strengths
+    /// spread negative influence. Takes effect on the next `propagate` or
+    /// `tick`. Sources that aren't hexes of the grid are skipped.
+    pub fn set_source(&mut self, coord: MultiCoord, strength: f64) {
+        let cube = self.frame.cube_of(coord);
+
+        if strength == 0.0 {
+            self.sources.remove(&cube);
+        } else if self.resistance.contains_key(&cube) {
+            self.sources.insert(cube, strength);
+        }
+    }
+
+    /// Returns the strength of the source on a hex.
+    pub fn source(&self, coord: MultiCoord) -> Option<f64> {
+        self.sources.get(&self.frame.cube_of(coord)).copied()
+    }
+
+    /// Removes every source. Takes effect on the next `propagate` or `tick`.
+    pub fn clear_sources(&mut self) {
+        self.sources.clear();
+    }
+
+    /// Changes the resistance of a hex, or keeps influence out of it if
+    /// `resistance` is `None`. Takes effect on the next `propagate` or
+    /// `tick`. Hexes that aren't hexes of the grid are skipped.
+    pub fn set_resistance(
+        &mut self,
+        coord: MultiCoord,
+        resistance: Option<f64>,
+    ) {
+        let cube = self.frame.cube_of(coord);
+
+        if let Some(old) = self.resistance.get_mut(&cube) {
+            *old = resistance.map(|r| r.max(0.0));
+        }
+    }
+
+    /// Replaces the influence over every hex with that of the current
+    /// sources, ignoring `momentum`.
+    pub fn propagate(&mut self) {
+        self.values = self.spread();
+    }
+
+    /// Blends the influence of the current sources into the previous
+    /// influence, keeping `momentum` of the previous influence over each
+    /// hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::influence::Falloff;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_radial(2, ());
+    /// let origin = MultiCoord::axial(0, 0);
+    /// let mut map = grid.influence_map(Falloff::Exponential(0.5), |_, _| {
+    ///     Some(1.0)
+    /// });
+    ///
+    /// map.set_source(origin, 1.0);
+    /// map.tick();
+    /// assert_eq!(map.influence(origin), Some(0.5));
+    ///
+    /// // The source leaves, but its influence lingers.
+    /// map.clear_sources();
+    /// map.tick();
+    /// assert_eq!(map.influence(origin), Some(0.25));
+    /// ```
+    pub fn tick(&mut self) {
+        let momentum = self.momentum.clamp(0.0, 1.0);
+        let mut fresh = self.spread();
+
+        for value in fresh.values_mut() {
+            *value *= 1.0 - momentum;
+        }
+
+        for (&cube, &old) in self.values.iter() {
+            *fresh.entry(cube).or_insert(0.0) += old * momentum;
+        }
+
+        fresh.retain(|_, value| value.abs() > self.threshold);
+        self.values = fresh;
+    }
+
+    /// Returns a grid of this map's influence less `other`'s over each hex,
+    /// which is positive where this side holds sway, and negative where the
+    /// other does.
+    pub fn balance(&self, other: &InfluenceMap) -> HexGrid<f64> {
+        self.combine(|&cube| self.value(cube) - other.value(cube))
+    }
+
+    /// Returns a grid of the sum of both maps' influence over each hex,
+    /// which is high where both sides are strong, such as a front line.
+    pub fn tension(&self, other: &InfluenceMap) -> HexGrid<f64> {
+        self.combine(|&cube| self.value(cube) + other.value(cube))
+    }
+
+    /// Returns a grid of `tension` less the magnitude of `balance` over each
+    /// hex, which is high where both sides are strong but neither holds
+    /// sway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::influence::Falloff;
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_boxy(5, 1, ());
+    /// let mut maps = [0, 4].iter().map(|&col| {
+    ///     let mut map = grid.influence_map(Falloff::Linear(5.0), |_, _| {
+    ///         Some(1.0)
+    ///     });
+    ///
+    ///     map.set_source(MultiCoord::offset(col, 0), 5.0);
+    ///     map.propagate();
+    ///     map
+    /// });
+    /// let (ours, theirs) = (maps.next().unwrap(), maps.next().unwrap());
+    /// let contested = ours.vulnerability(&theirs);
+    ///
+    /// assert_eq!(contested[MultiCoord::offset(2, 0)], 6.0);
+    /// assert_eq!(contested[MultiCoord::offset(0, 0)], 2.0);
+    /// ```
+    pub fn vulnerability(&self, other: &InfluenceMap) -> HexGrid<f64> {
+        self.combine(|&cube| {
+            let (ours, theirs) = (self.value(cube), other.value(cube));
+
+            (ours + theirs) - (ours - theirs).abs()
+        })
+    }
+
+    /// Returns the influence of the current sources over each hex they
+    /// reach, summed across sources.
+    fn spread(&self) -> HashMap<Cube, f64> {
+        let mut values = HashMap::new();
+
+        for (&source, &strength) in self.sources.iter() {
+            for (cube, dist) in self.reach(source, strength) {
+                *values.entry(cube).or_insert(0.0) +=
+                    strength * self.falloff.factor(dist);
+            }
+        }
+
+        values
+    }
+
+    /// Returns the distance to each hex that a source of the given strength
+    /// reaches with more than `threshold` influence.
+    fn reach(&self, source: Cube, strength: f64) -> HashMap<Cube, f64> {
+        let mut dists = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+        let strong = |dist: f64| {
+            (strength * self.falloff.factor(dist)).abs() > self.threshold
+        };
+
+        if strong(0.0) {
+            dists.insert(source, 0.0);
+            frontier.push(Step(0.0, source));
+        }
+
+        while let Some(Step(dist, cube)) = frontier.pop() {
+            if dists.get(&cube).is_some_and(|&known| dist > known) {
+                continue;
+            }
+
+            for next in cube.neighbors() {
+                let total = match self.resistance.get(&next) {
+                    Some(&Some(resistance)) => dist + resistance,
+                    _ => continue,
+                };
+
+                if strong(total)
+                    && dists.get(&next).map_or(true, |&known| total < known)
+                {
+                    dists.insert(next, total);
+                    frontier.push(Step(total, next));
+                }
+            }
+        }
+
+        dists
+    }
+
+    /// Returns a grid of the given function of each hex.
+    fn combine<F>(&self, f: F) -> HexGrid<f64>
+    where
+        F: Fn(&Cube) -> f64,
+    {
+        let mut grid =
+            HexGrid::new(self.frame.tilt, self.frame.parity, self.frame.sys);
+        let mut cubes: Vec<&Cube> = self.resistance.keys().collect();
+
+        cubes.sort();
+
+        for cube in cubes {
+            grid.set(MultiCoord::from(*cube), f(cube));
+        }
+
+        grid
+    }
+
+    fn value(&self, cube: Cube) -> f64 {
+        self.values.get(&cube).copied().unwrap_or(0.0)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Search
+//////////////////////////////////////////////////////////////////////////////
+
+/// A hex reached at a distance, ordered so the nearest is popped first from
+/// a `BinaryHeap`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Step(f64, Cube);
+
+impl Eq for Step {}
+
+impl Ord for Step {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+impl PartialOrd for Step {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
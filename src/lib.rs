@@ -3,6 +3,30 @@
 #![crate_name = "chickenwire"]
 #![crate_type = "lib"]
 
+pub mod ascii;
+pub mod automata;
+pub mod binary;
+pub mod cooperative;
 pub mod coordinate;
+pub mod diff;
+pub mod entry;
+pub mod events;
+pub mod export;
+pub mod flow;
+pub mod generate;
+pub mod geometry;
 pub mod hexgrid;
+pub mod history;
+pub mod hpa;
+pub mod influence;
+pub mod movement;
+pub mod pathfind;
 pub mod prelude;
+pub mod raster;
+pub mod spatial;
+pub mod svg;
+pub mod tiled;
+pub mod voronoi;
+pub mod wfc;
+
+mod text;
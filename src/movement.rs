@@ -0,0 +1,198 @@
+//! Movement and Threat
+//!
+//! Movement ranges and threat ranges for hex wargames, where a unit spends
+//! points of movement to enter each hex, and entering a hex in an enemy's
+//! zone of control ends its move. Costs follow `HexGrid::find_path`: a cost
+//! function gives the cost of entering a hex, or `None` if it can't be
+//! entered, and costs below 1 are raised to 1.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::movement::Reach;
+//! use chickenwire::prelude::*;
+//!
+//! let grid = HexGrid::new_boxy(10, 1, ());
+//! let enemy = MultiCoord::offset(6, 0);
+//! let zoc = |coord: MultiCoord| coord == MultiCoord::offset(5, 0);
+//!
+//! // The enemy's zone of control stops the unit short of its full move.
+//! let start = MultiCoord::offset(0, 0);
+//! let range = grid.movement_range(start, 8, |_, _| Some(1), zoc);
+//!
+//! assert_eq!(range.iter().count(), 6);
+//! assert!(range.get(MultiCoord::offset(6, 0)).is_none());
+//!
+//! let units = [(start, Reach::new(8, 1))];
+//! let threats = grid.threat_map(&units, |_, _| Some(1), |_, c| zoc(c));
+//!
+//! assert_eq!(threats.get(enemy), Some(&vec![0]));
+//! assert!(threats.get(MultiCoord::offset(7, 0)).is_none());
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Reach
+//////////////////////////////////////////////////////////////////////////////
+
+/// How far a unit can move and attack.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Reach {
+    /// Points of movement the unit can spend.
+    pub movement: u32,
+    /// Distance in hexes the unit can attack from where it ends its move.
+    pub attack: u32,
+}
+
+impl Reach {
+    /// Creates a reach of the given movement and attack range.
+    pub fn new(movement: u32, attack: u32) -> Self {
+        Self { movement, attack }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Ranges
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> HexGrid<T> {
+    /// Returns a grid of the hexes a unit at `start` can move to by spending
+    /// at most `movement`, each holding the least it costs to get there.
+    /// `cost` gives the cost of entering a hex, or `None` if it can't be
+    /// entered. `zoc` returns `true` for hexes in an enemy's zone of
+    /// control, which end the move once entered. A unit may leave a zone of
+    /// control it starts in. Returns an empty grid if `start` isn't a hex of
+    /// the grid.
+    pub fn movement_range<F, Z>(
+        &self,
+        start: MultiCoord,
+        movement: u32,
+        mut cost: F,
+        mut zoc: Z,
+    ) -> HexGrid<u32>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+        Z: FnMut(MultiCoord) -> bool,
+    {
+        let mut grid = HexGrid::new(self.tilt, self.parity, self.sys);
+        let spent = self.spend(start, movement, &mut cost, &mut zoc);
+        let mut hexes: Vec<(Cube, u32)> = spent.into_iter().collect();
+
+        hexes.sort();
+
+        for (cube, spent) in hexes {
+            grid.set(MultiCoord::from(cube), spent);
+        }
+
+        grid
+    }
+
+    /// Returns a grid of the hexes at least one unit could attack after
+    /// moving, each holding the indices of those units in ascending order.
+    /// Units are given as a hex and a `Reach`, and move as in
+    /// `movement_range`, then can attack every hex of the grid within their
+    /// attack range of where they stop. `zoc` is given a unit's index and a
+    /// hex, and returns `true` if the hex is in the zone of control of one
+    /// of that unit's enemies. Units that aren't on hexes of the grid
+    /// threaten nothing.
+    pub fn threat_map<F, Z>(
+        &self,
+        units: &[(MultiCoord, Reach)],
+        mut cost: F,
+        mut zoc: Z,
+    ) -> HexGrid<Vec<usize>>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+        Z: FnMut(usize, MultiCoord) -> bool,
+    {
+        let mut threats: HashMap<Cube, Vec<usize>> = HashMap::new();
+
+        for (index, &(start, reach)) in units.iter().enumerate() {
+            let mut zoc = |coord| zoc(index, coord);
+            let stops = self.spend(start, reach.movement, &mut cost, &mut zoc);
+            let mut targets = HashSet::new();
+
+            for &stop in stops.keys() {
+                for target in stop.spiral(reach.attack) {
+                    let coord = MultiCoord::from(target);
+
+                    if self.contains_coord(coord) {
+                        targets.insert(target);
+                    }
+                }
+            }
+
+            for target in targets {
+                threats.entry(target).or_default().push(index);
+            }
+        }
+
+        let mut grid = HexGrid::new(self.tilt, self.parity, self.sys);
+        let mut hexes: Vec<(Cube, Vec<usize>)> = threats.into_iter().collect();
+
+        hexes.sort();
+
+        for (cube, units) in hexes {
+            grid.set(MultiCoord::from(cube), units);
+        }
+
+        grid
+    }
+
+    /// Returns the least it costs to reach each hex a unit at `start` can
+    /// move to by spending at most `movement`.
+    fn spend<F, Z>(
+        &self,
+        start: MultiCoord,
+        movement: u32,
+        cost: &mut F,
+        zoc: &mut Z,
+    ) -> HashMap<Cube, u32>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+        Z: FnMut(MultiCoord) -> bool,
+    {
+        let mut spent = HashMap::new();
+
+        if !self.contains_coord(start) {
+            return spent;
+        }
+
+        let start = self.cube_of(start);
+        let mut frontier = BinaryHeap::new();
+
+        spent.insert(start, 0);
+        frontier.push(Reverse((0, start)));
+
+        while let Some(Reverse((used, cube))) = frontier.pop() {
+            if spent.get(&cube).is_some_and(|&known| used > known)
+                || (cube != start && zoc(self.coord_of(cube)))
+            {
+                continue;
+            }
+
+            for next in cube.neighbors() {
+                let coord = self.coord_of(next);
+                let step = match self.get(coord).and_then(|v| cost(coord, v)) {
+                    Some(step) => step.max(1),
+                    None => continue,
+                };
+                let total = used.saturating_add(step);
+
+                if total <= movement
+                    && spent.get(&next).map_or(true, |&known| total < known)
+                {
+                    spent.insert(next, total);
+                    frontier.push(Reverse((total, next)));
+                }
+            }
+        }
+
+        spent
+    }
+}
@@ -0,0 +1,367 @@
+//! Pathfinding
+//!
+//! A* search between hexes of a `HexGrid`, where a cost function gives the
+//! cost of entering each hex, or `None` if it can't be entered. Costs below 1
+//! are raised to 1, which keeps `Cube::dist` a safe estimate of the cost
+//! remaining, so paths found are always the cheapest.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! let mut grid = HexGrid::new_radial(3, '.');
+//!
+//! grid.set(MultiCoord::axial(0, 0), '#');
+//! grid.set(MultiCoord::axial(1, -1), '#');
+//!
+//! let path = grid
+//!     .find_path(
+//!         MultiCoord::axial(-1, 1),
+//!         MultiCoord::axial(2, -2),
+//!         |_, &hex| if hex == '#' { None } else { Some(1) },
+//!     )
+//!     .unwrap();
+//!
+//! assert_eq!(path.cost, 4);
+//! assert_eq!(path.coords.len(), 5);
+//! assert_eq!(path.coords[0], MultiCoord::axial(-1, 1));
+//! ```
+//!
+//! When every passable hex costs the same, `HexGrid::find_path_uniform`
+//! finds paths of the same length while expanding far fewer hexes.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Paths
+//////////////////////////////////////////////////////////////////////////////
+
+/// A path between two hexes, including both ends.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Path {
+    /// The hexes along the path, in the grid's `sys`.
+    pub coords: Vec<MultiCoord>,
+    /// The total cost of entering each hex after the first.
+    pub cost: u64,
+}
+
+impl<T> HexGrid<T> {
+    /// Returns the cheapest path from `start` to `goal`, or `None` if either
+    /// isn't a hex of the grid or `goal` can't be reached. `cost` gives the
+    /// cost of entering a hex, or `None` if it can't be entered.
+    pub fn find_path<F>(
+        &self,
+        start: MultiCoord,
+        goal: MultiCoord,
+        mut cost: F,
+    ) -> Option<Path>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+    {
+        if !self.contains_coord(start) || !self.contains_coord(goal) {
+            return None;
+        }
+
+        let start = self.cube_of(start);
+        let goal = self.cube_of(goal);
+        let (cubes, total) = astar(
+            start,
+            goal,
+            |cube| {
+                let coord = self.coord_of(cube);
+
+                self.get(coord).and_then(|value| cost(coord, value))
+            },
+            |_| false,
+        )?;
+
+        Some(self.path_from(cubes, total))
+    }
+
+    /// Returns the cheapest path from `start` to `goal` that doesn't pass
+    /// through a hex in an enemy's zone of control, under the same
+    /// conditions as `find_path`. `zoc` returns `true` for hexes in a zone
+    /// of control, which end movement once entered, so may only be the
+    /// last hex of a path. A path may leave a zone of control it starts in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let grid = HexGrid::new_boxy(6, 3, ());
+    /// let start = MultiCoord::offset(0, 1);
+    /// let goal = MultiCoord::offset(5, 1);
+    /// let enemy = grid.cube_of(MultiCoord::offset(2, 1));
+    /// let zoc = |coord: MultiCoord| grid.cube_of(coord).dist(enemy) <= 1;
+    ///
+    /// let path = grid.find_path_zoc(start, goal, |_, _| Some(1), zoc);
+    ///
+    /// // The enemy's neighbors fill the middle column, so there's no way by.
+    /// assert_eq!(path, None);
+    /// ```
+    pub fn find_path_zoc<F, Z>(
+        &self,
+        start: MultiCoord,
+        goal: MultiCoord,
+        mut cost: F,
+        mut zoc: Z,
+    ) -> Option<Path>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+        Z: FnMut(MultiCoord) -> bool,
+    {
+        if !self.contains_coord(start) || !self.contains_coord(goal) {
+            return None;
+        }
+
+        let start = self.cube_of(start);
+        let goal = self.cube_of(goal);
+        let (cubes, total) = astar(
+            start,
+            goal,
+            |cube| {
+                let coord = self.coord_of(cube);
+
+                self.get(coord).and_then(|value| cost(coord, value))
+            },
+            |cube| {
+                cube != start
+                    && zoc(self.coord_of(cube))
+            },
+        )?;
+
+        Some(self.path_from(cubes, total))
+    }
+
+    /// Returns a shortest path from `start` to `goal` where every hex costs
+    /// 1 to enter, or `None` if either isn't a hex of the grid or `goal`
+    /// can't be reached. `passable` returns `true` for hexes that can be
+    /// entered.
+    ///
+    /// Paths are as short as those of `find_path`, but the search only
+    /// follows paths in a canonical order, turning clockwise freely and
+    /// counterclockwise only around obstacles, which skips the many equally
+    /// short paths across open ground.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_radial(4, true);
+    ///
+    /// for cube in Cube::<i32>::ORIGIN.ring(2).into_iter().skip(1) {
+    ///     grid.set(MultiCoord::from(cube), false);
+    /// }
+    ///
+    /// let (start, goal) = (MultiCoord::axial(0, 0), MultiCoord::axial(4, 0));
+    /// let uniform = grid.find_path_uniform(start, goal, |_, &open| open);
+    /// let astar = grid.find_path(start, goal, |_, &open| {
+    ///     if open { Some(1) } else { None }
+    /// });
+    ///
+    /// assert_eq!(uniform.unwrap().cost, astar.unwrap().cost);
+    /// ```
+    pub fn find_path_uniform<F>(
+        &self,
+        start: MultiCoord,
+        goal: MultiCoord,
+        mut passable: F,
+    ) -> Option<Path>
+    where
+        F: FnMut(MultiCoord, &T) -> bool,
+    {
+        if !self.contains_coord(start) || !self.contains_coord(goal) {
+            return None;
+        }
+
+        let start = self.cube_of(start);
+        let goal = self.cube_of(goal);
+        let (cubes, total) = canonical(start, goal, |cube| {
+            let coord = self.coord_of(cube);
+
+            self.get(coord).is_some_and(|value| passable(coord, value))
+        })?;
+
+        Some(self.path_from(cubes, total))
+    }
+
+    /// Converts a path of cube coordinates into the grid's `sys`.
+    pub(crate) fn path_from(&self, cubes: Vec<Cube>, cost: u64) -> Path {
+        Path {
+            coords: cubes
+                .into_iter()
+                .map(|cube| self.coord_of(cube))
+                .collect(),
+            cost,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Search
+//////////////////////////////////////////////////////////////////////////////
+
+/// Returns the cheapest path from `start` to `goal`, including both, and its
+/// cost. `cost` gives the cost of entering a hex, or `None` if it can't be
+/// entered; costs below 1 are raised to 1. `stops` returns `true` for hexes
+/// that can be entered but not left.
+pub(crate) fn astar<F, S>(
+    start: Cube,
+    goal: Cube,
+    mut cost: F,
+    mut stops: S,
+) -> Option<(Vec<Cube>, u64)>
+where
+    F: FnMut(Cube) -> Option<u32>,
+    S: FnMut(Cube) -> bool,
+{
+    let estimate = |cube: Cube| u64::from(cube.dist(goal).unsigned_abs());
+    let mut best: HashMap<Cube, u64> = HashMap::new();
+    let mut came_from: HashMap<Cube, Cube> = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+
+    best.insert(start, 0);
+    frontier.push(Reverse((estimate(start), 0, start)));
+
+    while let Some(Reverse((_, spent, cube))) = frontier.pop() {
+        if cube == goal {
+            let mut path = vec![goal];
+
+            while let Some(&prev) = came_from.get(&path[path.len() - 1]) {
+                path.push(prev);
+            }
+
+            path.reverse();
+            return Some((path, spent));
+        }
+
+        if best.get(&cube).is_some_and(|&known| spent > known) || stops(cube)
+        {
+            continue;
+        }
+
+        for next in cube.neighbors() {
+            let step = match cost(next) {
+                Some(step) => u64::from(step.max(1)),
+                None => continue,
+            };
+            let total = spent + step;
+
+            if best.get(&next).map_or(true, |&known| total < known) {
+                best.insert(next, total);
+                came_from.insert(next, cube);
+                frontier.push(Reverse((total + estimate(next), total, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns a shortest path from `start` to `goal`, including both, and its
+/// length, where `passable` returns `true` for hexes that can be entered.
+///
+/// Every shortest path can be rearranged into one whose turns are all
+/// clockwise, except where a counterclockwise turn rounds an obstacle: a
+/// step in one direction followed by a step counterclockwise of it crosses
+/// the same ground as the two steps swapped, unless the hex between them is
+/// blocked. Searching hexes together with the direction they were entered
+/// from, and only stepping straight on, clockwise, or counterclockwise
+/// around a blocked hex, still finds a shortest path.
+pub(crate) fn canonical<F>(
+    start: Cube,
+    goal: Cube,
+    mut passable: F,
+) -> Option<(Vec<Cube>, u64)>
+where
+    F: FnMut(Cube) -> bool,
+{
+    type State = (Cube, Option<usize>);
+
+    let estimate = |cube: Cube| u64::from(cube.dist(goal).unsigned_abs());
+    let mut best: HashMap<Cube, u64> = HashMap::new();
+    let mut seen: HashMap<State, u64> = HashMap::new();
+    let mut came_from: HashMap<State, State> = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+
+    best.insert(start, 0);
+    seen.insert((start, None), 0);
+
+    // Ties go first to hexes that could still reach the goal across open
+    // ground without turning counterclockwise, which is when a step
+    // clockwise of the last heads toward it, then to the hex furthest along.
+    let detour = |cube: Cube, dir: usize| {
+        cube.neighbor(dir + 1).dist(goal) >= cube.dist(goal)
+    };
+
+    frontier.push(Reverse((estimate(start), false, Reverse(0), start, None)));
+
+    while let Some(Reverse((_, _, Reverse(spent), cube, dir))) = frontier.pop()
+    {
+        if cube == goal {
+            let mut state = (cube, dir);
+            let mut path = vec![cube];
+
+            while let Some(&prev) = came_from.get(&state) {
+                path.push(prev.0);
+                state = prev;
+            }
+
+            path.reverse();
+            return Some((path, spent));
+        }
+
+        if seen.get(&(cube, dir)).is_some_and(|&known| spent > known)
+            || best.get(&cube).is_some_and(|&known| spent > known)
+        {
+            continue;
+        }
+
+        let turns = match dir {
+            None => (0..6).collect(),
+            Some(dir) => {
+                let mut turns = vec![dir, (dir + 1) % 6];
+
+                if !passable(cube.neighbor(dir + 4)) {
+                    turns.push((dir + 5) % 6);
+                }
+
+                turns
+            }
+        };
+
+        for turn in turns {
+            let next = cube.neighbor(turn);
+            let total = spent + 1;
+
+            if best.get(&next).is_some_and(|&known| total > known)
+                || seen
+                    .get(&(next, Some(turn)))
+                    .is_some_and(|&known| total >= known)
+                || !passable(next)
+            {
+                continue;
+            }
+
+            best.insert(next, total);
+            seen.insert((next, Some(turn)), total);
+            came_from.insert((next, Some(turn)), (cube, dir));
+            frontier.push(Reverse((
+                total + estimate(next),
+                detour(next, turn),
+                Reverse(total),
+                next,
+                Some(turn),
+            )));
+        }
+    }
+
+    None
+}
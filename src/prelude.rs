@@ -6,6 +6,7 @@
 
 #[doc(no_inline)]
 pub use crate::coordinate::{
+    CoordInt,
     CoordResult,
     CoordSys,
     MultiCoord,
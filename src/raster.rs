@@ -0,0 +1,259 @@
+//! Raster Rendering
+//!
+//! A `HexGrid` can be drawn into an RGBA pixel buffer, with each hex filled
+//! by a callback and outlined by an antialiased border. Buffers can be
+//! written out as PPM images without any dependencies, or as PNG images
+//! when the `image` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//! use chickenwire::raster::Layout;
+//!
+//! let grid = HexGrid::new_radial(2, [40, 120, 60, 255]);
+//! let layout = Layout::new(10.0, (50.0, 50.0));
+//! let raster = grid.render_raster(100, 100, &layout, |&color| color);
+//!
+//! assert_eq!(raster.pixel(50, 50), [40, 120, 60, 255]);
+//! assert_eq!(raster.pixel(0, 0), [0, 0, 0, 0]);
+//!
+//! let mut ppm = Vec::new();
+//!
+//! raster.write_ppm(&mut ppm, [255, 255, 255]).unwrap();
+//! assert!(ppm.starts_with(b"P6\n100 100\n255\n"));
+//! ```
+
+use std::io::{self, Write};
+#[cfg(feature = "image")]
+use std::path::Path;
+
+use crate::coordinate::*;
+use crate::geometry;
+use crate::hexgrid::*;
+
+/// Number of samples taken along each axis of a pixel.
+const SAMPLES: u32 = 4;
+
+//////////////////////////////////////////////////////////////////////////////
+// Layout
+//////////////////////////////////////////////////////////////////////////////
+
+/// Placement and outlining of hexes within a raster.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout {
+    /// Distance in pixels from a hex's center to its corners.
+    pub size: f64,
+    /// Pixel position of the center of the `Cube::ORIGIN` hex.
+    pub origin: (f64, f64),
+    /// Width in pixels of the border drawn between hexes, or 0 for none.
+    pub border_width: f64,
+    /// Color of the border drawn between hexes.
+    pub border_color: [u8; 4],
+}
+
+impl Layout {
+    /// Creates a `Layout` with a 1 pixel, opaque black border.
+    pub fn new(size: f64, origin: (f64, f64)) -> Self {
+        Self {
+            size,
+            origin,
+            border_width: 1.0,
+            border_color: [0, 0, 0, 255],
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Raster
+//////////////////////////////////////////////////////////////////////////////
+
+/// A buffer of pixels in row-major order, each holding unpremultiplied
+/// red, green, blue, and alpha bytes.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Raster {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// Returns the width of the raster in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the raster in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the RGBA bytes of every pixel, in row-major order.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns the RGBA bytes of every pixel, consuming the raster.
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// Returns the color of the pixel at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside the raster.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+
+        let start = 4 * (y as usize * self.width as usize + x as usize);
+        let mut color = [0; 4];
+
+        color.copy_from_slice(&self.pixels[start..start + 4]);
+        color
+    }
+
+    /// Writes the raster as a binary PPM image. PPM has no alpha channel, so
+    /// pixels are blended over the opaque `background` color.
+    pub fn write_ppm<W: Write>(
+        &self,
+        mut writer: W,
+        background: [u8; 3],
+    ) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+
+        let mut rgb = Vec::with_capacity(self.pixels.len() / 4 * 3);
+
+        for pixel in self.pixels.chunks(4) {
+            let alpha = u32::from(pixel[3]);
+
+            for channel in 0..3 {
+                let fore = u32::from(pixel[channel]) * alpha;
+                let back = u32::from(background[channel]) * (255 - alpha);
+
+                rgb.push(((fore + back + 127) / 255) as u8);
+            }
+        }
+
+        writer.write_all(&rgb)
+    }
+
+    /// Saves the raster as a PNG image at the given path.
+    #[cfg(feature = "image")]
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> image::ImageResult<()> {
+        image::save_buffer_with_format(
+            path,
+            &self.pixels,
+            self.width,
+            self.height,
+            image::ColorType::Rgba8,
+            image::ImageFormat::Png,
+        )
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Rendering
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> HexGrid<T> {
+    /// Draws the calling instance into a `width` by `height` raster, filling
+    /// each hex with the color returned by `color` and outlining it with the
+    /// border described by `layout`. Pixels are supersampled, so hex edges
+    /// and borders are antialiased. Pixels outside every hex stay fully
+    /// transparent, as does the whole raster if `layout.size` isn't finite
+    /// and positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::raster::Layout;
+    ///
+    /// let grid = HexGrid::new_radial(1, 255);
+    /// let mut layout = Layout::new(8.0, (20.0, 20.0));
+    ///
+    /// layout.border_width = 0.0;
+    ///
+    /// let raster = grid.render_raster(40, 40, &layout, |&gray| {
+    ///     [gray, gray, gray, 255]
+    /// });
+    ///
+    /// assert_eq!(raster.pixel(20, 20), [255; 4]);
+    /// ```
+    pub fn render_raster<F>(
+        &self,
+        width: u32,
+        height: u32,
+        layout: &Layout,
+        color: F,
+    ) -> Raster
+    where
+        F: Fn(&T) -> [u8; 4],
+    {
+        let mut pixels =
+            Vec::with_capacity(4 * width as usize * height as usize);
+        let step = 1.0 / f64::from(SAMPLES);
+        let weight = 1.0 / f64::from(SAMPLES * SAMPLES);
+        let (ox, oy) = layout.origin;
+
+        for y in 0..height {
+            for x in 0..width {
+                // Premultiplied color, summed over every sample.
+                let mut sum = [0.0f64; 4];
+
+                for sy in 0..SAMPLES {
+                    for sx in 0..SAMPLES {
+                        let point = (
+                            f64::from(x) + (f64::from(sx) + 0.5) * step - ox,
+                            f64::from(y) + (f64::from(sy) + 0.5) * step - oy,
+                        );
+                        let hex = match geometry::hex_at(
+                            self.tilt,
+                            layout.size,
+                            point,
+                        ) {
+                            Some(hex) => hex,
+                            None => continue,
+                        };
+                        let value = match self.get(MultiCoord::from(hex)) {
+                            Some(value) => value,
+                            None => continue,
+                        };
+                        let center =
+                            geometry::center(self.tilt, layout.size, hex);
+                        let dist = geometry::edge_dist(
+                            self.tilt,
+                            layout.size,
+                            center,
+                            point,
+                        );
+                        let sample = if dist < layout.border_width / 2.0 {
+                            layout.border_color
+                        } else {
+                            color(value)
+                        };
+                        let alpha = f64::from(sample[3]) / 255.0;
+
+                        for channel in 0..3 {
+                            sum[channel] +=
+                                f64::from(sample[channel]) * alpha * weight;
+                        }
+                        sum[3] += alpha * weight;
+                    }
+                }
+
+                if sum[3] > 0.0 {
+                    for channel in 0..3 {
+                        pixels.push((sum[channel] / sum[3]).round() as u8);
+                    }
+                    pixels.push((sum[3] * 255.0).round() as u8);
+                } else {
+                    pixels.extend_from_slice(&[0; 4]);
+                }
+            }
+        }
+
+        Raster { width, height, pixels }
+    }
+}
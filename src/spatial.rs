@@ -0,0 +1,282 @@
+//! Spatial Indexing
+//!
+//! `HexSpatialIndex` places any number of entities on each hex, tracking
+//! where each is by a key of the caller's choosing, and answers queries for
+//! the entities near a hex or within a region. Unlike `HexGrid`, it has no
+//! fixed shape: entities may be placed on any `Cube` coordinate.
+//!
+//! Queries walk `Cube::spiral` outward from their center, or scan every
+//! occupied hex when that's cheaper, and list nearer entities first.
+//! Entities on the same hex are listed in the order they arrived there.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//! use chickenwire::spatial::HexSpatialIndex;
+//!
+//! let mut index = HexSpatialIndex::new();
+//!
+//! index.insert("archer", Cube::ORIGIN);
+//! index.insert("knight", Cube::from_coords(2, -1, -1).unwrap());
+//! index.insert("dragon", Cube::from_coords(5, -5, 0).unwrap());
+//!
+//! assert_eq!(index.within(Cube::ORIGIN, 4), vec![&"archer", &"knight"]);
+//! assert_eq!(index.nearest_k(Cube::ORIGIN, 1), vec![&"archer"]);
+//!
+//! index.move_to(&"dragon", Cube::from_coords(1, 0, -1).unwrap());
+//!
+//! assert_eq!(index.nearest_k(Cube::ORIGIN, 2), vec![&"archer", &"dragon"]);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::coordinate::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Index
+//////////////////////////////////////////////////////////////////////////////
+
+/// Entities keyed by `K`, each placed on a hex.
+#[derive(Clone, Debug)]
+pub struct HexSpatialIndex<K> {
+    /// The hex each entity is on.
+    positions: HashMap<K, Cube>,
+    /// The entities on each occupied hex, in the order they arrived.
+    buckets: HashMap<Cube, Vec<K>>,
+}
+
+impl<K> Default for HexSpatialIndex<K>
+where
+    K: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> HexSpatialIndex<K>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self {
+            positions: HashMap::new(),
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of entities.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns `true` if there are no entities.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Returns the number of hexes holding at least one entity.
+    pub fn occupied(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Returns `true` if the entity is in the index.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    /// Returns the hex an entity is on.
+    pub fn position(&self, key: &K) -> Option<Cube> {
+        self.positions.get(key).copied()
+    }
+
+    /// Returns the entities on a hex, in the order they arrived.
+    pub fn at(&self, hex: Cube) -> &[K] {
+        self.buckets.get(&hex).map_or(&[], |bucket| bucket.as_slice())
+    }
+
+    /// Returns an iterator over every entity and its hex, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Cube)> {
+        self.positions.iter().map(|(key, &hex)| (key, hex))
+    }
+
+    /// Places an entity on a hex, returning the hex it was on if it was
+    /// already in the index.
+    pub fn insert(&mut self, key: K, hex: Cube) -> Option<Cube> {
+        let old = self.take(&key);
+
+        self.buckets.entry(hex).or_default().push(key.clone());
+        self.positions.insert(key, hex);
+        old
+    }
+
+    /// Moves an entity already in the index to another hex, returning the
+    /// hex it was on, or `None` without placing it if it isn't in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::spatial::HexSpatialIndex;
+    ///
+    /// let mut index = HexSpatialIndex::new();
+    /// let east = Cube::from_coords(1, 0, -1).unwrap();
+    ///
+    /// index.insert(7, Cube::ORIGIN);
+    ///
+    /// assert_eq!(index.move_to(&7, east), Some(Cube::ORIGIN));
+    /// assert_eq!(index.move_to(&8, east), None);
+    /// assert_eq!(index.at(east), &[7]);
+    /// assert!(index.at(Cube::ORIGIN).is_empty());
+    /// ```
+    pub fn move_to(&mut self, key: &K, hex: Cube) -> Option<Cube> {
+        let old = self.position(key)?;
+
+        if old != hex {
+            self.take(key);
+            self.buckets.entry(hex).or_default().push(key.clone());
+            self.positions.insert(key.clone(), hex);
+        }
+
+        Some(old)
+    }
+
+    /// Removes an entity, returning the hex it was on.
+    pub fn remove(&mut self, key: &K) -> Option<Cube> {
+        self.take(key)
+    }
+
+    /// Removes every entity.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.buckets.clear();
+    }
+
+    /// Returns every entity at most `radius` hexes from `center`, nearer
+    /// entities first. Entities more than `i32::MAX` hexes away are left
+    /// out whatever the radius.
+    pub fn within(&self, center: Cube, radius: u32) -> Vec<&K> {
+        let radius_64 = u64::from(radius);
+        let hexes = (radius_64 + 1)
+            .checked_mul(radius_64)
+            .and_then(|n| n.checked_mul(3))
+            .and_then(|n| n.checked_add(1));
+
+        if hexes.is_some_and(|hexes| hexes <= self.buckets.len() as u64) {
+            return center
+                .spiral(radius)
+                .into_iter()
+                .flat_map(|hex| self.at(hex))
+                .collect();
+        }
+
+        self.scan(center, |dist| dist <= radius_64)
+    }
+
+    /// Returns every entity on the given hexes, in the order the hexes are
+    /// given. Hexes given more than once are only visited the first time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::spatial::HexSpatialIndex;
+    ///
+    /// let mut index = HexSpatialIndex::new();
+    ///
+    /// index.insert('a', Cube::from_coords(0, 2, -2).unwrap());
+    /// index.insert('b', Cube::from_coords(0, -2, 2).unwrap());
+    /// index.insert('c', Cube::from_coords(0, 1, -1).unwrap());
+    ///
+    /// let ring = Cube::ORIGIN.ring(2);
+    ///
+    /// assert_eq!(index.in_region(ring.iter().copied()).len(), 2);
+    /// ```
+    pub fn in_region<I>(&self, hexes: I) -> Vec<&K>
+    where
+        I: IntoIterator<Item = Cube>,
+    {
+        let mut seen = HashSet::new();
+
+        hexes
+            .into_iter()
+            .filter(|&hex| seen.insert(hex))
+            .flat_map(|hex| self.at(hex))
+            .collect()
+    }
+
+    /// Returns the `k` entities nearest `center`, nearer entities first, or
+    /// every entity if there are fewer than `k`. Entities as near as the
+    /// last one returned may be left out.
+    pub fn nearest_k(&self, center: Cube, k: usize) -> Vec<&K> {
+        let mut found: Vec<&K> = Vec::new();
+        let mut radius = 0;
+
+        while found.len() < k && found.len() < self.len() {
+            let ring = if radius == 0 { 1 } else { 6 * radius as usize };
+
+            // Once rings outgrow the occupied hexes, scanning those beyond
+            // the rings walked so far is cheaper.
+            if ring > self.buckets.len() {
+                found.extend(
+                    self.scan(center, |dist| dist >= u64::from(radius)),
+                );
+                break;
+            }
+
+            found.extend(
+                center
+                    .ring(radius)
+                    .into_iter()
+                    .flat_map(|hex| self.at(hex)),
+            );
+            radius += 1;
+        }
+
+        found.truncate(k);
+        found
+    }
+
+    /// Returns the entities on every occupied hex whose distance from
+    /// `center` passes `keep`, nearer entities first. Distances too large
+    /// for an `i32` are given as `u64::MAX`, beyond any radius.
+    fn scan<F>(&self, center: Cube, keep: F) -> Vec<&K>
+    where
+        F: Fn(u64) -> bool,
+    {
+        let mut hexes: Vec<(u64, Cube)> = self
+            .buckets
+            .keys()
+            .map(|&hex| {
+                let dist = hex.checked_dist(center);
+
+                (dist.map_or(u64::MAX, |dist| dist as u64), hex)
+            })
+            .filter(|&(dist, _)| keep(dist))
+            .collect();
+
+        hexes.sort_unstable();
+        hexes.into_iter().flat_map(|(_, hex)| self.at(hex)).collect()
+    }
+
+    /// Removes an entity from its hex's bucket and the positions.
+    fn take(&mut self, key: &K) -> Option<Cube> {
+        let hex = self.positions.remove(key)?;
+        let bucket = self
+            .buckets
+            .get_mut(&hex)
+            .expect("entity's hex has no bucket");
+
+        bucket.retain(|other| other != key);
+
+        if bucket.is_empty() {
+            self.buckets.remove(&hex);
+        }
+
+        Some(hex)
+    }
+}
@@ -0,0 +1,302 @@
+//! SVG Rendering
+//!
+//! A `HexGrid` can be written out as a standalone SVG document, with each
+//! hex styled by a callback and optional overlays drawn on top. Hexes are
+//! written in a fixed order, so rendering the same grid twice produces
+//! identical documents that diff cleanly.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//! use chickenwire::svg::{Overlay, Style};
+//!
+//! let grid = HexGrid::new_radial(1, true);
+//! let path = vec![MultiCoord::axial(-1, 0), MultiCoord::axial(1, 0)];
+//!
+//! let svg = grid.to_svg(
+//!     10.0,
+//!     |&land| {
+//!         if land {
+//!             Style::new("green", "black")
+//!         } else {
+//!             Style::new("blue", "black")
+//!         }
+//!     },
+//!     &[Overlay::Path { coords: path, stroke: "red".to_string() }],
+//! );
+//!
+//! assert!(svg.starts_with("<svg "));
+//! assert_eq!(svg.matches("<polygon ").count(), 7);
+//! assert_eq!(svg.matches("<polyline ").count(), 1);
+//! ```
+
+use crate::coordinate::*;
+use crate::geometry;
+use crate::hexgrid::*;
+use crate::text::{escape, num, push_line};
+
+//////////////////////////////////////////////////////////////////////////////
+// Styling
+//////////////////////////////////////////////////////////////////////////////
+
+/// The appearance of a single hex. `fill` and `stroke` accept any SVG paint,
+/// such as `"none"`, `"teal"`, or `"#3a7f5c"`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Style {
+    pub fill: String,
+    pub stroke: String,
+    pub label: Option<String>,
+}
+
+impl Style {
+    /// Creates an unlabeled `Style` with the given fill and stroke.
+    pub fn new(fill: &str, stroke: &str) -> Self {
+        Self {
+            fill: fill.to_string(),
+            stroke: stroke.to_string(),
+            label: None,
+        }
+    }
+
+    /// Adds a label, drawn at the center of the hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::svg::Style;
+    ///
+    /// let style = Style::new("white", "gray").with_label("A");
+    ///
+    /// assert_eq!(style.label, Some("A".to_string()));
+    /// ```
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+}
+
+/// Extra drawing layered over the hexes of a `HexGrid`. Coordinates may be
+/// given in any system, and needn't be in the grid.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Overlay {
+    /// A line running through the centers of the given hexes, in order.
+    Path { coords: Vec<MultiCoord>, stroke: String },
+    /// A fill painted over each of the given hexes.
+    Region { coords: Vec<MultiCoord>, fill: String },
+    /// Every hex in the grid labeled with its coordinate in the grid's `sys`.
+    CoordLabels,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Rendering
+//////////////////////////////////////////////////////////////////////////////
+
+/// Formats a list of points for a `points` attribute.
+fn points(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .map(|&(x, y)| format!("{},{}", num(x), num(y)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl<T> HexGrid<T> {
+    /// Renders the calling instance as an SVG document, drawing each hex
+    /// with the `Style` returned by `style` and then each of the `overlays`
+    /// in order. `size` is the distance in pixels from a hex's center to
+    /// its corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::svg::{Overlay, Style};
+    ///
+    /// let grid = HexGrid::new_boxy(2, 2, "<&>");
+    /// let svg = grid.to_svg(
+    ///     8.0,
+    ///     |&name| Style::new("#eee", "#333").with_label(name),
+    ///     &[Overlay::CoordLabels],
+    /// );
+    ///
+    /// assert!(svg.contains("&lt;&amp;&gt;"));
+    /// assert!(svg.contains(">offset(1,1)</text>"));
+    /// ```
+    pub fn to_svg<F>(
+        &self,
+        size: f64,
+        style: F,
+        overlays: &[Overlay],
+    ) -> String
+    where
+        F: Fn(&T) -> Style,
+    {
+        let mut hexes: Vec<(Cube, MultiCoord, &T)> = self
+            .iter()
+            .map(|(coord, value)| (self.cube_of(coord), coord, value))
+            .collect();
+
+        hexes.sort_by_key(|&(cube, _, _)| cube.to_tuple());
+
+        // Bounds cover every hex drawn, including those only in overlays.
+        let mut drawn: Vec<Cube> =
+            hexes.iter().map(|&(cube, ..)| cube).collect();
+
+        for overlay in overlays {
+            match overlay {
+                Overlay::Path { coords, .. }
+                | Overlay::Region { coords, .. } => {
+                    drawn.extend(
+                        coords.iter().map(|&coord| self.cube_of(coord)),
+                    );
+                }
+                Overlay::CoordLabels => (),
+            }
+        }
+
+        let corners = |cube| {
+            geometry::corners(
+                self.tilt,
+                size,
+                geometry::center(self.tilt, size, cube),
+            )
+        };
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+
+        for &cube in drawn.iter() {
+            for &(x, y) in corners(cube).iter() {
+                min_x = min_x.min(x);
+                max_x = max_x.max(x);
+                min_y = min_y.min(y);
+                max_y = max_y.max(y);
+            }
+        }
+
+        if drawn.is_empty() {
+            min_x = 0.0;
+            max_x = 0.0;
+            min_y = 0.0;
+            max_y = 0.0;
+        }
+
+        let (width, height) = (max_x - min_x, max_y - min_y);
+        let stroke_width = num(size / 16.0);
+        let mut svg = String::new();
+
+        push_line(
+            &mut svg,
+            format_args!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+                 viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">",
+                num(min_x),
+                num(min_y),
+                num(width),
+                num(height),
+                num(width),
+                num(height),
+            ),
+        );
+
+        svg.push_str("<g class=\"hexes\">\n");
+        for &(cube, _, value) in hexes.iter() {
+            let style = style(value);
+
+            push_line(
+                &mut svg,
+                format_args!(
+                    "<polygon points=\"{}\" fill=\"{}\" stroke=\"{}\" \
+                     stroke-width=\"{}\"/>",
+                    points(&corners(cube)),
+                    escape(&style.fill),
+                    escape(&style.stroke),
+                    stroke_width,
+                ),
+            );
+
+            if let Some(label) = style.label {
+                let (x, y) = geometry::center(self.tilt, size, cube);
+
+                push_line(
+                    &mut svg,
+                    format_args!(
+                        "<text x=\"{}\" y=\"{}\" font-size=\"{}\" \
+                         text-anchor=\"middle\" dominant-baseline=\"central\">\
+                         {}</text>",
+                        num(x),
+                        num(y),
+                        num(size / 2.0),
+                        escape(&label),
+                    ),
+                );
+            }
+        }
+        svg.push_str("</g>\n");
+
+        for overlay in overlays {
+            match overlay {
+                Overlay::Path { coords, stroke } => {
+                    let centers: Vec<(f64, f64)> = coords
+                        .iter()
+                        .map(|&coord| {
+                            let cube = self.cube_of(coord);
+
+                            geometry::center(self.tilt, size, cube)
+                        })
+                        .collect();
+
+                    push_line(
+                        &mut svg,
+                        format_args!(
+                            "<polyline class=\"path\" points=\"{}\" \
+                             fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" \
+                             stroke-linecap=\"round\" \
+                             stroke-linejoin=\"round\"/>",
+                            points(&centers),
+                            escape(stroke),
+                            num(size / 4.0),
+                        ),
+                    );
+                }
+                Overlay::Region { coords, fill } => {
+                    svg.push_str("<g class=\"region\">\n");
+                    for &coord in coords {
+                        push_line(
+                            &mut svg,
+                            format_args!(
+                                "<polygon points=\"{}\" fill=\"{}\" \
+                                 stroke=\"none\"/>",
+                                points(&corners(self.cube_of(coord))),
+                                escape(fill),
+                            ),
+                        );
+                    }
+                    svg.push_str("</g>\n");
+                }
+                Overlay::CoordLabels => {
+                    svg.push_str("<g class=\"coords\">\n");
+                    for &(cube, coord, _) in hexes.iter() {
+                        let (x, y) = geometry::center(self.tilt, size, cube);
+
+                        push_line(
+                            &mut svg,
+                            format_args!(
+                                "<text x=\"{}\" y=\"{}\" font-size=\"{}\" \
+                                 text-anchor=\"middle\">{}</text>",
+                                num(x),
+                                num(y + size / 2.0),
+                                num(size / 5.0),
+                                escape(&coord.to_string()),
+                            ),
+                        );
+                    }
+                    svg.push_str("</g>\n");
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
@@ -0,0 +1,43 @@
+//! Text Output
+//!
+//! Helpers shared by the modules that write grids out as text, such as SVG,
+//! DOT, GraphML, and Tiled maps.
+
+use std::fmt::{self, Write};
+
+/// Formats a number with at most two decimal places.
+pub(crate) fn num(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Appends formatted text and a newline to `out`.
+pub(crate) fn push_line(out: &mut String, args: fmt::Arguments) {
+    // Writing to a `String` can't fail.
+    let _ = out.write_fmt(args);
+    out.push('\n');
+}
+
+/// Escapes text for use in XML content and attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
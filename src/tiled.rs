@@ -0,0 +1,1349 @@
+//! Tiled Maps
+//!
+//! Reading and writing of hexagonal maps made with the
+//! [Tiled](https://www.mapeditor.org) editor, in both its XML (`.tmx`) and
+//! JSON (`.tmj`) formats.
+//!
+//! Tiled describes a hexagonal map by its stagger axis and stagger index.
+//! Staggering along the `x` axis shoves alternating columns down, as with a
+//! `Tilt::Flat` grid, and staggering along the `y` axis shoves alternating
+//! rows right, as with a `Tilt::Sharp` grid. The stagger index picks whether
+//! the `odd` or `even` columns or rows are shoved, matching `Parity`. Tile
+//! `(x, y)` of a layer is then the hex at `Offset` column `x` and row `y`.
+//!
+//! Each tile layer is loaded into a `HexGrid<u32>` holding the global tile
+//! ids (gids) of the layer's tiles, flip flags included. Empty tiles, with a
+//! gid of 0, are left out of the grid.
+//!
+//! Only finite maps with external tilesets are supported, and layers other
+//! than tile layers are skipped. Layer data may be stored as CSV, as
+//! uncompressed base64, or as XML `<tile>` elements, and is always written
+//! as CSV.
+//!
+//! The XML and JSON are read by small parsers private to this module, which
+//! keeps `petgraph` the crate's only required dependency. They accept only
+//! as much of either format as Tiled writes, and reject documents nested
+//! deeper than Tiled ever nests them.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::hexgrid::{Parity, Tilt};
+//! use chickenwire::prelude::*;
+//! use chickenwire::tiled::TiledMap;
+//!
+//! let tmx = r#"
+//!     <map orientation="hexagonal" width="3" height="2" tilewidth="28"
+//!          tileheight="32" hexsidelength="16" staggeraxis="y"
+//!          staggerindex="odd">
+//!       <tileset firstgid="1" source="terrain.tsx"/>
+//!       <layer name="Ground" width="3" height="2">
+//!         <data encoding="csv">1,1,2,0,3,3</data>
+//!       </layer>
+//!     </map>
+//! "#;
+//!
+//! let map = TiledMap::from_tmx(tmx).unwrap();
+//! let ground = &map.layers[0].grid;
+//!
+//! assert_eq!((map.tilt, map.parity), (Tilt::Sharp, Parity::Odd));
+//! assert_eq!(ground.get(MultiCoord::offset(2, 0)), Some(&2));
+//! assert_eq!(ground.get(MultiCoord::offset(0, 1)), None);
+//!
+//! let again = TiledMap::from_tmj(&map.to_tmj()).unwrap();
+//!
+//! assert_eq!(again.to_tmx(), map.to_tmx());
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+use crate::text::{escape, push_line};
+
+//////////////////////////////////////////////////////////////////////////////
+// Layout
+//////////////////////////////////////////////////////////////////////////////
+
+/// Returns the `Tilt` and `Parity` matching a Tiled stagger axis (`"x"` or
+/// `"y"`) and stagger index (`"odd"` or `"even"`).
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::hexgrid::{Parity, Tilt};
+/// use chickenwire::tiled;
+///
+/// assert_eq!(
+///     tiled::layout_from_stagger("x", "even"),
+///     Ok((Tilt::Flat, Parity::Even))
+/// );
+/// assert!(tiled::layout_from_stagger("z", "odd").is_err());
+/// ```
+pub fn layout_from_stagger(
+    axis: &str,
+    index: &str,
+) -> Result<(Tilt, Parity), String> {
+    let tilt = match axis {
+        "x" => Tilt::Flat,
+        "y" => Tilt::Sharp,
+        _ => return Err(format!("Invalid stagger axis {:?}", axis)),
+    };
+    let parity = match index {
+        "odd" => Parity::Odd,
+        "even" => Parity::Even,
+        _ => return Err(format!("Invalid stagger index {:?}", index)),
+    };
+
+    Ok((tilt, parity))
+}
+
+/// Returns the Tiled stagger axis and stagger index matching a `Tilt` and
+/// `Parity`.
+///
+/// # Examples
+///
+/// ```
+/// use chickenwire::hexgrid::{Parity, Tilt};
+/// use chickenwire::tiled;
+///
+/// assert_eq!(
+///     tiled::stagger_from_layout(Tilt::Sharp, Parity::Odd),
+///     ("y", "odd")
+/// );
+/// ```
+pub fn stagger_from_layout(
+    tilt: Tilt,
+    parity: Parity,
+) -> (&'static str, &'static str) {
+    let axis = match tilt {
+        Tilt::Flat => "x",
+        Tilt::Sharp => "y",
+    };
+    let index = match parity {
+        Parity::Odd => "odd",
+        Parity::Even => "even",
+    };
+
+    (axis, index)
+}
+
+/// Returns the `Offset` coordinate of a hex under the given layout.
+fn offset_of(tilt: Tilt, parity: Parity, coord: Cube) -> Offset {
+    match (tilt, parity) {
+        (Tilt::Flat, Parity::Odd) => Offset::cube_to_oflat(coord),
+        (Tilt::Flat, Parity::Even) => Offset::cube_to_eflat(coord),
+        (Tilt::Sharp, Parity::Odd) => Offset::cube_to_osharp(coord),
+        (Tilt::Sharp, Parity::Even) => Offset::cube_to_esharp(coord),
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Maps
+//////////////////////////////////////////////////////////////////////////////
+
+/// A reference to a tileset stored in its own file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Tileset {
+    /// Global tile id of the tileset's first tile.
+    pub first_gid: u32,
+    /// Path to the tileset's file, relative to the map.
+    pub source: String,
+}
+
+/// A named layer of tiles. When written, hexes are placed by position, so
+/// the grid's `tilt`, `parity`, and `sys` needn't match the map's. Hexes
+/// outside the map are left out.
+#[derive(Debug)]
+pub struct TileLayer {
+    pub name: String,
+    /// Global tile ids of the layer's non-empty tiles.
+    pub grid: HexGrid<u32>,
+}
+
+/// A hexagonal Tiled map.
+#[derive(Debug)]
+pub struct TiledMap {
+    pub tilt: Tilt,
+    pub parity: Parity,
+    /// Number of columns of tiles.
+    pub width: u32,
+    /// Number of rows of tiles.
+    pub height: u32,
+    /// Width in pixels of a tile.
+    pub tile_width: u32,
+    /// Height in pixels of a tile.
+    pub tile_height: u32,
+    /// Length in pixels of the tile sides running along the stagger axis.
+    pub hex_side_length: u32,
+    pub tilesets: Vec<Tileset>,
+    /// Tile layers, from bottom to top.
+    pub layers: Vec<TileLayer>,
+}
+
+impl TiledMap {
+    /// Creates an empty `width` by `height` map with the given layout, sized
+    /// for regular hexes 32 pixels across their corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::hexgrid::{Parity, Tilt};
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::tiled::{TileLayer, TiledMap};
+    ///
+    /// let mut map = TiledMap::new(Tilt::Flat, Parity::Odd, 10, 8);
+    /// let mut grid = HexGrid::new(Tilt::Flat, Parity::Odd, CoordSys::Offset);
+    ///
+    /// grid.set(MultiCoord::offset(3, 4), 7);
+    /// map.layers.push(TileLayer { name: "Trees".to_string(), grid });
+    ///
+    /// assert!(map.to_tmx().contains("staggeraxis=\"x\""));
+    /// ```
+    pub fn new(tilt: Tilt, parity: Parity, width: u32, height: u32) -> Self {
+        let (tile_width, tile_height) = match tilt {
+            Tilt::Flat => (32, 28),
+            Tilt::Sharp => (28, 32),
+        };
+
+        Self {
+            tilt,
+            parity,
+            width,
+            height,
+            tile_width,
+            tile_height,
+            hex_side_length: 16,
+            tilesets: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    /// Returns a layer's gids in row-major order, with 0 for empty tiles.
+    fn layer_gids(&self, layer: &TileLayer) -> Vec<u32> {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut gids = vec![0; width * height];
+
+        for (coord, &gid) in layer.grid.iter() {
+            let cube = layer.grid.cube_of(coord);
+            let Offset { col, row } = offset_of(self.tilt, self.parity, cube);
+
+            if col >= 0
+                && row >= 0
+                && (col as usize) < width
+                && (row as usize) < height
+            {
+                gids[row as usize * width + col as usize] = gid;
+            }
+        }
+
+        gids
+    }
+
+    /// Creates a layer from its gids in row-major order.
+    fn layer_from_gids(
+        &self,
+        name: String,
+        gids: &[u32],
+    ) -> Result<TileLayer, String> {
+        let expected = self.width as usize * self.height as usize;
+
+        if gids.len() != expected {
+            return Err(format!(
+                "Layer {:?} has {} tiles, expected {}",
+                name,
+                gids.len(),
+                expected
+            ));
+        }
+
+        let mut grid = HexGrid::new(self.tilt, self.parity, CoordSys::Offset);
+        let width = self.width as usize;
+
+        for (index, &gid) in gids.iter().enumerate() {
+            if gid != 0 {
+                let col = i32::try_from(index % width);
+                let row = i32::try_from(index / width);
+
+                match (col, row) {
+                    (Ok(col), Ok(row)) => {
+                        grid.set(MultiCoord::offset(col, row), gid)
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Map of {}x{} tiles is too large",
+                            self.width, self.height
+                        ))
+                    }
+                };
+            }
+        }
+
+        Ok(TileLayer { name, grid })
+    }
+
+    //////////////////////////////////
+    // TMX
+    //////////////////////////////////
+
+    /// Reads a map from Tiled's XML format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::tiled::TiledMap;
+    ///
+    /// let tmx = r#"<map orientation="orthogonal" width="1" height="1"
+    ///     tilewidth="16" tileheight="16"/>"#;
+    ///
+    /// assert_eq!(
+    ///     TiledMap::from_tmx(tmx).unwrap_err(),
+    ///     "Unsupported orientation \"orthogonal\""
+    /// );
+    /// ```
+    pub fn from_tmx(text: &str) -> Result<Self, String> {
+        let root = XmlParser::new(text).document()?;
+
+        if root.name != "map" {
+            return Err(format!("Unexpected root element <{}>", root.name));
+        }
+
+        let attr = |name: &str| {
+            root.attr(name)
+                .ok_or_else(|| format!("Missing map attribute {:?}", name))
+        };
+        let number = |name: &str| parse_u32(name, attr(name)?);
+
+        check_orientation(attr("orientation")?)?;
+
+        if root.attr("infinite").is_some_and(|value| value != "0") {
+            return Err("Infinite maps are not supported".to_string());
+        }
+
+        let (tilt, parity) = layout_from_stagger(
+            root.attr("staggeraxis").unwrap_or("y"),
+            root.attr("staggerindex").unwrap_or("odd"),
+        )?;
+        let mut map = Self {
+            width: number("width")?,
+            height: number("height")?,
+            tile_width: number("tilewidth")?,
+            tile_height: number("tileheight")?,
+            hex_side_length: match root.attr("hexsidelength") {
+                Some(value) => parse_u32("hexsidelength", value)?,
+                None => 0,
+            },
+            ..Self::new(tilt, parity, 0, 0)
+        };
+
+        for tileset in root.children_named("tileset") {
+            let first_gid = tileset
+                .attr("firstgid")
+                .ok_or("Missing tileset attribute \"firstgid\"")?;
+            let source = tileset
+                .attr("source")
+                .ok_or("Embedded tilesets are not supported")?;
+
+            map.tilesets.push(Tileset {
+                first_gid: parse_u32("firstgid", first_gid)?,
+                source: source.to_string(),
+            });
+        }
+
+        for layer in root.children_named("layer") {
+            let name = layer.attr("name").unwrap_or("").to_string();
+            let data = layer
+                .children_named("data")
+                .next()
+                .ok_or_else(|| format!("Layer {:?} has no data", name))?;
+            let gids = match data.attr("encoding") {
+                Some("csv") => parse_csv(&data.text)?,
+                Some("base64") => {
+                    check_compression(data.attr("compression"))?;
+                    parse_base64(&data.text)?
+                }
+                Some(other) => {
+                    return Err(format!("Unsupported encoding {:?}", other));
+                }
+                None => data
+                    .children_named("tile")
+                    .map(|tile| match tile.attr("gid") {
+                        Some(gid) => parse_u32("gid", gid),
+                        None => Ok(0),
+                    })
+                    .collect::<Result<_, _>>()?,
+            };
+
+            map.layers.push(map.layer_from_gids(name, &gids)?);
+        }
+
+        Ok(map)
+    }
+
+    /// Writes the map in Tiled's XML format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::hexgrid::{Parity, Tilt};
+    /// use chickenwire::tiled::TiledMap;
+    ///
+    /// let map = TiledMap::new(Tilt::Sharp, Parity::Even, 4, 4);
+    /// let tmx = map.to_tmx();
+    ///
+    /// assert!(tmx.contains("orientation=\"hexagonal\""));
+    /// assert!(tmx.contains("staggerindex=\"even\""));
+    /// ```
+    pub fn to_tmx(&self) -> String {
+        let (axis, index) = stagger_from_layout(self.tilt, self.parity);
+        let mut tmx = String::new();
+
+        tmx.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        push_line(
+            &mut tmx,
+            format_args!(
+                "<map version=\"1.10\" orientation=\"hexagonal\" \
+                 renderorder=\"right-down\" width=\"{}\" height=\"{}\" \
+                 tilewidth=\"{}\" tileheight=\"{}\" infinite=\"0\" \
+                 hexsidelength=\"{}\" staggeraxis=\"{}\" staggerindex=\"{}\" \
+                 nextlayerid=\"{}\" nextobjectid=\"1\">",
+                self.width,
+                self.height,
+                self.tile_width,
+                self.tile_height,
+                self.hex_side_length,
+                axis,
+                index,
+                self.layers.len() + 1,
+            ),
+        );
+
+        for tileset in self.tilesets.iter() {
+            push_line(
+                &mut tmx,
+                format_args!(
+                    " <tileset firstgid=\"{}\" source=\"{}\"/>",
+                    tileset.first_gid,
+                    escape(&tileset.source),
+                ),
+            );
+        }
+
+        for (id, layer) in self.layers.iter().enumerate() {
+            push_line(
+                &mut tmx,
+                format_args!(
+                    " <layer id=\"{}\" name=\"{}\" width=\"{}\" \
+                     height=\"{}\">",
+                    id + 1,
+                    escape(&layer.name),
+                    self.width,
+                    self.height,
+                ),
+            );
+            tmx.push_str("  <data encoding=\"csv\">\n");
+
+            let gids = self.layer_gids(layer);
+            let rows: Vec<String> = gids
+                .chunks(self.width.max(1) as usize)
+                .map(|row| {
+                    row.iter()
+                        .map(|gid| gid.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect();
+
+            push_line(&mut tmx, format_args!("{}", rows.join(",\n")));
+            tmx.push_str("</data>\n");
+            tmx.push_str(" </layer>\n");
+        }
+
+        tmx.push_str("</map>\n");
+        tmx
+    }
+
+    //////////////////////////////////
+    // TMJ
+    //////////////////////////////////
+
+    /// Reads a map from Tiled's JSON format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    /// use chickenwire::tiled::TiledMap;
+    ///
+    /// let tmj = r#"{
+    ///     "orientation": "hexagonal", "width": 2, "height": 1,
+    ///     "tilewidth": 32, "tileheight": 28, "hexsidelength": 16,
+    ///     "staggeraxis": "x", "staggerindex": "even",
+    ///     "layers": [
+    ///         {"type": "tilelayer", "name": "Ground", "data": [0, 5]}
+    ///     ]
+    /// }"#;
+    ///
+    /// let map = TiledMap::from_tmj(tmj).unwrap();
+    ///
+    /// assert_eq!(map.layers[0].grid.get(MultiCoord::offset(1, 0)), Some(&5));
+    /// ```
+    pub fn from_tmj(text: &str) -> Result<Self, String> {
+        let root = JsonParser::new(text).document()?;
+        let field = |name: &str| {
+            root.get(name)
+                .ok_or_else(|| format!("Missing map field {:?}", name))
+        };
+        let number = |name: &str| {
+            field(name)?
+                .as_u32()
+                .ok_or_else(|| format!("Invalid map field {:?}", name))
+        };
+        let text_field = |name: &str, default: &'static str| match root
+            .get(name)
+        {
+            Some(value) => value
+                .as_str()
+                .ok_or_else(|| format!("Invalid map field {:?}", name)),
+            None => Ok(default),
+        };
+
+        check_orientation(
+            field("orientation")?
+                .as_str()
+                .ok_or("Invalid map field \"orientation\"")?,
+        )?;
+
+        if let Some(&Json::Bool(true)) = root.get("infinite") {
+            return Err("Infinite maps are not supported".to_string());
+        }
+
+        let (tilt, parity) = layout_from_stagger(
+            text_field("staggeraxis", "y")?,
+            text_field("staggerindex", "odd")?,
+        )?;
+        let mut map = Self {
+            width: number("width")?,
+            height: number("height")?,
+            tile_width: number("tilewidth")?,
+            tile_height: number("tileheight")?,
+            hex_side_length: match root.get("hexsidelength") {
+                Some(_) => number("hexsidelength")?,
+                None => 0,
+            },
+            ..Self::new(tilt, parity, 0, 0)
+        };
+
+        for tileset in root.get("tilesets").map_or(&[][..], Json::items) {
+            let first_gid = tileset
+                .get("firstgid")
+                .and_then(Json::as_u32)
+                .ok_or("Missing tileset field \"firstgid\"")?;
+            let source = tileset
+                .get("source")
+                .and_then(Json::as_str)
+                .ok_or("Embedded tilesets are not supported")?;
+
+            map.tilesets.push(Tileset {
+                first_gid,
+                source: source.to_string(),
+            });
+        }
+
+        for layer in field("layers")?.items() {
+            if layer.get("type").and_then(Json::as_str) != Some("tilelayer") {
+                continue;
+            }
+
+            let name = layer
+                .get("name")
+                .and_then(Json::as_str)
+                .unwrap_or("")
+                .to_string();
+            let gids = match layer.get("data") {
+                Some(Json::Array(items)) => items
+                    .iter()
+                    .map(|item| item.as_u32().ok_or("Invalid tile gid"))
+                    .collect::<Result<_, _>>()?,
+                Some(Json::Str(data)) => {
+                    check_compression(
+                        layer.get("compression").and_then(Json::as_str),
+                    )?;
+                    parse_base64(data)?
+                }
+                _ => return Err(format!("Layer {:?} has no data", name)),
+            };
+
+            map.layers.push(map.layer_from_gids(name, &gids)?);
+        }
+
+        Ok(map)
+    }
+
+    /// Writes the map in Tiled's JSON format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::hexgrid::{Parity, Tilt};
+    /// use chickenwire::tiled::TiledMap;
+    ///
+    /// let map = TiledMap::new(Tilt::Flat, Parity::Odd, 4, 4);
+    ///
+    /// assert!(map.to_tmj().contains("\"staggeraxis\": \"x\""));
+    /// ```
+    pub fn to_tmj(&self) -> String {
+        let (axis, index) = stagger_from_layout(self.tilt, self.parity);
+        let mut tmj = String::new();
+
+        tmj.push_str("{\n");
+        tmj.push_str("  \"compressionlevel\": -1,\n");
+        push_line(&mut tmj, format_args!("  \"height\": {},", self.height));
+        push_line(
+            &mut tmj,
+            format_args!("  \"hexsidelength\": {},", self.hex_side_length),
+        );
+        tmj.push_str("  \"infinite\": false,\n");
+        tmj.push_str("  \"layers\": [\n");
+
+        for (id, layer) in self.layers.iter().enumerate() {
+            let gids = self.layer_gids(layer);
+            let rows: Vec<String> = gids
+                .chunks(self.width.max(1) as usize)
+                .map(|row| {
+                    row.iter()
+                        .map(|gid| gid.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+
+            tmj.push_str("    {\n");
+            tmj.push_str("      \"data\": [\n");
+            push_line(
+                &mut tmj,
+                format_args!("        {}", rows.join(",\n        ")),
+            );
+            tmj.push_str("      ],\n");
+            push_line(
+                &mut tmj,
+                format_args!("      \"height\": {},", self.height),
+            );
+            push_line(&mut tmj, format_args!("      \"id\": {},", id + 1));
+            push_line(
+                &mut tmj,
+                format_args!("      \"name\": {},", escape_json(&layer.name)),
+            );
+            tmj.push_str("      \"opacity\": 1,\n");
+            tmj.push_str("      \"type\": \"tilelayer\",\n");
+            tmj.push_str("      \"visible\": true,\n");
+            push_line(
+                &mut tmj,
+                format_args!("      \"width\": {},", self.width),
+            );
+            tmj.push_str("      \"x\": 0,\n");
+            tmj.push_str("      \"y\": 0\n");
+
+            if id + 1 == self.layers.len() {
+                tmj.push_str("    }\n");
+            } else {
+                tmj.push_str("    },\n");
+            }
+        }
+
+        tmj.push_str("  ],\n");
+        push_line(
+            &mut tmj,
+            format_args!("  \"nextlayerid\": {},", self.layers.len() + 1),
+        );
+        tmj.push_str("  \"nextobjectid\": 1,\n");
+        tmj.push_str("  \"orientation\": \"hexagonal\",\n");
+        tmj.push_str("  \"renderorder\": \"right-down\",\n");
+        push_line(&mut tmj, format_args!("  \"staggeraxis\": \"{}\",", axis));
+        push_line(
+            &mut tmj,
+            format_args!("  \"staggerindex\": \"{}\",", index),
+        );
+        push_line(
+            &mut tmj,
+            format_args!("  \"tileheight\": {},", self.tile_height),
+        );
+
+        let tilesets: Vec<String> = self
+            .tilesets
+            .iter()
+            .map(|tileset| {
+                format!(
+                    "    {{ \"firstgid\": {}, \"source\": {} }}",
+                    tileset.first_gid,
+                    escape_json(&tileset.source)
+                )
+            })
+            .collect();
+
+        if tilesets.is_empty() {
+            tmj.push_str("  \"tilesets\": [],\n");
+        } else {
+            tmj.push_str("  \"tilesets\": [\n");
+            push_line(&mut tmj, format_args!("{}", tilesets.join(",\n")));
+            tmj.push_str("  ],\n");
+        }
+
+        push_line(
+            &mut tmj,
+            format_args!("  \"tilewidth\": {},", self.tile_width),
+        );
+        tmj.push_str("  \"type\": \"map\",\n");
+        tmj.push_str("  \"version\": \"1.10\",\n");
+        push_line(&mut tmj, format_args!("  \"width\": {}", self.width));
+        tmj.push_str("}\n");
+        tmj
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Layer Data
+//////////////////////////////////////////////////////////////////////////////
+
+/// Checks that a map is hexagonal.
+fn check_orientation(orientation: &str) -> Result<(), String> {
+    if orientation == "hexagonal" {
+        Ok(())
+    } else {
+        Err(format!("Unsupported orientation {:?}", orientation))
+    }
+}
+
+/// Checks that layer data is uncompressed.
+fn check_compression(compression: Option<&str>) -> Result<(), String> {
+    match compression {
+        None | Some("") => Ok(()),
+        Some(other) => Err(format!("Unsupported compression {:?}", other)),
+    }
+}
+
+/// Parses an unsigned number held in the named attribute.
+fn parse_u32(name: &str, value: &str) -> Result<u32, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid {} {:?}", name, value))
+}
+
+/// Parses comma-separated gids.
+fn parse_csv(text: &str) -> Result<Vec<u32>, String> {
+    text.trim()
+        .split(',')
+        .map(|gid| parse_u32("gid", gid))
+        .collect()
+}
+
+/// Parses base64 holding little-endian gids.
+fn parse_base64(text: &str) -> Result<Vec<u32>, String> {
+    let invalid = || "Invalid base64 layer data".to_string();
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for c in text.trim().trim_end_matches('=').bytes() {
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(invalid()),
+        };
+
+        buffer = (buffer << 6) | u32::from(sextet);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if bytes.len() % 4 != 0 {
+        return Err(invalid());
+    }
+
+    Ok(bytes
+        .chunks(4)
+        .map(|gid| u32::from_le_bytes([gid[0], gid[1], gid[2], gid[3]]))
+        .collect())
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// XML
+//////////////////////////////////////////////////////////////////////////////
+
+/// An XML element, with the text of its children concatenated.
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/// Replaces entity and character references with the text they stand for.
+fn unescape(text: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(';').ok_or("Unterminated XML entity")?;
+        let entity = &rest[1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") => {
+                u32::from_str_radix(&entity[2..], 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+            }
+            _ if entity.starts_with('#') => {
+                entity[1..].parse().ok().and_then(std::char::from_u32)
+            }
+            _ => None,
+        };
+
+        unescaped.push(
+            c.ok_or_else(|| format!("Unknown XML entity \"&{};\"", entity))?,
+        );
+        rest = &rest[end + 1..];
+    }
+
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+/// Deepest nesting of XML elements or JSON values read before giving up.
+/// Tiled nests a handful of levels, and the parsers recurse once per level,
+/// so this keeps hostile input from overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+/// A minimal parser for the XML written by Tiled. Namespaces and DTDs are
+/// ignored.
+struct XmlParser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Number of elements open around the current position.
+    depth: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn error(&self) -> String {
+        if self.pos >= self.text.len() {
+            "Unexpected end of XML".to_string()
+        } else {
+            format!("Malformed XML at byte {}", self.pos)
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Moves past the next occurrence of `end`.
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(index) => {
+                self.pos += index + end.len();
+                Ok(())
+            }
+            None => {
+                self.pos = self.text.len();
+                Err(self.error())
+            }
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Skips whitespace, comments, declarations, and processing
+    /// instructions.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| {
+                c.is_whitespace() || c == '=' || c == '>' || c == '/'
+            })
+            .unwrap_or(rest.len());
+
+        if end == 0 {
+            return Err(self.error());
+        }
+
+        self.pos += end;
+        Ok(rest[..end].to_string())
+    }
+
+    /// Parses the single element making up a document.
+    fn document(&mut self) -> Result<Element, String> {
+        self.skip_misc()?;
+
+        let root = self.element()?;
+
+        self.skip_misc()?;
+
+        if self.pos < self.text.len() {
+            return Err(self.error());
+        }
+
+        Ok(root)
+    }
+
+    fn element(&mut self) -> Result<Element, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(format!(
+                "XML nested over {} elements deep at byte {}",
+                MAX_DEPTH, self.pos
+            ));
+        }
+
+        self.depth += 1;
+
+        let element = self.element_within();
+
+        self.depth -= 1;
+        element
+    }
+
+    /// Parses an element, counted in `depth` by `element`.
+    fn element_within(&mut self) -> Result<Element, String> {
+        self.expect("<")?;
+
+        let mut element = Element {
+            name: self.name()?,
+            attrs: Vec::new(),
+            children: Vec::new(),
+            text: String::new(),
+        };
+
+        loop {
+            self.skip_whitespace();
+
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            } else if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+
+            let key = self.name()?;
+
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+
+            let quote = if self.rest().starts_with('"') { "\"" } else { "'" };
+
+            self.expect(quote)?;
+
+            let start = self.pos;
+
+            self.skip_past(quote)?;
+
+            let value = unescape(&self.text[start..self.pos - 1])?;
+
+            element.attrs.push((key, value));
+        }
+
+        loop {
+            let rest = self.rest();
+
+            if rest.starts_with("</") {
+                self.pos += 2;
+
+                if self.name()? != element.name {
+                    return Err(self.error());
+                }
+
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                let start = self.pos + 9;
+
+                self.skip_past("]]>")?;
+                element.text.push_str(&self.text[start..self.pos - 3]);
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                element.children.push(self.element()?);
+            } else if rest.is_empty() {
+                return Err(self.error());
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+
+                element.text.push_str(&unescape(&rest[..end])?);
+                self.pos += end;
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// JSON
+//////////////////////////////////////////////////////////////////////////////
+
+/// A JSON value.
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::Str(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn as_u32(&self) -> Option<u32> {
+        match *self {
+            Json::Number(n)
+                if n >= 0.0 && n <= f64::from(u32::MAX) && n.fract() == 0.0 =>
+            {
+                Some(n as u32)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the items of an array, or nothing for any other value.
+    fn items(&self) -> &[Json] {
+        match self {
+            Json::Array(items) => items,
+            _ => &[],
+        }
+    }
+}
+
+/// Escapes text as a JSON string, quotes included.
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ if (c as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+/// A minimal JSON parser.
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Number of values open around the current position.
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn error(&self) -> String {
+        if self.pos >= self.text.len() {
+            "Unexpected end of JSON".to_string()
+        } else {
+            format!("Malformed JSON at byte {}", self.pos)
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Parses the single value making up a document.
+    fn document(&mut self) -> Result<Json, String> {
+        let value = self.value()?;
+
+        self.skip_whitespace();
+
+        if self.pos < self.text.len() {
+            return Err(self.error());
+        }
+
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(format!(
+                "JSON nested over {} values deep at byte {}",
+                MAX_DEPTH, self.pos
+            ));
+        }
+
+        self.depth += 1;
+
+        let value = self.value_within();
+
+        self.depth -= 1;
+        value
+    }
+
+    /// Parses a value, counted in `depth` by `value`.
+    fn value_within(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+
+        if rest.starts_with('{') {
+            self.pos += 1;
+
+            let mut fields = Vec::new();
+
+            self.skip_whitespace();
+            if self.rest().starts_with('}') {
+                self.pos += 1;
+                return Ok(Json::Object(fields));
+            }
+
+            loop {
+                self.skip_whitespace();
+
+                let key = self.string()?;
+
+                self.skip_whitespace();
+                self.expect(":")?;
+                fields.push((key, self.value()?));
+                self.skip_whitespace();
+
+                if self.rest().starts_with(',') {
+                    self.pos += 1;
+                } else {
+                    self.expect("}")?;
+                    return Ok(Json::Object(fields));
+                }
+            }
+        } else if rest.starts_with('[') {
+            self.pos += 1;
+
+            let mut items = Vec::new();
+
+            self.skip_whitespace();
+            if self.rest().starts_with(']') {
+                self.pos += 1;
+                return Ok(Json::Array(items));
+            }
+
+            loop {
+                items.push(self.value()?);
+                self.skip_whitespace();
+
+                if self.rest().starts_with(',') {
+                    self.pos += 1;
+                } else {
+                    self.expect("]")?;
+                    return Ok(Json::Array(items));
+                }
+            }
+        } else if rest.starts_with('"') {
+            self.string().map(Json::Str)
+        } else if rest.starts_with("true") {
+            self.pos += 4;
+            Ok(Json::Bool(true))
+        } else if rest.starts_with("false") {
+            self.pos += 5;
+            Ok(Json::Bool(false))
+        } else if rest.starts_with("null") {
+            self.pos += 4;
+            Ok(Json::Null)
+        } else {
+            let end = rest
+                .find(|c: char| {
+                    !(c.is_ascii_digit() || "+-.eE".contains(c))
+                })
+                .unwrap_or(rest.len());
+
+            match rest[..end].parse() {
+                Ok(number) if end > 0 => {
+                    self.pos += end;
+                    Ok(Json::Number(number))
+                }
+                _ => Err(self.error()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect("\"")?;
+
+        let mut text = String::new();
+
+        loop {
+            let rest = self.rest();
+            let end = rest.find(['"', '\\']).ok_or_else(|| {
+                self.pos = self.text.len();
+                self.error()
+            })?;
+
+            text.push_str(&rest[..end]);
+            self.pos += end + 1;
+
+            if rest[end..].starts_with('"') {
+                return Ok(text);
+            }
+
+            let escape =
+                self.rest().chars().next().ok_or_else(|| self.error())?;
+
+            self.pos += 1;
+            text.push(match escape {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => self.code_point()?,
+                _ => return Err(self.error()),
+            });
+        }
+    }
+
+    /// Parses the hex digits of a `\u` escape, joining surrogate pairs.
+    fn code_point(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+
+        if (0xD800..0xDC00).contains(&high) {
+            self.expect("\\u")?;
+
+            let low = self.hex4()?;
+
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error());
+            }
+
+            let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+
+            std::char::from_u32(code).ok_or_else(|| self.error())
+        } else {
+            std::char::from_u32(high).ok_or_else(|| self.error())
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self.rest().get(..4).ok_or_else(|| self.error())?;
+        let value =
+            u32::from_str_radix(digits, 16).map_err(|_| self.error())?;
+
+        self.pos += 4;
+        Ok(value)
+    }
+}
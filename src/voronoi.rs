@@ -0,0 +1,152 @@
+//! Voronoi Partitioning
+//!
+//! Divides a `HexGrid` into regions around seed hexes, labeling each hex
+//! with the index of the seed it belongs to. `HexGrid::voronoi` assigns hexes
+//! to their nearest seed as the crow flies, while
+//! `HexGrid::voronoi_weighted` grows regions outward from every seed at
+//! once, so terrain that's costly or impossible to cross shapes the borders.
+//!
+//! Ties always go to the seed listed first, so the same seeds always produce
+//! the same regions.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::prelude::*;
+//!
+//! let grid = HexGrid::new_radial(4, ());
+//! let towns = [MultiCoord::axial(-2, 0), MultiCoord::axial(2, 0)];
+//! let provinces = grid.voronoi(&towns);
+//!
+//! assert_eq!(provinces[MultiCoord::axial(-3, 1)], 0);
+//! assert_eq!(provinces[MultiCoord::axial(3, -2)], 1);
+//!
+//! // The middle column is equally far from both, so goes to the first.
+//! assert_eq!(provinces[MultiCoord::axial(0, 0)], 0);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::coordinate::*;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Voronoi
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> HexGrid<T> {
+    /// Returns a grid with the same layout as the calling instance, holding
+    /// the index in `seeds` of the seed nearest each hex by `Cube::dist`.
+    /// Seeds needn't be hexes of the grid. If `seeds` is empty, so is the
+    /// returned grid.
+    pub fn voronoi(&self, seeds: &[MultiCoord]) -> HexGrid<usize> {
+        if seeds.is_empty() {
+            return HexGrid::new(self.tilt, self.parity, self.sys);
+        }
+
+        let seeds: Vec<Cube> =
+            seeds.iter().map(|&seed| self.cube_of(seed)).collect();
+
+        self.map(|coord, _| {
+            let cube = self.cube_of(coord);
+
+            // `min_by_key` keeps the first of equal keys.
+            seeds
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, &seed)| cube.dist(seed))
+                .map(|(index, _)| index)
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns a grid of the hexes reachable from `seeds`, each holding the
+    /// index in `seeds` of the seed it's cheapest to reach from. Regions grow
+    /// between neighboring hexes of the grid, with `cost` giving the cost of
+    /// entering a hex, or `None` if it can't be entered.
+    ///
+    /// Each seed hex belongs to its own seed, whatever its cost. Seeds that
+    /// aren't hexes of the grid are skipped, and where a hex is listed as a
+    /// seed more than once, it goes to the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::prelude::*;
+    ///
+    /// let mut grid = HexGrid::new_boxy(7, 1, '.');
+    ///
+    /// grid.set(MultiCoord::offset(2, 0), '~');
+    /// grid.set(MultiCoord::offset(5, 0), '#');
+    ///
+    /// let seeds = [MultiCoord::offset(0, 0), MultiCoord::offset(6, 0)];
+    /// let regions = grid.voronoi_weighted(&seeds, |_, &hex| match hex {
+    ///     '.' => Some(1),
+    ///     '~' => Some(3),
+    ///     _ => None,
+    /// });
+    ///
+    /// // The river slows the first seed, and the wall blocks the second.
+    /// assert_eq!(regions[MultiCoord::offset(2, 0)], 0);
+    /// assert_eq!(regions[MultiCoord::offset(4, 0)], 0);
+    /// assert!(!regions.contains_coord(MultiCoord::offset(5, 0)));
+    /// assert_eq!(regions[MultiCoord::offset(6, 0)], 1);
+    /// ```
+    pub fn voronoi_weighted<F>(
+        &self,
+        seeds: &[MultiCoord],
+        mut cost: F,
+    ) -> HexGrid<usize>
+    where
+        F: FnMut(MultiCoord, &T) -> Option<u32>,
+    {
+        let mut owners: HashMap<Cube, usize> = HashMap::new();
+        let mut frontier = BinaryHeap::new();
+
+        for (index, &seed) in seeds.iter().enumerate() {
+            if self.contains_coord(seed) {
+                frontier.push(Reverse((0u64, index, self.cube_of(seed))));
+            }
+        }
+
+        // The heap orders equal costs by seed index, so ties go to the
+        // earlier seed.
+        while let Some(Reverse((dist, index, cube))) = frontier.pop() {
+            if owners.contains_key(&cube) {
+                continue;
+            }
+
+            owners.insert(cube, index);
+
+            for next in cube.neighbors() {
+                if owners.contains_key(&next) {
+                    continue;
+                }
+
+                let coord = self.coord_of(next);
+                let step = match self.get(coord) {
+                    Some(value) => cost(coord, value),
+                    None => None,
+                };
+
+                if let Some(step) = step {
+                    let dist = dist + u64::from(step);
+
+                    frontier.push(Reverse((dist, index, next)));
+                }
+            }
+        }
+
+        let mut regions = HexGrid::new(self.tilt, self.parity, self.sys);
+        let mut owners: Vec<(Cube, usize)> = owners.into_iter().collect();
+
+        owners.sort();
+
+        for (cube, index) in owners {
+            regions.set(MultiCoord::from(cube), index);
+        }
+
+        regions
+    }
+}
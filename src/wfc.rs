@@ -0,0 +1,551 @@
+//! Wave Function Collapse
+//!
+//! Fills the shape of a `HexGrid` with tiles whose sides must match their
+//! neighbors'. Each side of a tile has a socket, and two tiles may sit side
+//! by side only where their touching sockets are equal. Hexes missing from
+//! the grid place no constraints on their neighbors.
+//!
+//! Tiles are chosen at random, weighted by each tile's `weight`, starting
+//! with the hexes that have the fewest options left. When the remaining
+//! options run out, earlier choices are undone and retried. The same seed
+//! always produces the same result.
+//!
+//! # Examples
+//!
+//! ```
+//! use chickenwire::hexgrid::{Compass, Tilt};
+//! use chickenwire::prelude::*;
+//! use chickenwire::wfc::{CollapseOptions, TileSet};
+//!
+//! use Compass::*;
+//!
+//! let mut tiles = TileSet::new(Tilt::Flat);
+//! let all = |socket| {
+//!     [North, Northeast, Southeast, South, Southwest, Northwest]
+//!         .iter()
+//!         .map(|&dir| (dir, socket))
+//!         .collect::<Vec<_>>()
+//! };
+//!
+//! let land = tiles.add('#', 3.0, &all("land")).unwrap();
+//! let sea = tiles.add('~', 3.0, &all("sea")).unwrap();
+//!
+//! tiles
+//!     .add_rotations('/', 1.0, &[
+//!         (North, "land"),
+//!         (Northeast, "land"),
+//!         (Southeast, "land"),
+//!         (South, "sea"),
+//!         (Southwest, "sea"),
+//!         (Northwest, "sea"),
+//!     ])
+//!     .unwrap();
+//!
+//! let shape = HexGrid::new_radial(4, ());
+//! let options = CollapseOptions {
+//!     seed: 7,
+//!     fixed: vec![
+//!         (MultiCoord::axial(0, 0), land),
+//!         (MultiCoord::axial(3, 0), sea),
+//!     ],
+//!     ..CollapseOptions::default()
+//! };
+//! let map = shape.collapse(&tiles, &options).unwrap();
+//!
+//! assert_eq!(map.iter().count(), 61);
+//! assert_eq!(map[MultiCoord::axial(0, 0)], land);
+//! assert_eq!(tiles.get(map[MultiCoord::axial(3, 0)]).unwrap().value, '~');
+//! ```
+
+use std::collections::HashMap;
+
+use crate::coordinate::*;
+use crate::generate::split_mix;
+use crate::hexgrid::*;
+
+//////////////////////////////////////////////////////////////////////////////
+// Tiles
+//////////////////////////////////////////////////////////////////////////////
+
+/// A tile that can be placed by `HexGrid::collapse`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tile<T> {
+    pub value: T,
+    /// Number of clockwise turns the tile was rotated by when added.
+    pub rotation: u32,
+    /// Relative likelihood of the tile being chosen.
+    pub weight: f64,
+    sockets: [String; 6],
+}
+
+impl<T> Tile<T> {
+    /// Returns the tile's sockets, in the order of `Cube::neighbors`.
+    pub fn sockets(&self) -> &[String; 6] {
+        &self.sockets
+    }
+}
+
+/// The tiles available to `HexGrid::collapse`, for hexes of a given tilt.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileSet<T> {
+    tilt: Tilt,
+    tiles: Vec<Tile<T>>,
+}
+
+impl<T> TileSet<T> {
+    /// Creates an empty `TileSet` for hexes with the given tilt.
+    pub fn new(tilt: Tilt) -> Self {
+        Self {
+            tilt,
+            tiles: Vec::new(),
+        }
+    }
+
+    /// Returns the tilt of the hexes the tiles are for.
+    pub fn tilt(&self) -> Tilt {
+        self.tilt
+    }
+
+    /// Returns the number of tiles in the set.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Returns `true` if the set holds no tiles.
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Returns the tile with the given index.
+    pub fn get(&self, index: usize) -> Option<&Tile<T>> {
+        self.tiles.get(index)
+    }
+
+    /// Adds a tile with a socket facing each of the six sides of a hex,
+    /// returning the tile's index.
+    ///
+    /// Returns an error if `weight` isn't positive, or the sockets don't
+    /// name each side of the set's hexes exactly once.
+    pub fn add(
+        &mut self,
+        value: T,
+        weight: f64,
+        sockets: &[(Compass, &str)],
+    ) -> Result<usize, String> {
+        let sockets = self.sides(weight, sockets)?;
+
+        self.tiles.push(Tile {
+            value,
+            rotation: 0,
+            weight,
+            sockets,
+        });
+
+        Ok(self.tiles.len() - 1)
+    }
+
+    /// Adds a tile in each of its six rotations, as given by
+    /// `Cube::rotate_cw`, returning the indices of the tiles added.
+    /// Rotations with the same sockets as an earlier rotation are skipped.
+    ///
+    /// Returns an error under the same conditions as `add`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chickenwire::hexgrid::{Compass, Tilt};
+    /// use chickenwire::wfc::TileSet;
+    ///
+    /// use Compass::*;
+    ///
+    /// let mut tiles = TileSet::new(Tilt::Sharp);
+    /// let added = tiles
+    ///     .add_rotations("road", 1.0, &[
+    ///         (East, "road"),
+    ///         (West, "road"),
+    ///         (Northeast, "grass"),
+    ///         (Southeast, "grass"),
+    ///         (Southwest, "grass"),
+    ///         (Northwest, "grass"),
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// // A straight road looks the same after half a turn.
+    /// assert_eq!(added, vec![0, 1, 2]);
+    /// assert_eq!(tiles.get(1).unwrap().rotation, 1);
+    /// assert_eq!(tiles.get(1).unwrap().sockets()[2], "road");
+    /// ```
+    pub fn add_rotations(
+        &mut self,
+        value: T,
+        weight: f64,
+        sockets: &[(Compass, &str)],
+    ) -> Result<Vec<usize>, String>
+    where
+        T: Clone,
+    {
+        let sockets = self.sides(weight, sockets)?;
+        let origin = Cube::<i32>::ORIGIN;
+        let mut seen: Vec<[String; 6]> = Vec::new();
+        let mut added = Vec::new();
+
+        for rotation in 0..6 {
+            let mut rotated = sockets.clone();
+
+            for (side, socket) in sockets.iter().enumerate() {
+                let turned = origin.rotate_cw(origin.neighbor(side), rotation);
+                let to = (0..6)
+                    .position(|index| origin.neighbor(index) == turned)
+                    .unwrap_or(side);
+
+                rotated[to] = socket.clone();
+            }
+
+            if seen.contains(&rotated) {
+                continue;
+            }
+
+            seen.push(rotated.clone());
+            self.tiles.push(Tile {
+                value: value.clone(),
+                rotation,
+                weight,
+                sockets: rotated,
+            });
+            added.push(self.tiles.len() - 1);
+        }
+
+        Ok(added)
+    }
+
+    /// Orders sockets given by direction into neighbor order, checking them
+    /// and the tile's weight.
+    fn sides(
+        &self,
+        weight: f64,
+        sockets: &[(Compass, &str)],
+    ) -> Result<[String; 6], String> {
+        if weight.is_nan() || weight <= 0.0 {
+            return Err("Tile weights must be positive".to_string());
+        }
+
+        let mut sides: [Option<String>; 6] = Default::default();
+
+        for &(dir, socket) in sockets {
+            let side = (0..6)
+                .map(|index| Compass::from_neighbor(self.tilt, index))
+                .position(|side| side == dir)
+                .ok_or_else(|| {
+                    format!("{:?} isn't a side of a {:?} hex", dir, self.tilt)
+                })?;
+
+            if sides[side].replace(socket.to_string()).is_some() {
+                return Err(format!("Tile has two sockets facing {:?}", dir));
+            }
+        }
+
+        let mut result: [String; 6] = Default::default();
+
+        for (side, socket) in sides.iter_mut().enumerate() {
+            result[side] = socket.take().ok_or_else(|| {
+                format!(
+                    "Tile has no socket facing {:?}",
+                    Compass::from_neighbor(self.tilt, side)
+                )
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Collapse
+//////////////////////////////////////////////////////////////////////////////
+
+/// Options for `HexGrid::collapse`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CollapseOptions {
+    /// Seed for choosing tiles.
+    pub seed: u64,
+    /// Hexes fixed to the tile with the given index before collapsing.
+    pub fixed: Vec<(MultiCoord, usize)>,
+    /// Number of times choices may be undone before giving up.
+    pub max_backtracks: usize,
+}
+
+impl Default for CollapseOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            fixed: Vec::new(),
+            max_backtracks: 1000,
+        }
+    }
+}
+
+/// The tiles each hex may still hold, indexed by hex and then tile.
+type Domains = Vec<Vec<bool>>;
+
+/// The hexes of a grid being collapsed and how their tiles may fit.
+struct Solver {
+    /// Neighboring hexes, by hex and then side.
+    links: Vec<[Option<usize>; 6]>,
+    /// Whether tile `b` may sit on side `side` of tile `a`, indexed by
+    /// `side`, `a`, then `b`.
+    fits: Vec<Vec<Vec<bool>>>,
+    weights: Vec<f64>,
+}
+
+impl Solver {
+    /// Removes tiles that no longer fit beside their neighbors, starting
+    /// from the given hexes, and returns `false` if any hex runs out. Each
+    /// removed tile is pushed onto `trail` as a hex and tile.
+    fn propagate(
+        &self,
+        domains: &mut Domains,
+        trail: &mut Vec<(usize, usize)>,
+        mut stack: Vec<usize>,
+    ) -> bool {
+        while let Some(hex) = stack.pop() {
+            for (side, &link) in self.links[hex].iter().enumerate() {
+                let other = match link {
+                    Some(other) => other,
+                    None => continue,
+                };
+                let mut changed = false;
+
+                for tile in 0..self.weights.len() {
+                    if !domains[other][tile] {
+                        continue;
+                    }
+
+                    let supported = (0..self.weights.len()).any(|here| {
+                        domains[hex][here] && self.fits[side][here][tile]
+                    });
+
+                    if !supported {
+                        domains[other][tile] = false;
+                        trail.push((other, tile));
+                        changed = true;
+                    }
+                }
+
+                if changed {
+                    if !domains[other].contains(&true) {
+                        return false;
+                    }
+
+                    stack.push(other);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns the undecided hex with the lowest Shannon entropy, if any.
+    fn next_hex(&self, domains: &Domains) -> Option<usize> {
+        let mut best: Option<(f64, usize)> = None;
+
+        for (hex, domain) in domains.iter().enumerate() {
+            if domain.iter().filter(|&&open| open).count() < 2 {
+                continue;
+            }
+
+            let weights = self.open_weights(domain);
+            let total: f64 = weights.iter().map(|&(_, w)| w).sum();
+            let entropy = total.ln()
+                - weights.iter().map(|&(_, w)| w * w.ln()).sum::<f64>()
+                    / total;
+
+            if best.map_or(true, |(lowest, _)| entropy < lowest) {
+                best = Some((entropy, hex));
+            }
+        }
+
+        best.map(|(_, hex)| hex)
+    }
+
+    /// Picks one of a hex's remaining tiles at random, by weight.
+    fn choose(&self, domain: &[bool], rng: &mut u64) -> usize {
+        let weights = self.open_weights(domain);
+        let total: f64 = weights.iter().map(|&(_, w)| w).sum();
+        let unit = (split_mix(rng) >> 11) as f64 / (1u64 << 53) as f64;
+        let mut target = unit * total;
+
+        for &(tile, weight) in weights.iter() {
+            if target < weight {
+                return tile;
+            }
+
+            target -= weight;
+        }
+
+        weights[weights.len() - 1].0
+    }
+
+    fn open_weights(&self, domain: &[bool]) -> Vec<(usize, f64)> {
+        domain
+            .iter()
+            .enumerate()
+            .filter(|&(_, &open)| open)
+            .map(|(tile, _)| (tile, self.weights[tile]))
+            .collect()
+    }
+}
+
+impl<T> HexGrid<T> {
+    /// Returns a grid with the same shape as the calling instance, holding
+    /// the index of the tile chosen for each hex.
+    ///
+    /// Returns an error if the tile set is for hexes of a different tilt, a
+    /// fixed hex or tile doesn't exist, the tiles can't fill the grid, or
+    /// `options.max_backtracks` is exceeded.
+    pub fn collapse<V>(
+        &self,
+        tiles: &TileSet<V>,
+        options: &CollapseOptions,
+    ) -> Result<HexGrid<usize>, String> {
+        if tiles.tilt != self.tilt {
+            return Err(format!(
+                "Tiles are for {:?} hexes, but the grid is {:?}",
+                tiles.tilt, self.tilt
+            ));
+        }
+
+        let mut cells: Vec<Cube> = self
+            .iter()
+            .map(|(coord, _)| self.cube_of(coord))
+            .collect();
+
+        cells.sort();
+
+        let indices: HashMap<Cube, usize> =
+            cells.iter().enumerate().map(|(i, &cube)| (cube, i)).collect();
+        let count = tiles.len();
+        let solver = Solver {
+            links: cells
+                .iter()
+                .map(|cube| {
+                    let mut links = [None; 6];
+
+                    for (side, link) in links.iter_mut().enumerate() {
+                        *link = indices.get(&cube.neighbor(side)).copied();
+                    }
+
+                    links
+                })
+                .collect(),
+            fits: (0..6)
+                .map(|side| {
+                    tiles
+                        .tiles
+                        .iter()
+                        .map(|here| {
+                            tiles
+                                .tiles
+                                .iter()
+                                .map(|there| {
+                                    here.sockets[side]
+                                        == there.sockets[(side + 3) % 6]
+                                })
+                                .collect()
+                        })
+                        .collect()
+                })
+                .collect(),
+            weights: tiles.tiles.iter().map(|tile| tile.weight).collect(),
+        };
+        let mut domains: Domains = vec![vec![true; count]; cells.len()];
+
+        for &(coord, tile) in options.fixed.iter() {
+            if tile >= count {
+                return Err(format!("No tile with index {}", tile));
+            }
+
+            let cube = self.cube_of(coord);
+            let hex = match indices.get(&cube) {
+                Some(&hex) => hex,
+                None => {
+                    return Err(format!("Grid contains no value at {}", coord))
+                }
+            };
+
+            for (other, open) in domains[hex].iter_mut().enumerate() {
+                *open &= other == tile;
+            }
+        }
+
+        let unsolvable = || "Tiles can't fill the grid".to_string();
+        let mut trail = Vec::new();
+
+        if domains.iter().any(|domain| !domain.contains(&true))
+            || !solver.propagate(
+                &mut domains,
+                &mut trail,
+                (0..cells.len()).collect(),
+            )
+        {
+            return Err(unsolvable());
+        }
+
+        // Each choice is kept as the length of the trail before it was made,
+        // so undoing it only restores the tiles removed since.
+        let mut rng = options.seed;
+        let mut choices: Vec<(usize, usize, usize)> = Vec::new();
+        let mut backtracks = 0;
+
+        trail.clear();
+
+        while let Some(hex) = solver.next_hex(&domains) {
+            let tile = solver.choose(&domains[hex], &mut rng);
+
+            choices.push((trail.len(), hex, tile));
+
+            for (other, open) in domains[hex].iter_mut().enumerate() {
+                if *open && other != tile {
+                    *open = false;
+                    trail.push((hex, other));
+                }
+            }
+
+            let mut consistent =
+                solver.propagate(&mut domains, &mut trail, vec![hex]);
+
+            // Undo choices until the grid is consistent again, ruling out
+            // each choice that was undone.
+            while !consistent {
+                backtracks += 1;
+
+                if backtracks > options.max_backtracks {
+                    return Err(format!(
+                        "Gave up after {} backtracks",
+                        options.max_backtracks
+                    ));
+                }
+
+                let (mark, hex, tile) = choices.pop().ok_or_else(unsolvable)?;
+
+                for (removed, tile) in trail.drain(mark..) {
+                    domains[removed][tile] = true;
+                }
+
+                domains[hex][tile] = false;
+                trail.push((hex, tile));
+                consistent = domains[hex].contains(&true)
+                    && solver.propagate(&mut domains, &mut trail, vec![hex]);
+            }
+        }
+
+        let mut result = HexGrid::new(self.tilt, self.parity, self.sys);
+
+        for (cube, domain) in cells.into_iter().zip(domains) {
+            if let Some(tile) = domain.iter().position(|&open| open) {
+                result.set(MultiCoord::from(cube), tile);
+            }
+        }
+
+        Ok(result)
+    }
+}
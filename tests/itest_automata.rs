@@ -0,0 +1,91 @@
+//! Integration tests for `chickenwire::automata`.
+
+use chickenwire::automata::{Boundary, StepOptions};
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+#[test]
+fn test_step_reads_previous_generation() {
+    let mut grid = HexGrid::new_radial(2, 0);
+
+    grid.set(MultiCoord::axial(0, 0), 1);
+    grid.track_changes(true);
+
+    // Each hex takes the sum of its neighbors, which would compound if
+    // writes were visible mid-step.
+    grid.step(|_, neighbors| neighbors.iter().flatten().copied().sum());
+
+    assert_eq!(grid[MultiCoord::axial(0, 0)], 0);
+    assert_eq!(grid[MultiCoord::axial(1, 0)], 1);
+    assert_eq!(grid[MultiCoord::axial(2, 0)], 0);
+    assert_eq!(grid.take_changes().len(), 19);
+}
+
+#[test]
+fn test_neighbor_order_matches_cube() {
+    let mut grid = HexGrid::new_radial(1, 0);
+
+    for (i, cube) in Cube::<i32>::ORIGIN.neighbors().into_iter().enumerate() {
+        grid.set(MultiCoord::from(cube), i + 1);
+    }
+
+    grid.step(|&hex, neighbors| {
+        let order: Vec<_> = neighbors.iter().flatten().copied().collect();
+
+        if order.len() == 6 {
+            order.iter().fold(0, |acc, &n| acc * 10 + n)
+        } else {
+            hex
+        }
+    });
+
+    assert_eq!(grid[MultiCoord::axial(0, 0)], 123_456);
+}
+
+#[test]
+fn test_boundaries() {
+    let count = |boundary, diagonals| {
+        let mut grid = HexGrid::new_radial(1, 1);
+        let options = StepOptions {
+            boundary,
+            diagonals,
+        };
+
+        grid.step_with(&options, |_, around| around.count(|&n| n == 1));
+        grid[MultiCoord::axial(1, 0)]
+    };
+
+    assert_eq!(count(Boundary::Absent, false), 3);
+    assert_eq!(count(Boundary::Absent, true), 5);
+    assert_eq!(count(Boundary::Constant(1), false), 6);
+    assert_eq!(count(Boundary::Constant(1), true), 12);
+    assert_eq!(count(Boundary::Constant(0), true), 5);
+}
+
+#[test]
+fn test_wrap_boundary() {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+
+    for col in 0..5 {
+        for row in 0..4 {
+            grid.set(MultiCoord::offset(col, row), 0);
+        }
+    }
+
+    grid.set(MultiCoord::offset(0, 0), 1);
+
+    let options = StepOptions {
+        boundary: Boundary::Wrap,
+        diagonals: false,
+    };
+
+    grid.run(1, &options, |&hex, around| {
+        hex.max(around.count(|&n| n == 1) as i32)
+    });
+
+    // The lit corner reaches across both seams.
+    assert_eq!(grid[MultiCoord::offset(4, 0)], 1);
+    assert_eq!(grid[MultiCoord::offset(0, 3)], 1);
+    assert_eq!(grid[MultiCoord::offset(2, 2)], 0);
+    assert_eq!(grid.iter().filter(|&(_, &n)| n == 1).count(), 7);
+}
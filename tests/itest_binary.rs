@@ -0,0 +1,198 @@
+//! Integration tests for `chickenwire::binary`.
+
+use chickenwire::binary::{Codec, IntCodec, VERSION};
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+/// Stores strings with a one byte length prefix.
+struct NameCodec;
+
+impl Codec<String> for NameCodec {
+    fn encode(&self, value: &String, out: &mut Vec<u8>) {
+        out.push(value.len() as u8);
+        out.extend_from_slice(value.as_bytes());
+    }
+
+    fn decode(&self, input: &mut &[u8]) -> Option<String> {
+        let (&len, rest) = input.split_first()?;
+        let bytes = rest.get(..len as usize)?;
+        let name = String::from_utf8(bytes.to_vec()).ok()?;
+
+        *input = &rest[len as usize..];
+        Some(name)
+    }
+}
+
+fn sorted<T: Clone>(grid: &HexGrid<T>) -> Vec<(String, T)> {
+    let mut cells: Vec<(String, T)> = grid
+        .iter()
+        .map(|(coord, value)| {
+            let cube = grid.convert(coord, CoordSys::Cube);
+
+            (cube.to_string(), value.clone())
+        })
+        .collect();
+
+    cells.sort_by(|a, b| a.0.cmp(&b.0));
+    cells
+}
+
+fn sample_grids() -> Vec<HexGrid<u32>> {
+    let mut boxy = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+
+    for col in -3..6 {
+        for row in 1..6 {
+            boxy.set(MultiCoord::offset(col, row), 3);
+        }
+    }
+    boxy.set(MultiCoord::offset(2, 3), 8);
+
+    let mut radial = HexGrid::new_radial(4, 0);
+
+    radial.set(MultiCoord::axial(2, -1), 5);
+
+    let mut moved = HexGrid::new(Tilt::Flat, Parity::Even, CoordSys::Axial);
+
+    for coord in Cube::force_from_coords(-40, 30, 10).spiral(2) {
+        moved.set(MultiCoord::from(coord), 1);
+    }
+
+    let mut sparse = HexGrid::new(Tilt::Sharp, Parity::Even, CoordSys::Double);
+
+    for (i, coord) in Cube::<i32>::ORIGIN.ring(6).into_iter().enumerate() {
+        sparse.set(MultiCoord::from(coord), (i / 4) as u32);
+    }
+    sparse.set(MultiCoord::force_double(1000, -2000), 9);
+
+    let mut cube = HexGrid::new(Tilt::Flat, Parity::Odd, CoordSys::Cube);
+
+    cube.set(MultiCoord::force_cube(5, -7, 2), 4);
+    cube.set(MultiCoord::force_cube(-5, 0, 5), 4);
+
+    vec![boxy, radial, moved, sparse, cube, HexGrid::default()]
+}
+
+#[test]
+fn test_round_trip() {
+    for grid in sample_grids() {
+        let bytes = grid.to_bytes(&IntCodec);
+        let decoded: HexGrid<u32> =
+            HexGrid::from_bytes(&bytes, &IntCodec).unwrap();
+
+        assert_eq!(decoded.tilt, grid.tilt);
+        assert_eq!(decoded.parity, grid.parity);
+        assert_eq!(decoded.sys, grid.sys);
+        assert_eq!(sorted(&decoded), sorted(&grid));
+        assert_eq!(decoded.to_bytes(&IntCodec), bytes);
+    }
+}
+
+#[test]
+fn test_compact_encodings() {
+    let grids = sample_grids();
+
+    // Header, bounds, and three runs of one byte lengths and values.
+    assert_eq!(grids[0].to_bytes(&IntCodec).len(), 9 + 16 + 3 * 5);
+    assert_eq!(grids[1].to_bytes(&IntCodec).len(), 9 + 16 + 3 * 5);
+    // A radial grid away from the origin is still dense.
+    assert_eq!(grids[2].to_bytes(&IntCodec)[8], 2);
+    // A sparse grid stores its coordinates.
+    assert_eq!(grids[3].to_bytes(&IntCodec)[8], 0);
+}
+
+#[test]
+fn test_custom_codec() {
+    let mut grid = HexGrid::default();
+
+    for coord in Cube::<i32>::ORIGIN.spiral(1) {
+        grid.set(MultiCoord::from(coord), "sea".to_string());
+    }
+    grid.set(MultiCoord::axial(0, 0), "isle".to_string());
+
+    let bytes = grid.to_bytes(&NameCodec);
+    let decoded = HexGrid::from_bytes(&bytes, &NameCodec).unwrap();
+
+    assert_eq!(sorted(&decoded), sorted(&grid));
+}
+
+#[test]
+fn test_rejects_corrupt_data() {
+    for grid in sample_grids() {
+        let bytes = grid.to_bytes(&IntCodec);
+
+        for len in 0..bytes.len() {
+            assert!(
+                HexGrid::<u32>::from_bytes(&bytes[..len], &IntCodec).is_err(),
+                "accepted {} of {} bytes",
+                len,
+                bytes.len()
+            );
+        }
+
+        let mut extended = bytes.clone();
+
+        extended.push(0);
+        assert!(HexGrid::<u32>::from_bytes(&extended, &IntCodec).is_err());
+
+        // Flipped bits must never panic, whether or not they're caught.
+        for i in 0..bytes.len() {
+            for &bit in [0x01, 0x10, 0x80].iter() {
+                let mut flipped = bytes.clone();
+
+                flipped[i] ^= bit;
+                let _ = HexGrid::<u32>::from_bytes(&flipped, &IntCodec);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rejection_messages() {
+    let decode = |bytes: &[u8]| {
+        HexGrid::<u8>::from_bytes(bytes, &IntCodec).unwrap_err()
+    };
+
+    assert_eq!(decode(b"PNG\x00"), "Not a chickenwire map");
+    assert_eq!(
+        decode(&[b'C', b'H', b'W', b'M', VERSION + 1]),
+        format!("Unsupported map version {}", VERSION + 1)
+    );
+    assert_eq!(
+        decode(b"CHWM\x01\x00\x00\x04"),
+        "Invalid coordinate system at byte 8"
+    );
+
+    // A sparse cube map holding cube(1,1,1).
+    assert_eq!(
+        decode(b"CHWM\x01\x00\x00\x01\x00\x01\x02\x02\x02\x01\x00"),
+        "Coordinate violates its constraint at byte 13"
+    );
+
+    // A sparse axial map whose second x is 1 + i64::MAX.
+    let mut bytes = b"CHWM\x01\x00\x00\x00\x00\x02\x02\x00\xfe".to_vec();
+
+    bytes.extend_from_slice(&[0xff; 8]);
+    bytes.extend_from_slice(&[0x01, 0x00]);
+
+    assert_eq!(decode(&bytes), "Coordinate out of range at byte 22");
+
+    // A boxy map with a run longer than the map.
+    let mut bytes = b"CHWM\x01\x00\x00\x03\x01".to_vec();
+
+    for &value in [0i32, 0, 2, 1].iter() {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes.extend_from_slice(&[3, 7]);
+
+    assert_eq!(decode(&bytes), "Invalid run length at byte 26");
+
+    // A boxy map claiming u32::MAX hexes, all in one run.
+    let mut bytes = b"CHWM\x01\x00\x00\x03\x01".to_vec();
+
+    for &value in [0i32, 0, 65_535, 65_537].iter() {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x0f, 7]);
+
+    assert_eq!(decode(&bytes), "Too many hexes at byte 25");
+}
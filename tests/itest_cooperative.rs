@@ -0,0 +1,132 @@
+//! Integration tests for `chickenwire::cooperative`.
+
+use chickenwire::cooperative::{CooperativePlanner, TimedPath};
+use chickenwire::prelude::*;
+
+/// Asserts that no two paths share a hex at the same time or swap hexes.
+fn assert_no_conflicts(grid: &HexGrid<()>, paths: &[TimedPath]) {
+    let end = paths.iter().map(TimedPath::arrival).max().unwrap_or(0) + 1;
+
+    for time in 0..end {
+        for (i, first) in paths.iter().enumerate() {
+            for second in paths[i + 1..].iter() {
+                let (a, b) = (first.at(time), second.at(time));
+
+                assert_ne!(a, b, "collision at time {}", time);
+                assert!(
+                    !(a == second.at(time + 1) && b == first.at(time + 1)),
+                    "swap at time {}",
+                    time
+                );
+            }
+        }
+
+        for path in paths {
+            let (here, next) = (path.at(time).unwrap(), path.at(time + 1));
+            let here = grid.cube_of(here);
+            let next = grid.cube_of(next.unwrap());
+
+            assert!(here.dist(next) <= 1);
+        }
+    }
+}
+
+#[test]
+fn test_agents_cross_without_conflicts() {
+    let grid = HexGrid::new_radial(3, ());
+    let agents = [
+        (MultiCoord::axial(-3, 0), MultiCoord::axial(3, 0)),
+        (MultiCoord::axial(3, 0), MultiCoord::axial(-3, 0)),
+        (MultiCoord::axial(0, -3), MultiCoord::axial(0, 3)),
+        (MultiCoord::axial(0, 3), MultiCoord::axial(0, -3)),
+        (MultiCoord::axial(1, 0), MultiCoord::axial(-1, 0)),
+    ];
+    let paths: Vec<TimedPath> = grid
+        .plan_paths(&agents, |_, _| Some(1))
+        .into_iter()
+        .map(Option::unwrap)
+        .collect();
+
+    for (path, &(start, goal)) in paths.iter().zip(agents.iter()) {
+        assert_eq!(path.at(0), Some(start));
+        assert_eq!(path.at(path.arrival()), Some(goal));
+        assert_eq!(path.at(1000), Some(goal));
+    }
+
+    // The first agent goes straight across.
+    assert_eq!(paths[0].arrival(), 6);
+    assert_eq!(paths[0].cost, 6);
+    assert_no_conflicts(&grid, &paths);
+}
+
+#[test]
+fn test_shared_starts_are_planned_once() {
+    let grid = HexGrid::new_radial(2, ());
+    let start = MultiCoord::axial(0, 0);
+    let agents = [
+        (start, MultiCoord::axial(2, 0)),
+        (MultiCoord::axial(-2, 0), MultiCoord::axial(2, -2)),
+        (start, MultiCoord::axial(-2, 2)),
+    ];
+    let paths = grid.plan_paths(&agents, |_, _| Some(1));
+
+    assert!(paths[2].is_none());
+
+    let paths: Vec<TimedPath> =
+        paths.into_iter().take(2).map(Option::unwrap).collect();
+
+    assert_no_conflicts(&grid, &paths);
+}
+
+#[test]
+fn test_waiting_and_reservations() {
+    let grid = HexGrid::new_boxy(5, 1, ());
+    let mut planner = CooperativePlanner::new(&grid, |_, _| Some(2));
+
+    planner.wait_cost = 1;
+
+    // A hex blocked for a while in the only corridor forces a wait.
+    for time in 0..4 {
+        planner.reserve(MultiCoord::offset(2, 0), time);
+    }
+
+    assert!(planner.is_reserved(MultiCoord::offset(2, 0), 3));
+    assert!(!planner.is_reserved(MultiCoord::offset(2, 0), 4));
+
+    let path = planner
+        .plan(MultiCoord::offset(0, 0), MultiCoord::offset(4, 0))
+        .unwrap();
+
+    assert_eq!(path.arrival(), 6);
+    assert_eq!(path.cost, 10);
+    assert!(planner.is_reserved(MultiCoord::offset(4, 0), 500));
+
+    // Nobody can end where another agent already waits.
+    assert_eq!(
+        planner.plan(MultiCoord::offset(1, 0), MultiCoord::offset(4, 0)),
+        None
+    );
+
+    planner.clear();
+    planner.max_time = 3;
+    assert_eq!(
+        planner.plan(MultiCoord::offset(0, 0), MultiCoord::offset(4, 0)),
+        None
+    );
+}
+
+#[test]
+fn test_swaps_are_conflicts() {
+    let grid = HexGrid::new_boxy(2, 1, ());
+    let mut planner = CooperativePlanner::new(&grid, |_, _| Some(1));
+    let (west, east) = (MultiCoord::offset(0, 0), MultiCoord::offset(1, 0));
+
+    planner.reserve_path(&TimedPath {
+        coords: vec![east, west, west],
+        cost: 2,
+    });
+
+    // Moving east as the other agent moves west would swap them, and
+    // waiting runs into the other agent.
+    assert_eq!(planner.plan(west, east), None);
+}
@@ -1 +1,72 @@
-//! Integration tests for `chickenwire::coordinate`.s
+//! Integration tests for `chickenwire::coordinate`.
+
+use chickenwire::coordinate::{ParseCoordError, ParseCoordErrorKind};
+use chickenwire::prelude::*;
+
+#[test]
+fn test_display_round_trip() {
+    let coords = vec![
+        MultiCoord::axial(-4, 9),
+        MultiCoord::force_cube(7, -11, 4),
+        MultiCoord::force_double(-3, 5),
+        MultiCoord::offset(0, 12),
+    ];
+
+    for coord in coords {
+        assert_eq!(Ok(coord), coord.to_string().parse(), "{}", coord);
+    }
+
+    let wide: Cube<i64> = Cube::force_from_coords(1 << 40, 0, -(1 << 40));
+
+    assert_eq!(Ok(wide), wide.to_string().parse());
+}
+
+#[test]
+fn test_parse_width_and_constraint_errors() {
+    let err: ParseCoordError =
+        "axial(1,40000)".parse::<Axial<i16>>().unwrap_err();
+
+    assert_eq!(err.kind(), ParseCoordErrorKind::OutOfRange);
+    assert_eq!(err.position(), 8);
+
+    let err = "double( 1, 2)".parse::<MultiCoord>().unwrap_err();
+
+    assert_eq!(err.kind(), ParseCoordErrorKind::Constraint);
+    assert_eq!(err.position(), 6);
+
+    let err = "  axial(1,2)".parse::<Offset>().unwrap_err();
+
+    assert_eq!(
+        err.kind(),
+        ParseCoordErrorKind::WrongSystem(CoordSys::Axial)
+    );
+    assert_eq!(err.position(), 2);
+}
+
+#[test]
+fn test_saturated_axials_have_cubes() {
+    let (max, min) = (i32::MAX, i32::MIN);
+    let coords = [
+        Axial::from_coords(max, 0),
+        Axial::from_coords(1, max),
+        Axial::from_coords(min, min / 2),
+        Axial::from_coords(-3, 7),
+    ];
+    // Converting to a `Cube` panics if there's no equivalent.
+    let converts = |coord: Axial| {
+        let (x, y, z) = Cube::from(coord).to_tuple();
+
+        i64::from(x) + i64::from(y) + i64::from(z) == 0
+    };
+
+    for &a in coords.iter() {
+        for &b in coords.iter() {
+            assert!(converts(a.saturating_add(b)));
+            assert!(converts(a.saturating_sub(b)));
+        }
+
+        for &n in [max, min, -1, 2].iter() {
+            assert!(converts(a.saturating_mul(n)));
+        }
+    }
+}
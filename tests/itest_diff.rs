@@ -0,0 +1,109 @@
+//! Integration tests for `chickenwire::diff`.
+
+use chickenwire::diff::HexDiff;
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+#[test]
+fn test_diff_lists_each_kind_of_change() {
+    let before = HexGrid::new_radial(2, 0);
+    let mut after = HexGrid::new_radial(2, 0);
+
+    after.set(MultiCoord::axial(0, 0), 1);
+    after.set(MultiCoord::axial(1, -1), 0);
+    after.set(MultiCoord::axial(3, 0), 2);
+    after.set(MultiCoord::axial(-3, 0), 3);
+    after.remove(MultiCoord::axial(0, 1));
+
+    let diff = before.diff(&after);
+    let origin = Cube::force_from_coords(0, 0, 0);
+
+    assert_eq!(
+        diff.added,
+        vec![
+            (Cube::force_from_coords(-3, 3, 0), 3),
+            (Cube::force_from_coords(3, -3, 0), 2),
+        ]
+    );
+    assert_eq!(diff.removed, vec![(Cube::force_from_coords(0, -1, 1), 0)]);
+    assert_eq!(diff.changed, vec![(origin, 0, 1)]);
+    assert_eq!(after.diff(&before), diff.inverse());
+}
+
+#[test]
+fn test_diff_ignores_layout() {
+    let before = HexGrid::new_boxy(4, 4, 'x');
+    let mut after = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Double);
+
+    for (coord, &value) in before.iter() {
+        after.set(before.convert(coord, CoordSys::Cube), value);
+    }
+
+    assert!(before.diff(&after).is_empty());
+}
+
+#[test]
+fn test_apply_is_all_or_nothing() {
+    let mut grid = HexGrid::new_radial(1, 0);
+    let diff = HexDiff {
+        added: vec![(Cube::force_from_coords(5, -5, 0), 1)],
+        removed: vec![(Cube::force_from_coords(0, 0, 0), 0)],
+        changed: vec![(Cube::force_from_coords(9, -9, 0), 0, 1)],
+    };
+
+    assert_eq!(
+        grid.apply(&diff).unwrap_err(),
+        "Grid contains no value at cube(9,-9,0)"
+    );
+    assert!(grid.diff(&HexGrid::new_radial(1, 0)).is_empty());
+
+    let diff = HexDiff {
+        added: vec![(Cube::force_from_coords(0, 0, 0), 1)],
+        ..HexDiff::default()
+    };
+
+    assert_eq!(
+        grid.apply(&diff).unwrap_err(),
+        "Grid already contains a value at cube(0,0,0)"
+    );
+}
+
+#[test]
+fn test_take_changes() {
+    let mut grid = HexGrid::new_boxy(3, 3, 0);
+
+    grid.set(MultiCoord::offset(0, 0), 1);
+    assert!(!grid.is_tracking_changes());
+    assert!(grid.take_changes().is_empty());
+
+    grid.track_changes(true);
+    grid.set(MultiCoord::offset(2, 2), 1);
+    grid.set(MultiCoord::offset(2, 2), 2);
+    grid.update(MultiCoord::offset(1, 0), 3).unwrap();
+    grid.add(MultiCoord::offset(5, 5), 4).unwrap();
+    grid.remove(MultiCoord::offset(0, 1));
+    grid.get(MultiCoord::offset(0, 2));
+    grid.get_mut(MultiCoord::offset(1, 1));
+    grid.get_mut(MultiCoord::offset(7, 7));
+
+    let mut changes = grid.take_changes();
+
+    changes.sort_by_key(|&coord| Offset::from(coord));
+
+    assert_eq!(
+        changes,
+        vec![
+            MultiCoord::offset(0, 1),
+            MultiCoord::offset(1, 0),
+            MultiCoord::offset(1, 1),
+            MultiCoord::offset(2, 2),
+            MultiCoord::offset(5, 5),
+        ]
+    );
+    assert!(grid.take_changes().is_empty());
+
+    grid.set(MultiCoord::offset(0, 0), 5);
+    grid.track_changes(false);
+    grid.track_changes(true);
+    assert!(grid.take_changes().is_empty());
+}
@@ -0,0 +1,112 @@
+//! Integration tests for `chickenwire::entry` and `HexGrid` indexing.
+
+use std::sync::{Arc, Mutex};
+
+use chickenwire::entry::Entry;
+use chickenwire::events::GridEvent;
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+#[test]
+fn test_entry_inserts_and_modifies() {
+    let mut grid: HexGrid<Vec<i32>> = HexGrid::default();
+    let origin = MultiCoord::axial(0, 0);
+
+    grid.entry(origin).or_default().push(1);
+    grid.entry(origin).or_insert_with(|| vec![9]).push(2);
+    grid.entry(origin).and_modify(|list| list.push(3)).or_default();
+
+    let coord = MultiCoord::axial(2, -1);
+    let list = grid
+        .entry(coord)
+        .or_insert_with_coord(|coord| vec![Axial::from(coord).q]);
+
+    assert_eq!(*list, vec![2]);
+    assert_eq!(grid[origin], vec![1, 2, 3]);
+}
+
+#[test]
+fn test_entry_variants() {
+    let mut grid = HexGrid::new_radial(1, 'a');
+
+    match grid.entry(MultiCoord::axial(1, 0)) {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(*entry.get(), 'a');
+            assert_eq!(entry.insert('b'), 'a');
+            assert_eq!(entry.remove(), 'b');
+        }
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+
+    match grid.entry(MultiCoord::axial(1, 0)) {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.coord(), MultiCoord::axial(1, 0));
+            *entry.insert('c') = 'd';
+        }
+        Entry::Occupied(_) => panic!("expected a vacant entry"),
+    }
+
+    assert_eq!(grid[MultiCoord::axial(1, 0)], 'd');
+    assert_eq!(grid.iter().count(), 7);
+}
+
+#[test]
+fn test_entry_changes_are_observed() {
+    let mut grid = HexGrid::new_radial(1, 0);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&log);
+
+    grid.subscribe(move |event: &GridEvent<i32>| {
+        let kind = match event {
+            GridEvent::CellAdded { .. } => "added",
+            GridEvent::CellChanged { .. } => "changed",
+            GridEvent::CellRemoved { .. } => "removed",
+        };
+
+        sink.lock().unwrap().push(kind);
+    });
+    grid.track_changes(true);
+
+    *grid.entry(MultiCoord::axial(0, 0)).or_insert(5) += 1;
+    grid.entry(MultiCoord::axial(3, 0)).or_insert(5);
+
+    if let Entry::Occupied(mut entry) = grid.entry(MultiCoord::axial(1, 0)) {
+        entry.insert(2);
+    }
+
+    if let Entry::Occupied(entry) = grid.entry(MultiCoord::axial(0, 1)) {
+        entry.remove();
+    }
+
+    assert_eq!(*log.lock().unwrap(), vec!["added", "changed", "removed"]);
+    assert_eq!(grid.take_changes().len(), 4);
+}
+
+#[test]
+fn test_index_by_each_coordinate_type() {
+    let mut grid = HexGrid::new_boxy(4, 4, 0);
+
+    grid.tilt = Tilt::Sharp;
+    grid.parity = Parity::Odd;
+
+    let offset = Offset::from_coords(1, 1);
+    let coord = MultiCoord::from(offset);
+    let cube = grid.cube_of(coord);
+    let axial = Axial::from(grid.convert(coord, CoordSys::Axial));
+    let double = Double::from(grid.convert(coord, CoordSys::Double));
+
+    grid[offset] += 1;
+    grid[cube] += 10;
+    grid[axial] += 100;
+    grid[double] += 1000;
+
+    assert_eq!(grid[MultiCoord::from(offset)], 1111);
+}
+
+#[test]
+#[should_panic(expected = "Grid contains no value at axial(7,7)")]
+fn test_index_panics_on_vacant_hex() {
+    let grid = HexGrid::new_radial(1, 0);
+
+    let _ = grid[MultiCoord::axial(7, 7)];
+}
@@ -0,0 +1,116 @@
+//! Integration tests for `chickenwire::events`.
+
+use std::sync::{Arc, Mutex};
+
+use chickenwire::events::GridEvent;
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::history::HistoryHexGrid;
+use chickenwire::prelude::*;
+
+type Log = Arc<Mutex<Vec<(char, MultiCoord, i32)>>>;
+
+/// Subscribes a listener logging each event's kind, coordinate, and value.
+fn record(grid: &mut HexGrid<i32>) -> Log {
+    let log = Log::default();
+    let sink = Arc::clone(&log);
+
+    grid.subscribe(move |event| {
+        let entry = match *event {
+            GridEvent::CellAdded { coord, value } => ('+', coord, *value),
+            GridEvent::CellChanged { coord, new, .. } => ('~', coord, *new),
+            GridEvent::CellRemoved { coord, value } => ('-', coord, *value),
+        };
+
+        sink.lock().unwrap().push(entry);
+    });
+
+    log
+}
+
+#[test]
+fn test_events_use_grid_sys() {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+    let log = record(&mut grid);
+
+    grid.set(MultiCoord::force_cube(0, -1, 1), 1);
+    grid.update(MultiCoord::offset(0, 1), 2).unwrap();
+    assert!(grid.update(MultiCoord::offset(5, 5), 2).is_err());
+    assert!(grid.add(MultiCoord::offset(0, 1), 3).is_err());
+    grid.remove(MultiCoord::axial(0, 1));
+    grid.remove(MultiCoord::axial(0, 1));
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            ('+', MultiCoord::offset(0, 1), 1),
+            ('~', MultiCoord::offset(0, 1), 2),
+            ('-', MultiCoord::offset(0, 1), 2),
+        ]
+    );
+}
+
+#[test]
+fn test_listeners_see_diffs_and_undo() {
+    let mut grid = HexGrid::new_radial(1, 0);
+    let log = record(&mut grid);
+    let mut target = HexGrid::new_radial(1, 0);
+
+    target.set(MultiCoord::axial(0, 0), 5);
+    target.remove(MultiCoord::axial(1, 0));
+    grid.apply(&grid.diff(&target)).unwrap();
+    assert_eq!(log.lock().unwrap().len(), 2);
+
+    let mut history = HistoryHexGrid::new(grid, 10);
+
+    history.set(MultiCoord::axial(2, 0), 7);
+    history.undo();
+
+    let log = log.lock().unwrap();
+
+    assert_eq!(log.len(), 4);
+    assert_eq!(log[3], ('-', MultiCoord::axial(2, 0), 7));
+}
+
+#[test]
+fn test_unsubscribe() {
+    let mut grid = HexGrid::new_radial(1, 0);
+    let first = record(&mut grid);
+    let id = grid.subscribe(|_| ());
+    let second = record(&mut grid);
+
+    assert!(grid.unsubscribe(id));
+    assert!(!grid.unsubscribe(id));
+
+    grid.set(MultiCoord::axial(0, 0), 1);
+
+    assert_eq!(first.lock().unwrap().len(), 1);
+    assert_eq!(second.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_fill_region_reports_each_hex_once() {
+    let mut grid = HexGrid::new_radial(1, 0);
+    let log = record(&mut grid);
+    let (east, origin) = (MultiCoord::axial(2, 0), MultiCoord::axial(0, 0));
+
+    grid.fill_region(vec![east, origin, east, origin], 4);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![('~', origin, 4), ('+', east, 4)]
+    );
+}
+
+#[test]
+fn test_grids_stay_thread_safe() {
+    fn assert_send_sync<S: Send + Sync>(_: &S) {}
+
+    let mut grid = HexGrid::new_radial(1, 0);
+    let calls = std::cell::Cell::new(0);
+
+    // A `Cell` is `Send` but not `Sync`.
+    grid.subscribe(move |_| calls.set(calls.get() + 1));
+    grid.set(MultiCoord::axial(0, 0), 1);
+
+    assert_send_sync(&grid);
+}
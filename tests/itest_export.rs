@@ -0,0 +1,83 @@
+//! Integration tests for `chickenwire::export`.
+
+use chickenwire::hexgrid::Tilt;
+use chickenwire::prelude::*;
+
+fn edges(dot: &str) -> Vec<&str> {
+    dot.lines().filter(|line| line.contains(" -> ")).collect()
+}
+
+#[test]
+fn test_links_are_symmetric() {
+    for &tilt in [Tilt::Flat, Tilt::Sharp].iter() {
+        let mut grid = HexGrid::new_radial(2, 0);
+
+        grid.tilt = tilt;
+        grid.set(MultiCoord::axial(0, 0), 1);
+        grid.set(MultiCoord::axial(0, 0), 2);
+        grid.remove(MultiCoord::axial(1, 0));
+
+        let dot = grid.to_dot(1.0, |value| value.to_string());
+        let edges = edges(&dot);
+
+        // 19 hexes have 42 neighboring pairs, and the removed hex had 6.
+        assert_eq!(edges.len(), 2 * (42 - 6), "{:?}", tilt);
+
+        for edge in edges.iter() {
+            let parts: Vec<&str> = edge.split_whitespace().collect();
+            let reverse = format!("{} -> {}", parts[2], parts[0]);
+
+            assert!(
+                edges.iter().any(|other| other.contains(&reverse)),
+                "{}",
+                edge
+            );
+        }
+    }
+}
+
+#[test]
+fn test_link_directions_follow_tilt() {
+    let mut grid = HexGrid::new_radial(1, 0);
+
+    let flat = grid.to_graphml(1.0, |value| value.to_string());
+
+    grid = HexGrid::default();
+    grid.tilt = Tilt::Sharp;
+    for coord in Cube::<i32>::ORIGIN.spiral(1) {
+        grid.set(MultiCoord::from(coord), 0);
+    }
+
+    let sharp = grid.to_graphml(1.0, |value| value.to_string());
+
+    for &(direction, in_flat, in_sharp) in [
+        ("North", true, false),
+        ("South", true, false),
+        ("East", false, true),
+        ("West", false, true),
+        ("Northeast", true, true),
+    ]
+    .iter()
+    {
+        let data = format!("<data key=\"direction\">{}</data>", direction);
+
+        assert_eq!(flat.contains(&data), in_flat, "{}", direction);
+        assert_eq!(sharp.contains(&data), in_sharp, "{}", direction);
+    }
+}
+
+#[test]
+fn test_link_directions_follow_retilting() {
+    let mut grid = HexGrid::new_radial(1, 0);
+
+    grid.tilt = Tilt::Sharp;
+
+    let dot = grid.to_dot(1.0, |value| value.to_string());
+    let graphml = grid.to_graphml(1.0, |value| value.to_string());
+
+    assert!(!dot.contains("label=\"North\""));
+    assert!(!dot.contains("label=\"South\""));
+    assert!(dot.contains("label=\"East\""));
+    assert!(!graphml.contains("<data key=\"direction\">North</data>"));
+    assert!(graphml.contains("<data key=\"direction\">West</data>"));
+}
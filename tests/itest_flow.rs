@@ -0,0 +1,121 @@
+//! Integration tests for `chickenwire::flow`.
+
+use chickenwire::flow::FlowField;
+use chickenwire::hexgrid::{Compass, Parity, Tilt};
+use chickenwire::prelude::*;
+
+/// A `Tilt::Sharp` grid of 12 by 10 hexes with costs from 0 to 4, where 0
+/// is impassable.
+fn terrain() -> HexGrid<u32> {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Even, CoordSys::Offset);
+
+    for col in 0..12 {
+        for row in 0..10 {
+            let cost = ((col * 7 + row * 13) % 5) as u32;
+
+            grid.set(MultiCoord::offset(col, row), cost);
+        }
+    }
+
+    grid
+}
+
+fn passable(_: MultiCoord, &cost: &u32) -> Option<u32> {
+    if cost == 0 {
+        None
+    } else {
+        Some(cost)
+    }
+}
+
+/// Asserts that two fields hold the same distances.
+fn assert_same(field: &FlowField, other: &FlowField) {
+    let (ours, theirs) = (field.distances(), other.distances());
+
+    assert_eq!(ours.iter().count(), theirs.iter().count());
+    assert!(ours.diff(&theirs).is_empty());
+}
+
+#[test]
+fn test_flow_field_steps_downhill() {
+    let grid = terrain();
+    let goals = [MultiCoord::offset(1, 1), MultiCoord::offset(10, 8)];
+    let field = grid.flow_field(&goals, passable);
+
+    for (coord, &cost) in grid.iter() {
+        let dist = match field.distance(coord) {
+            Some(dist) => dist,
+            None => continue,
+        };
+
+        if goals.contains(&coord) {
+            assert_eq!(dist, 0);
+            assert_eq!(field.next(coord), None);
+            continue;
+        }
+
+        let next = field.next(coord).unwrap();
+        let dir = field.direction(coord).unwrap();
+        let cube = grid.cube_of(coord);
+        let side = (0..6)
+            .find(|&side| Compass::from_neighbor(Tilt::Sharp, side) == dir)
+            .unwrap();
+
+        assert_eq!(dist, field.distance(next).unwrap() + i64::from(cost));
+        let step = MultiCoord::from(cube.neighbor(side));
+
+        assert_eq!(grid.convert(step, CoordSys::Offset), next);
+    }
+
+    assert_eq!(field.distance(MultiCoord::offset(0, 0)), None);
+    assert_eq!(field.distance(MultiCoord::offset(40, 0)), None);
+}
+
+#[test]
+fn test_set_cost_matches_rebuild() {
+    let mut grid = terrain();
+    let goals = [MultiCoord::offset(5, 4)];
+    let mut field = grid.flow_field(&goals, passable);
+    let changes = [
+        ((6, 4), 0),
+        ((3, 3), 4),
+        ((5, 6), 1),
+        ((8, 2), 0),
+        ((6, 4), 1),
+        ((2, 7), 3),
+        ((5, 4), 0),
+        ((9, 9), 2),
+        ((7, 5), 4),
+    ];
+
+    for &((col, row), cost) in changes.iter() {
+        let coord = MultiCoord::offset(col, row);
+
+        grid.set(coord, cost);
+        field.set_cost(coord, passable(coord, &cost));
+        assert_same(&field, &grid.flow_field(&goals, passable));
+    }
+}
+
+#[test]
+fn test_dijkstra_map_weights_and_fleeing() {
+    let grid = HexGrid::new_radial(5, ());
+    let goals = [
+        (MultiCoord::axial(-4, 0), 0),
+        (MultiCoord::axial(4, 0), -3),
+        (MultiCoord::axial(4, 0), 2),
+    ];
+    let field = grid.dijkstra_map(&goals, |_, _| Some(1));
+
+    assert_eq!(field.distance(MultiCoord::axial(4, 0)), Some(-3));
+    assert_eq!(field.distance(MultiCoord::axial(0, 0)), Some(1));
+    assert_eq!(field.distance(MultiCoord::axial(-2, 0)), Some(2));
+
+    let flee = field.flee(1.2);
+    let center = MultiCoord::axial(0, 0);
+    let next = flee.next(center).unwrap();
+
+    // Fleeing from the center leads away from the nearer goal.
+    assert!(flee.distance(next) < flee.distance(center));
+    assert!(field.distance(next) > field.distance(center));
+}
@@ -0,0 +1,116 @@
+//! Integration tests for `chickenwire::generate`.
+
+use chickenwire::generate::{biomes, Bands, Fractal, Noise, NoiseKind};
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+const KINDS: [NoiseKind; 3] =
+    [NoiseKind::Value, NoiseKind::Perlin, NoiseKind::Simplex];
+
+#[test]
+fn test_noise_is_seeded_and_bounded() {
+    for &kind in KINDS.iter() {
+        let noise = Noise::new(kind, 1234);
+        let other = Noise::new(kind, 4321);
+        let points: Vec<(f64, f64)> = (0..400)
+            .map(|n| (f64::from(n) * 0.37 - 50.0, f64::from(n) * -0.21))
+            .collect();
+
+        let sample = |noise: &Noise| -> Vec<f64> {
+            points.iter().map(|&p| noise.sample(p)).collect()
+        };
+        let samples = sample(&noise);
+        let again = sample(&Noise::new(kind, 1234));
+        let others = sample(&other);
+
+        assert_eq!(noise.seed(), 1234);
+        assert_eq!(samples, again);
+        assert_ne!(samples, others);
+        assert!(samples.iter().all(|x| (-1.0..=1.0).contains(x)));
+        assert!(samples.iter().any(|&x| x > 0.1));
+        assert!(samples.iter().any(|&x| x < -0.1));
+    }
+}
+
+#[test]
+fn test_noise_is_continuous() {
+    for &kind in KINDS.iter() {
+        let noise = Noise::new(kind, 9);
+
+        for n in 0..200 {
+            let x = f64::from(n) * 0.173;
+            let step = noise.sample((x, x * 0.5))
+                - noise.sample((x + 0.001, x * 0.5));
+
+            assert!(step.abs() < 0.05, "{:?} jumps at {}", kind, x);
+        }
+    }
+}
+
+#[test]
+fn test_fractal_octaves() {
+    let noise = Noise::new(NoiseKind::Perlin, 3);
+    let single = Fractal {
+        octaves: 1,
+        frequency: 0.5,
+        ..Fractal::default()
+    };
+    let none = Fractal {
+        octaves: 0,
+        ..Fractal::default()
+    };
+
+    assert_eq!(
+        noise.fractal(&single, (3.3, 1.9)),
+        noise.sample((1.65, 0.95))
+    );
+    assert_eq!(noise.fractal(&none, (3.3, 1.9)), 0.0);
+}
+
+#[test]
+fn test_heightmap_follows_shape_and_tilt() {
+    let mut shape = HexGrid::new(Tilt::Sharp, Parity::Even, CoordSys::Axial);
+
+    shape.fill_region(
+        Cube::<i32>::ORIGIN.spiral(3).into_iter().map(MultiCoord::from),
+        (),
+    );
+
+    let noise = Noise::new(NoiseKind::Simplex, 5);
+    let fractal = Fractal::default();
+    let heights = noise.heightmap(&shape, &fractal);
+    let coord = MultiCoord::axial(2, -1);
+    let cube = shape.cube_of(coord);
+    let center = chickenwire::geometry::center(Tilt::Sharp, 1.0, cube);
+
+    assert_eq!(heights.iter().count(), 37);
+    assert_eq!(heights.sys, CoordSys::Axial);
+    assert_eq!(heights[coord], noise.fractal(&fractal, center));
+}
+
+#[test]
+fn test_bands_and_biomes() {
+    let bands = Bands::new(3).below(0.5, 2).below(-0.5, 0).below(0.0, 1);
+
+    assert_eq!(*bands.get(-0.7), 0);
+    assert_eq!(*bands.get(-0.5), 1);
+    assert_eq!(*bands.get(0.2), 2);
+    assert_eq!(*bands.get(0.5), 3);
+
+    let elevation = HexGrid::new_radial(2, 0.2);
+    let mut moisture = HexGrid::new_radial(1, 0.9);
+
+    moisture.set(MultiCoord::axial(0, 0), -0.9);
+
+    let table = Bands::new(Bands::new("snow"))
+        .below(0.5, Bands::new("forest").below(0.0, "desert"));
+    let world = biomes(&elevation, &moisture, &table);
+
+    assert_eq!(world.iter().count(), 7);
+    assert_eq!(world[MultiCoord::axial(0, 0)], "desert");
+    assert_eq!(world[MultiCoord::axial(1, 0)], "forest");
+    assert_eq!(
+        elevation.classify(&bands).iter().filter(|&(_, &b)| b == 2).count(),
+        19
+    );
+}
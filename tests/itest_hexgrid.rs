@@ -1,5 +1,6 @@
 //! Integration tests for `chickenwire::hexgrid`.
 
+use chickenwire::hexgrid::{Parity, Tilt};
 use chickenwire::prelude::*;
 
 #[test]
@@ -9,3 +10,171 @@ fn test_radial() {
     assert_eq!(Some(&0), grid.get(MultiCoord::axial(0, 0)));
     assert_eq!(Some(&0), grid.get(MultiCoord::axial(1, 0)));
 }
+
+#[test]
+fn test_ascii_round_trip() {
+    let layouts = [
+        (Tilt::Flat, Parity::Odd),
+        (Tilt::Flat, Parity::Even),
+        (Tilt::Sharp, Parity::Odd),
+        (Tilt::Sharp, Parity::Even),
+    ];
+
+    for &(tilt, parity) in layouts.iter() {
+        let mut grid = HexGrid::new_radial(3, 0);
+
+        grid.tilt = tilt;
+        grid.parity = parity;
+        grid.set(MultiCoord::axial(1, -1), 1);
+        grid.set(MultiCoord::axial(-2, 2), 2);
+
+        let text = grid.to_ascii(|&value| {
+            std::char::from_digit(value, 10).unwrap()
+        });
+        let parsed =
+            HexGrid::from_ascii(&text, tilt, parity, |c| c.to_digit(10))
+                .unwrap();
+
+        let reprinted = parsed.to_ascii(|&value| {
+            std::char::from_digit(value, 10).unwrap()
+        });
+
+        assert_eq!(reprinted, text);
+        assert_eq!(parsed.iter().count(), 37, "{:?} {:?}", tilt, parity);
+
+        // Parsing shifts the grid, but keeps distances between hexes.
+        let find = |target| {
+            let (coord, _) =
+                parsed.iter().find(|&(_, &value)| value == target).unwrap();
+
+            parsed.cube_of(coord)
+        };
+
+        assert_eq!(find(1).dist(find(2)), 3, "{}", text);
+    }
+}
+
+#[test]
+fn test_ascii_fixture() {
+    let grid = HexGrid::from_ascii(
+        "
+        a . b
+         c d
+        ",
+        Tilt::Sharp,
+        Parity::Odd,
+        |c| if c == '.' { None } else { Some(c) },
+    )
+    .unwrap();
+
+    assert_eq!(grid.sys, CoordSys::Offset);
+    assert_eq!(grid.get(MultiCoord::offset(2, 0)), Some(&'b'));
+    assert_eq!(grid.get(MultiCoord::offset(0, 1)), Some(&'c'));
+    assert_eq!(grid.get(MultiCoord::offset(1, 0)), None);
+    assert_eq!(grid.iter().count(), 4);
+
+    let err = HexGrid::from_ascii(
+        "a b\n c d\nx y z!",
+        Tilt::Sharp,
+        Parity::Odd,
+        Some,
+    )
+    .unwrap_err();
+
+    assert_eq!(err, "Unexpected '!' between hexes at line 3, column 6");
+}
+
+/// Returns the number of links in a grid's graph.
+fn link_count<T>(grid: &HexGrid<T>) -> usize {
+    grid.to_dot(1.0, |_| String::new()).matches(" -> ").count()
+}
+
+#[test]
+fn test_fill_region_links_like_set() {
+    let mut filled = HexGrid::new_radial(1, 0);
+    let mut set = HexGrid::new_radial(1, 0);
+    let region: Vec<MultiCoord> = Cube::<i32>::ORIGIN
+        .spiral(3)
+        .into_iter()
+        .map(MultiCoord::from)
+        .collect();
+
+    filled.fill_region(region.iter().cloned(), 1);
+    for &coord in region.iter() {
+        set.set(coord, 1);
+    }
+
+    // 37 hexes have 90 neighboring pairs.
+    assert_eq!(link_count(&filled), 180);
+    assert_eq!(
+        filled.to_dot(1.0, |v| v.to_string()),
+        set.to_dot(1.0, |v| v.to_string())
+    );
+}
+
+#[test]
+fn test_map_filter_and_zip_keep_layout_and_links() {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+    let offsets = (0..4).flat_map(|col| {
+        (0..3).map(move |row| MultiCoord::offset(col, row))
+    });
+
+    grid.fill_region(offsets, 2);
+
+    let mapped = grid.map(|coord, &value| {
+        let Offset { col, row } = Offset::from(coord);
+
+        value * col + row
+    });
+
+    assert_eq!((mapped.tilt, mapped.parity), (Tilt::Sharp, Parity::Odd));
+    assert_eq!(mapped.sys, CoordSys::Offset);
+    assert_eq!(mapped[MultiCoord::offset(3, 2)], 8);
+    assert_eq!(link_count(&mapped), link_count(&grid));
+
+    let evens = mapped.filter(|_, &value| value % 2 == 0);
+
+    assert_eq!(evens.iter().count(), 8);
+    assert!(evens.iter().all(|(_, &value)| value % 2 == 0));
+    assert_eq!(link_count(&evens), link_count(&grid.filter(|coord, _| {
+        evens.contains_coord(coord)
+    })));
+
+    let mut other = HexGrid::new(Tilt::Flat, Parity::Even, CoordSys::Cube);
+
+    for (coord, _) in grid.iter().take(5) {
+        other.set(grid.convert(coord, CoordSys::Cube), 'x');
+    }
+
+    let zipped =
+        grid.zip_with(&other, |_, &value, &c| format!("{}{}", c, value));
+
+    assert_eq!(zipped.iter().count(), 5);
+    assert!(zipped.iter().all(|(_, text)| text == "x2"));
+}
+
+#[test]
+fn test_retain_and_drain() {
+    let mut grid = HexGrid::new_radial(2, 1);
+
+    grid.track_changes(true);
+    grid.retain(|coord, _| Axial::from(coord).q >= 0);
+
+    assert_eq!(grid.iter().count(), 12);
+    assert!(grid.iter().all(|(_, &value)| value == 1));
+    assert_eq!(grid.take_changes().len(), 7);
+    // 12 hexes with q of 0 to 2 have 23 neighboring pairs.
+    assert_eq!(link_count(&grid), 46);
+
+    let mut drained: Vec<(MultiCoord, i32)> = grid.drain().collect();
+
+    drained.sort_by_key(|&(coord, _)| Cube::from(coord).to_tuple());
+    assert_eq!(drained.len(), 12);
+    assert_eq!(drained[0].1, 1);
+    assert_eq!(grid.iter().count(), 0);
+    assert_eq!(link_count(&grid), 0);
+
+    grid.set(MultiCoord::axial(0, 0), 3);
+    grid.set(MultiCoord::axial(1, 0), 3);
+    assert_eq!(link_count(&grid), 2);
+}
@@ -0,0 +1,99 @@
+//! Integration tests for `chickenwire::history`.
+
+use chickenwire::history::HistoryHexGrid;
+use chickenwire::prelude::*;
+
+#[test]
+fn test_undo_and_redo_restore_every_state() {
+    let mut grid = HistoryHexGrid::new(HexGrid::new_boxy(3, 3, 0), 10);
+    let mut states = Vec::new();
+
+    grid.set(MultiCoord::offset(0, 0), 1);
+    grid.add(MultiCoord::offset(4, 4), 2).unwrap();
+    grid.update(MultiCoord::offset(1, 1), 3).unwrap();
+    grid.remove(MultiCoord::offset(2, 2));
+
+    // Failed and empty edits aren't recorded.
+    assert!(grid.add(MultiCoord::offset(0, 0), 9).is_err());
+    assert!(grid.update(MultiCoord::offset(8, 8), 9).is_err());
+    assert_eq!(grid.remove(MultiCoord::offset(8, 8)), None);
+
+    let end = HexGrid::new_boxy(3, 3, 0).diff(grid.grid());
+
+    for _ in 0..4 {
+        assert!(grid.undo());
+        states.push(HexGrid::new_boxy(3, 3, 0).diff(grid.grid()));
+    }
+
+    assert!(!grid.undo());
+    assert_eq!(
+        states.iter().map(|diff| diff.len()).collect::<Vec<_>>(),
+        vec![3, 2, 1, 0]
+    );
+
+    while grid.redo() {}
+
+    assert_eq!(HexGrid::new_boxy(3, 3, 0).diff(grid.grid()), end);
+}
+
+#[test]
+fn test_transactions_group_edits() {
+    let mut grid = HistoryHexGrid::new(HexGrid::new_radial(1, 'a'), 10);
+
+    grid.begin();
+    grid.set(MultiCoord::axial(0, 0), 'b');
+    grid.begin();
+    grid.set(MultiCoord::axial(0, 0), 'c');
+    grid.remove(MultiCoord::axial(1, 0));
+    grid.commit();
+    assert!(grid.in_transaction());
+    grid.set(MultiCoord::axial(1, 0), 'd');
+    grid.commit();
+    assert!(!grid.in_transaction());
+
+    assert!(grid.undo());
+    assert!(!grid.can_undo());
+    assert_eq!(grid.get(MultiCoord::axial(0, 0)), Some(&'a'));
+    assert_eq!(grid.get(MultiCoord::axial(1, 0)), Some(&'a'));
+
+    assert!(grid.redo());
+    assert_eq!(grid.get(MultiCoord::axial(0, 0)), Some(&'c'));
+    assert_eq!(grid.get(MultiCoord::axial(1, 0)), Some(&'d'));
+}
+
+#[test]
+fn test_new_edits_discard_redo() {
+    let mut grid = HistoryHexGrid::new(HexGrid::new_radial(1, 0), 10);
+
+    grid.set(MultiCoord::axial(0, 0), 1);
+    grid.undo();
+    assert!(grid.can_redo());
+
+    grid.set(MultiCoord::axial(0, 0), 2);
+    assert!(!grid.can_redo());
+    assert!(!grid.redo());
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let mut grid = HistoryHexGrid::new(HexGrid::new_radial(1, 0), 3);
+
+    for value in 1..=5 {
+        grid.set(MultiCoord::axial(0, 0), value);
+    }
+
+    let mut undone = 0;
+
+    while grid.undo() {
+        undone += 1;
+    }
+
+    assert_eq!(undone, 3);
+    assert_eq!(grid.get(MultiCoord::axial(0, 0)), Some(&2));
+
+    grid.set_limit(1);
+    assert!(grid.redo());
+    assert!(grid.redo());
+    assert!(grid.undo());
+    assert!(!grid.undo());
+}
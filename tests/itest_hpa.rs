@@ -0,0 +1,188 @@
+//! Integration tests for `chickenwire::hpa`.
+
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::hpa::HpaHexGrid;
+use chickenwire::pathfind::Path;
+use chickenwire::prelude::*;
+
+fn cost(_: MultiCoord, &hex: &u32) -> Option<u32> {
+    if hex == 0 {
+        None
+    } else {
+        Some(hex)
+    }
+}
+
+/// A `Tilt::Sharp` grid of 30 by 24 hexes with walls and rough ground.
+fn terrain() -> HexGrid<u32> {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Even, CoordSys::Offset);
+
+    for col in 0..30 {
+        for row in 0..24 {
+            let wall = (col == 10 && row != 20) || (row == 12 && col > 14);
+            let value = if wall { 0 } else { 1 + ((col * row) % 3) as u32 };
+
+            grid.set(MultiCoord::offset(col, row), value);
+        }
+    }
+
+    grid
+}
+
+/// Asserts that a path is connected, passable, and costs what it claims.
+fn assert_valid(grid: &HexGrid<u32>, path: &Path) {
+    let mut total = 0;
+
+    for pair in path.coords.windows(2) {
+        let from = grid.cube_of(pair[0]);
+        let to = grid.cube_of(pair[1]);
+
+        assert_eq!(from.dist(to), 1);
+        total += u64::from(cost(pair[1], &grid[pair[1]]).unwrap());
+    }
+
+    assert_eq!(total, path.cost);
+}
+
+#[test]
+fn test_paths_are_valid_and_near_optimal() {
+    let grid = terrain();
+    let hpa = HpaHexGrid::new(terrain(), 6, cost);
+    let queries = [
+        ((0, 0), (29, 23)),
+        ((2, 18), (25, 5)),
+        ((12, 1), (12, 2)),
+        ((3, 3), (4, 4)),
+        ((28, 11), (28, 13)),
+    ];
+
+    assert_eq!(hpa.cluster_size(), 6);
+    assert_eq!(hpa.cluster_count(), 20);
+    assert!(hpa.entrance_count() > 0);
+
+    for &((c1, r1), (c2, r2)) in queries.iter() {
+        let start = MultiCoord::offset(c1, r1);
+        let goal = MultiCoord::offset(c2, r2);
+        let path = hpa.find_path(start, goal).unwrap();
+        let best = grid.find_path(start, goal, cost).unwrap();
+
+        assert_eq!(path.coords.first(), Some(&start));
+        assert_eq!(path.coords.last(), Some(&goal));
+        assert_valid(&grid, &path);
+        assert!(path.cost >= best.cost);
+        assert!(path.cost * 2 <= best.cost * 3, "{:?} too costly", goal);
+    }
+
+    assert_eq!(
+        hpa.find_path(MultiCoord::offset(0, 0), MultiCoord::offset(10, 0)),
+        None
+    );
+    assert_eq!(
+        hpa.find_path(MultiCoord::offset(0, 0), MultiCoord::offset(50, 0)),
+        None
+    );
+}
+
+#[test]
+fn test_repairs_match_rebuild() {
+    let mut hpa = HpaHexGrid::new(terrain(), 5, cost);
+    let start = MultiCoord::offset(1, 2);
+    let goal = MultiCoord::offset(27, 22);
+    let edits = [
+        ((10, 20), Some(0)),
+        ((10, 4), Some(1)),
+        ((20, 12), None),
+        ((22, 12), Some(2)),
+        ((0, 0), None),
+        ((10, 4), Some(0)),
+        ((10, 20), Some(3)),
+    ];
+
+    for &((col, row), value) in edits.iter() {
+        let coord = MultiCoord::offset(col, row);
+
+        match value {
+            Some(value) => hpa.set(coord, value),
+            None => {
+                hpa.remove(coord);
+            }
+        }
+
+        let fresh = HpaHexGrid::new(hpa.grid().map(|_, &hex| hex), 5, cost);
+
+        assert_eq!(hpa.entrance_count(), fresh.entrance_count());
+        assert_eq!(hpa.find_path(start, goal), fresh.find_path(start, goal));
+
+        if let Some(path) = hpa.find_path(start, goal) {
+            assert_valid(hpa.grid(), &path);
+        }
+    }
+
+    assert_eq!(hpa.get(MultiCoord::offset(0, 0)), None);
+    assert_eq!(hpa.remove(MultiCoord::offset(0, 0)), None);
+    assert_eq!(hpa.into_grid().iter().count(), 30 * 24 - 2);
+}
+
+/// Returns the next value of a xorshift generator.
+fn next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+#[test]
+fn test_random_terrain_matches_find_path() {
+    for seed in 1..=12u64 {
+        let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let mut grid = HexGrid::new_boxy(24, 20, 1);
+
+        // A quarter of the hexes are walls, the rest cost 1 to 3.
+        for col in 0..24 {
+            for row in 0..20 {
+                let value = match next(&mut state) % 12 {
+                    0..=2 => 0,
+                    roll => 1 + (roll % 3) as u32,
+                };
+
+                grid.set(MultiCoord::offset(col, row), value);
+            }
+        }
+
+        let hpa = HpaHexGrid::new(grid.map(|_, &hex| hex), 5, cost);
+
+        for _ in 0..100 {
+            let mut pick = || {
+                let col = (next(&mut state) % 24) as i32;
+                let row = (next(&mut state) % 20) as i32;
+
+                MultiCoord::offset(col, row)
+            };
+            let (start, goal) = (pick(), pick());
+            let path = hpa.find_path(start, goal);
+            let best = grid.find_path(start, goal, cost);
+
+            assert_eq!(
+                path.is_some(),
+                best.is_some(),
+                "{} to {}",
+                start,
+                goal
+            );
+
+            if let (Some(path), Some(best)) = (path, best) {
+                let dist = grid.cube_of(start).dist(grid.cube_of(goal));
+
+                assert_eq!(path.coords.first(), Some(&start));
+                assert_eq!(path.coords.last(), Some(&goal));
+                assert_valid(&grid, &path);
+                assert!(path.cost >= best.cost);
+
+                // Nearby queries whose cheapest path stays close are exact.
+                if dist <= 5 && best.cost <= dist as u64 + 10 {
+                    assert_eq!(path.cost, best.cost, "{} to {}", start, goal);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Integration tests for `chickenwire::influence`.
+
+use chickenwire::influence::Falloff;
+use chickenwire::prelude::*;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn test_falloff_factor() {
+    assert!(close(Falloff::Linear(4.0).factor(1.0), 0.75));
+    assert!(close(Falloff::Linear(4.0).factor(6.0), 0.0));
+    assert!(close(Falloff::Linear(0.0).factor(0.0), 1.0));
+    assert!(close(Falloff::Exponential(0.5).factor(3.0), 0.125));
+    assert!(close(Falloff::Exponential(2.0).factor(3.0), 1.0));
+}
+
+#[test]
+fn test_influence_resistance_and_walls() {
+    let mut grid = HexGrid::new_boxy(7, 1, '.');
+
+    grid.set(MultiCoord::offset(2, 0), '^');
+    grid.set(MultiCoord::offset(5, 0), '#');
+
+    let mut map = grid.influence_map(Falloff::Linear(10.0), |_, &hex| {
+        match hex {
+            '^' => Some(3.0),
+            '#' => None,
+            _ => Some(1.0),
+        }
+    });
+
+    map.set_source(MultiCoord::offset(0, 0), 10.0);
+    map.set_source(MultiCoord::offset(40, 0), 10.0);
+    map.propagate();
+
+    assert_eq!(map.source(MultiCoord::offset(40, 0)), None);
+    assert_eq!(map.influence(MultiCoord::offset(40, 0)), None);
+    assert!(close(map.influence(MultiCoord::offset(1, 0)).unwrap(), 9.0));
+
+    // The hills cost three hexes of reach, and the wall blocks the rest.
+    assert!(close(map.influence(MultiCoord::offset(2, 0)).unwrap(), 6.0));
+    assert!(close(map.influence(MultiCoord::offset(4, 0)).unwrap(), 4.0));
+    assert_eq!(map.influence(MultiCoord::offset(5, 0)), Some(0.0));
+    assert_eq!(map.influence(MultiCoord::offset(6, 0)), Some(0.0));
+
+    map.set_resistance(MultiCoord::offset(5, 0), Some(1.0));
+    map.propagate();
+
+    assert!(close(map.influence(MultiCoord::offset(6, 0)).unwrap(), 2.0));
+    assert_eq!(map.values().iter().count(), 7);
+}
+
+#[test]
+fn test_influence_sources_sum_and_tick() {
+    let grid = HexGrid::new_radial(3, ());
+    let mut map =
+        grid.influence_map(Falloff::Exponential(0.5), |_, _| Some(1.0));
+    let origin = MultiCoord::axial(0, 0);
+
+    map.set_source(MultiCoord::axial(1, 0), 4.0);
+    map.set_source(MultiCoord::axial(-1, 0), -2.0);
+    map.propagate();
+
+    assert!(close(map.influence(origin).unwrap(), 1.0));
+
+    // Influence weaker than the threshold doesn't spread.
+    map.threshold = 1.5;
+    map.propagate();
+
+    assert!(close(map.influence(origin).unwrap(), 2.0));
+    assert_eq!(map.influence(MultiCoord::axial(3, 0)), Some(0.0));
+
+    map.threshold = 0.01;
+    map.momentum = 0.75;
+    map.set_source(MultiCoord::axial(-1, 0), 0.0);
+    map.tick();
+
+    // Three quarters of the old value, and a quarter of the new.
+    assert!(close(map.influence(origin).unwrap(), 2.0));
+    assert!(close(map.influence(MultiCoord::axial(-1, 0)).unwrap(), -1.25));
+
+    map.clear_sources();
+
+    for _ in 0..40 {
+        map.tick();
+    }
+
+    assert!(map.values().iter().all(|(_, &value)| value == 0.0));
+}
+
+#[test]
+fn test_influence_merging() {
+    let grid = HexGrid::new_boxy(5, 1, ());
+    let resistance = |_, _: &()| Some(1.0);
+    let mut ours = grid.influence_map(Falloff::Linear(4.0), resistance);
+    let mut theirs = grid.influence_map(Falloff::Linear(4.0), resistance);
+
+    ours.set_source(MultiCoord::offset(0, 0), 4.0);
+    theirs.set_source(MultiCoord::offset(4, 0), 8.0);
+    ours.propagate();
+    theirs.propagate();
+
+    let (balance, tension, vulnerability) = (
+        ours.balance(&theirs),
+        ours.tension(&theirs),
+        ours.vulnerability(&theirs),
+    );
+    let mid = MultiCoord::offset(2, 0);
+
+    assert!(close(balance[mid], -2.0));
+    assert!(close(tension[mid], 6.0));
+    assert!(close(vulnerability[mid], 4.0));
+    assert!(close(theirs.balance(&ours)[mid], 2.0));
+    assert_eq!(balance.iter().count(), 5);
+}
@@ -0,0 +1,120 @@
+//! Integration tests for `chickenwire::movement`.
+
+use chickenwire::movement::Reach;
+use chickenwire::prelude::*;
+
+#[test]
+fn test_movement_range_costs() {
+    let mut grid = HexGrid::new_radial(3, 1);
+
+    grid.set(MultiCoord::axial(1, 0), 3);
+    grid.set(MultiCoord::axial(-1, 0), 0);
+
+    let origin = MultiCoord::axial(0, 0);
+    let cost = |_, &hex: &u32| if hex == 0 { None } else { Some(hex) };
+    let range = grid.movement_range(origin, 2, cost, |_| false);
+
+    // Every hex within two steps, less the wall, the costly hex, and the
+    // hexes straight beyond them.
+    assert_eq!(range.iter().count(), 19 - 4);
+    assert_eq!(range.get(origin), Some(&0));
+    assert_eq!(range.get(MultiCoord::axial(1, 0)), None);
+    assert_eq!(range.get(MultiCoord::axial(-1, 0)), None);
+    assert_eq!(range.get(MultiCoord::axial(2, 0)), None);
+    assert_eq!(range.get(MultiCoord::axial(1, 1)), Some(&2));
+    assert!(range.iter().all(|(_, &spent)| spent <= 2));
+    assert_eq!(
+        grid.movement_range(MultiCoord::axial(9, 0), 2, cost, |_| false)
+            .iter()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_movement_range_zone_of_control() {
+    let grid = HexGrid::new_radial(4, ());
+    let enemy = grid.cube_of(MultiCoord::axial(2, 0));
+    let zoc = |coord| grid.cube_of(coord).dist(enemy) <= 1;
+    let free = grid.movement_range(
+        MultiCoord::axial(0, 0),
+        4,
+        |_, _| Some(1),
+        |_| false,
+    );
+    let held = grid.movement_range(
+        MultiCoord::axial(0, 0),
+        4,
+        |_, _| Some(1),
+        zoc,
+    );
+
+    // Hexes beside the enemy end movement, so those beyond it must be
+    // walked around to.
+    assert_eq!(free.get(MultiCoord::axial(3, 0)), Some(&3));
+    assert_eq!(held.get(MultiCoord::axial(1, 0)), Some(&1));
+    assert_eq!(held.get(MultiCoord::axial(2, 0)), None);
+    assert_eq!(held.get(MultiCoord::axial(4, 0)), None);
+
+    // Units may leave a zone of control they start in.
+    let leaving = grid.movement_range(
+        MultiCoord::axial(1, 0),
+        1,
+        |_, _| Some(1),
+        zoc,
+    );
+
+    assert_eq!(leaving.iter().count(), 7);
+}
+
+#[test]
+fn test_find_path_zoc() {
+    let grid = HexGrid::new_boxy(6, 3, ());
+    let (start, goal) = (MultiCoord::offset(0, 1), MultiCoord::offset(2, 0));
+    let enemy = grid.cube_of(MultiCoord::offset(3, 1));
+    let zoc = |coord| grid.cube_of(coord).dist(enemy) <= 1;
+
+    // Stopping in a zone of control is fine.
+    let path = grid.find_path_zoc(start, goal, |_, _| Some(1), zoc).unwrap();
+
+    assert_eq!(path.coords.last(), Some(&goal));
+    assert_eq!(path.cost, 2);
+
+    // But passing through one isn't, unless starting in it.
+    let far = MultiCoord::offset(5, 1);
+
+    assert_eq!(grid.find_path_zoc(start, far, |_, _| Some(1), zoc), None);
+    assert!(grid.find_path(start, far, |_, _| Some(1)).is_some());
+    assert!(grid
+        .find_path_zoc(MultiCoord::offset(2, 1), start, |_, _| Some(1), zoc)
+        .is_some());
+}
+
+#[test]
+fn test_threat_map() {
+    let grid = HexGrid::new_boxy(8, 1, '.');
+    let units = [
+        (MultiCoord::offset(0, 0), Reach::new(2, 1)),
+        (MultiCoord::offset(7, 0), Reach::new(1, 2)),
+        (MultiCoord::offset(20, 0), Reach::new(9, 9)),
+    ];
+    let threats = grid.threat_map(&units, |_, _| Some(1), |_, _| false);
+
+    for col in 0..8 {
+        let expected = if col <= 3 { vec![0] } else { vec![1] };
+
+        assert_eq!(threats.get(MultiCoord::offset(col, 0)), Some(&expected));
+    }
+
+    let units = [
+        (MultiCoord::offset(0, 0), Reach::new(4, 1)),
+        (MultiCoord::offset(7, 0), Reach::new(4, 1)),
+    ];
+    // Only the first unit is hindered by its enemy's zone of control.
+    let zoc = |unit, coord| unit == 0 && coord == MultiCoord::offset(2, 0);
+    let threats = grid.threat_map(&units, |_, _| Some(1), zoc);
+
+    assert_eq!(threats.get(MultiCoord::offset(1, 0)), Some(&vec![0]));
+    assert_eq!(threats.get(MultiCoord::offset(3, 0)), Some(&vec![0, 1]));
+    assert_eq!(threats.get(MultiCoord::offset(4, 0)), Some(&vec![1]));
+}
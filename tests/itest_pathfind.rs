@@ -0,0 +1,127 @@
+//! Integration tests for `chickenwire::pathfind`.
+
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+#[test]
+fn test_find_path_prefers_cheap_hexes() {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+
+    for col in 0..6 {
+        for row in 0..3 {
+            let cost = if row == 1 { 5 } else { 1 };
+
+            grid.set(MultiCoord::offset(col, row), cost);
+        }
+    }
+
+    let (start, goal) = (MultiCoord::offset(0, 1), MultiCoord::offset(5, 1));
+    let path = grid.find_path(start, goal, |_, &c| Some(c)).unwrap();
+
+    // Skirting the costly middle row beats crossing it.
+    assert_eq!(path.cost, 10);
+    assert_eq!(path.coords.first(), Some(&MultiCoord::offset(0, 1)));
+    assert_eq!(path.coords.last(), Some(&MultiCoord::offset(5, 1)));
+    assert!(path.coords[1..path.coords.len() - 1]
+        .iter()
+        .all(|&coord| grid[coord] == 1));
+}
+
+#[test]
+fn test_find_path_edge_cases() {
+    let mut grid = HexGrid::new_radial(2, 0);
+    let origin = MultiCoord::axial(0, 0);
+
+    let path = grid.find_path(origin, origin, |_, _| Some(1)).unwrap();
+
+    assert_eq!(path.coords, vec![origin]);
+    assert_eq!(path.cost, 0);
+    assert_eq!(
+        grid.find_path(origin, MultiCoord::axial(5, 0), |_, _| Some(1)),
+        None
+    );
+
+    for cube in Cube::<i32>::ORIGIN.ring(1) {
+        grid.set(MultiCoord::from(cube), 1);
+    }
+
+    let walled = |_, &hex: &i32| if hex == 1 { None } else { Some(0) };
+
+    assert_eq!(grid.find_path(origin, MultiCoord::axial(2, 0), walled), None);
+    let around = grid
+        .find_path(MultiCoord::axial(2, 0), MultiCoord::axial(-2, 0), walled)
+        .unwrap();
+
+    assert_eq!(around.cost, 6);
+}
+
+/// Fills a hexagon of the given radius with walls at about the given
+/// percentage of hexes, placed by a seeded generator.
+fn maze(radius: u32, percent: u64, seed: u64) -> HexGrid<bool> {
+    let mut state = seed;
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Even, CoordSys::Offset);
+
+    for cube in Cube::<i32>::ORIGIN.spiral(radius) {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        grid.set(MultiCoord::from(cube), (state >> 33) % 100 >= percent);
+    }
+
+    grid
+}
+
+#[test]
+fn test_find_path_uniform_matches_astar() {
+    let wall = |_, &open: &bool| if open { Some(1) } else { None };
+
+    for seed in 0..20 {
+        let grid = maze(8, 10 + seed % 4 * 10, seed);
+        let corners: Vec<MultiCoord> = Cube::<i32>::ORIGIN
+            .ring(8)
+            .into_iter()
+            .step_by(8)
+            .map(|cube| grid.convert(MultiCoord::from(cube), CoordSys::Offset))
+            .collect();
+
+        for &start in &corners {
+            for &goal in &corners {
+                let uniform = grid.find_path_uniform(start, goal, |_, &o| o);
+                let astar = grid.find_path(start, goal, wall);
+
+                assert_eq!(
+                    uniform.as_ref().map(|path| path.cost),
+                    astar.map(|path| path.cost)
+                );
+
+                if let Some(path) = uniform {
+                    assert_eq!(path.coords.len() as u64, path.cost + 1);
+                    assert_eq!(path.coords[0], start);
+                    assert!(path.coords[1..].iter().all(|&coord| grid[coord]));
+                    assert!(path.coords.windows(2).all(|pair| {
+                        grid.cube_of(pair[0]).dist(grid.cube_of(pair[1])) == 1
+                    }));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_find_path_uniform_edge_cases() {
+    let grid = maze(3, 0, 0);
+    let origin = MultiCoord::offset(0, 0);
+
+    let path = grid.find_path_uniform(origin, origin, |_, _| true).unwrap();
+
+    assert_eq!(path.coords, vec![origin]);
+    assert_eq!(path.cost, 0);
+    assert_eq!(
+        grid.find_path_uniform(origin, MultiCoord::offset(9, 0), |_, _| true),
+        None
+    );
+    assert_eq!(
+        grid.find_path_uniform(origin, MultiCoord::offset(3, 0), |_, _| false),
+        None
+    );
+}
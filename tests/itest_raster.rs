@@ -0,0 +1,92 @@
+//! Integration tests for `chickenwire::geometry` and `chickenwire::raster`.
+
+use chickenwire::geometry;
+use chickenwire::hexgrid::Tilt;
+use chickenwire::prelude::*;
+use chickenwire::raster::Layout;
+
+#[test]
+fn test_hex_at_inverts_center() {
+    for &tilt in [Tilt::Flat, Tilt::Sharp].iter() {
+        for coord in Cube::<i32>::ORIGIN.spiral(6) {
+            let (x, y) = geometry::center(tilt, 7.5, coord);
+
+            for &(dx, dy) in [(0.0, 0.0), (3.0, -2.0), (-2.5, 3.0)].iter() {
+                assert_eq!(
+                    geometry::hex_at(tilt, 7.5, (x + dx, y + dy)),
+                    Some(coord),
+                    "{:?}",
+                    tilt
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_render_raster() {
+    let mut grid = HexGrid::default();
+
+    grid.tilt = Tilt::Sharp;
+    grid.set(MultiCoord::axial(0, 0), [200, 0, 0, 255]);
+
+    let mut layout = Layout::new(10.0, (15.0, 15.0));
+
+    layout.border_width = 4.0;
+    layout.border_color = [0, 0, 255, 255];
+
+    let raster = grid.render_raster(30, 30, &layout, |&color| color);
+
+    assert_eq!((raster.width(), raster.height()), (30, 30));
+    assert_eq!(raster.pixels().len(), 30 * 30 * 4);
+    assert_eq!(raster.pixel(15, 15), [200, 0, 0, 255]);
+
+    // A sharp hex's right edge is vertical, its inradius out from center,
+    // and half of the border lies inside it.
+    let edge = 15.0 + 5.0 * 3f64.sqrt();
+
+    assert_eq!(raster.pixel(edge as u32 - 1, 15), [0, 0, 255, 255]);
+
+    // The top corner of the hex is only partly covered.
+    let [_, _, _, alpha] = raster.pixel(15, 5);
+
+    assert!(alpha > 0 && alpha < 255, "{}", alpha);
+    assert_eq!(raster.pixel(0, 0), [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_write_ppm_blends_alpha() {
+    let grid = HexGrid::new_radial(1, [0, 0, 0, 128]);
+    let mut layout = Layout::new(4.0, (2.0, 2.0));
+
+    layout.border_width = 0.0;
+
+    let raster = grid.render_raster(1, 1, &layout, |&color| color);
+    let mut ppm = Vec::new();
+
+    raster.write_ppm(&mut ppm, [255, 255, 255]).unwrap();
+
+    assert_eq!(ppm, b"P6\n1 1\n255\n\x7f\x7f\x7f".to_vec());
+}
+
+#[test]
+fn test_degenerate_layouts() {
+    assert_eq!(geometry::hex_at(Tilt::Flat, 1.0, (1e12, 3e11)), None);
+    assert_eq!(geometry::hex_at(Tilt::Sharp, f64::NAN, (0.0, 0.0)), None);
+    assert_eq!(geometry::hex_at(Tilt::Flat, -1.0, (0.0, 0.0)), None);
+
+    let grid = HexGrid::new_radial(1, [9, 9, 9, 255]);
+    let empty = |raster: &chickenwire::raster::Raster| {
+        raster.pixels().iter().all(|&byte| byte == 0)
+    };
+
+    for &layout in [
+        Layout::new(0.0, (2.0, 2.0)),
+        Layout::new(f64::INFINITY, (2.0, 2.0)),
+        Layout::new(1.0, (1e12, 3e11)),
+    ]
+    .iter()
+    {
+        assert!(empty(&grid.render_raster(4, 4, &layout, |&color| color)));
+    }
+}
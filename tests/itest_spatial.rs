@@ -0,0 +1,101 @@
+//! Integration tests for `chickenwire::spatial`.
+
+use chickenwire::prelude::*;
+use chickenwire::spatial::HexSpatialIndex;
+
+fn cube(x: i32, y: i32) -> Cube {
+    Cube::from_coords(x, y, -x - y).unwrap()
+}
+
+#[test]
+fn test_spatial_buckets() {
+    let mut index = HexSpatialIndex::new();
+
+    assert!(index.is_empty());
+    assert_eq!(index.insert("a", cube(1, 0)), None);
+    assert_eq!(index.insert("b", cube(1, 0)), None);
+    assert_eq!(index.insert("c", cube(0, 1)), None);
+    assert_eq!(index.len(), 3);
+    assert_eq!(index.occupied(), 2);
+    assert_eq!(index.at(cube(1, 0)), &["a", "b"]);
+
+    // Reinserting moves the entity to the back of its new hex.
+    assert_eq!(index.insert("a", cube(0, 1)), Some(cube(1, 0)));
+    assert_eq!(index.at(cube(0, 1)), &["c", "a"]);
+    assert_eq!(index.move_to(&"b", cube(0, 1)), Some(cube(1, 0)));
+    assert_eq!(index.occupied(), 1);
+    assert_eq!(index.remove(&"c"), Some(cube(0, 1)));
+    assert_eq!(index.remove(&"c"), None);
+    assert_eq!(index.position(&"b"), Some(cube(0, 1)));
+    assert!(!index.contains_key(&"c"));
+    assert_eq!(index.iter().count(), 2);
+
+    index.clear();
+
+    assert!(index.is_empty());
+    assert_eq!(index.occupied(), 0);
+}
+
+#[test]
+fn test_spatial_within_matches_distances() {
+    // Queries walk the spiral when few hexes are in range, and scan the
+    // occupied hexes otherwise; both must agree with a brute force search.
+    let mut index = HexSpatialIndex::new();
+    let hexes = Cube::ORIGIN.spiral(6);
+
+    for (key, &hex) in hexes.iter().enumerate().filter(|(i, _)| i % 3 == 0) {
+        index.insert(key, hex);
+    }
+
+    let center = cube(2, -1);
+
+    for radius in 0..10 {
+        let found = index.within(center, radius);
+        let mut expected: Vec<usize> = index
+            .iter()
+            .filter(|&(_, hex)| hex.dist(center) <= radius as i32)
+            .map(|(&key, _)| key)
+            .collect();
+        let mut actual: Vec<usize> = found.iter().map(|&&key| key).collect();
+
+        assert!(found
+            .windows(2)
+            .all(|pair| index.position(pair[0]).unwrap().dist(center)
+                <= index.position(pair[1]).unwrap().dist(center)));
+
+        expected.sort_unstable();
+        actual.sort_unstable();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_spatial_nearest_k() {
+    let mut index = HexSpatialIndex::new();
+
+    index.insert('a', cube(0, 0));
+    index.insert('b', cube(3, 0));
+    index.insert('c', cube(-1, 0));
+    index.insert('d', cube(30, -30));
+
+    assert_eq!(index.nearest_k(cube(0, 0), 0), Vec::<&char>::new());
+    assert_eq!(index.nearest_k(cube(0, 0), 3), vec![&'a', &'c', &'b']);
+    assert_eq!(index.nearest_k(cube(0, 0), 9).len(), 4);
+    assert_eq!(index.nearest_k(cube(29, -28), 2), vec![&'d', &'b']);
+    assert!(HexSpatialIndex::<char>::new()
+        .nearest_k(cube(0, 0), 2)
+        .is_empty());
+}
+
+#[test]
+fn test_spatial_far_apart_hexes() {
+    let mut index = HexSpatialIndex::new();
+    let far = Cube::from_coords(i32::MAX, i32::MIN, 1).unwrap();
+
+    index.insert('a', cube(0, 0));
+    index.insert('z', far);
+
+    assert_eq!(index.within(Cube::ORIGIN, u32::MAX), vec![&'a']);
+    assert_eq!(index.nearest_k(Cube::ORIGIN, 2), vec![&'a', &'z']);
+    assert_eq!(index.nearest_k(far, 1), vec![&'z']);
+}
@@ -0,0 +1,69 @@
+//! Integration tests for `chickenwire::svg`.
+
+use chickenwire::hexgrid::Tilt;
+use chickenwire::prelude::*;
+use chickenwire::svg::{Overlay, Style};
+
+fn style(value: &u32) -> Style {
+    match value {
+        0 => Style::new("white", "black"),
+        _ => Style::new("gray", "black").with_label(&value.to_string()),
+    }
+}
+
+#[test]
+fn test_svg_is_deterministic() {
+    let mut first = HexGrid::new_radial(3, 0);
+    let mut second = HexGrid::new_radial(3, 0);
+
+    first.set(MultiCoord::axial(1, 1), 7);
+    second.set(MultiCoord::axial(1, 1), 7);
+
+    assert_eq!(
+        first.to_svg(12.0, style, &[Overlay::CoordLabels]),
+        second.to_svg(12.0, style, &[Overlay::CoordLabels])
+    );
+}
+
+#[test]
+fn test_svg_styles_and_overlays() {
+    let mut grid = HexGrid::new_radial(1, 0);
+
+    grid.tilt = Tilt::Sharp;
+    grid.set(MultiCoord::axial(0, 0), 4);
+
+    let overlays = [
+        Overlay::Region {
+            coords: vec![MultiCoord::axial(0, 0), MultiCoord::axial(5, 0)],
+            fill: "rgba(255,0,0,0.5)".to_string(),
+        },
+        Overlay::Path {
+            coords: vec![MultiCoord::axial(0, 0), MultiCoord::axial(5, 0)],
+            stroke: "red".to_string(),
+        },
+    ];
+    let svg = grid.to_svg(10.0, style, &overlays);
+
+    assert_eq!(svg.matches("fill=\"white\"").count(), 6);
+    assert_eq!(svg.matches("fill=\"gray\"").count(), 1);
+    assert!(svg.contains(">4</text>"));
+    assert!(svg.contains("<g class=\"region\">"));
+    assert!(svg.ends_with("</svg>\n"));
+
+    // The viewBox stretches to fit hexes drawn only by overlays.
+    let sqrt_3 = 3f64.sqrt();
+    let width = format!("width=\"{:.2}\"", 7.0 * 10.0 * sqrt_3);
+
+    assert!(svg.contains(&width), "{}", svg.lines().next().unwrap());
+}
+
+#[test]
+fn test_svg_empty_grid() {
+    let grid: HexGrid<u32> = HexGrid::default();
+
+    assert_eq!(
+        grid.to_svg(10.0, style, &[]),
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 0 0\" \
+         width=\"0\" height=\"0\">\n<g class=\"hexes\">\n</g>\n</svg>\n"
+    );
+}
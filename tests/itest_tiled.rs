@@ -0,0 +1,154 @@
+//! Integration tests for `chickenwire::tiled`.
+
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+use chickenwire::tiled::{self, TileLayer, TiledMap, Tileset};
+
+const TMX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" tiledversion="1.10.2" orientation="hexagonal"
+     renderorder="right-down" width="4" height="3" tilewidth="32"
+     tileheight="28" infinite="0" hexsidelength="16" staggeraxis="x"
+     staggerindex="even" nextlayerid="4" nextobjectid="1">
+ <!-- Exported from Tiled -->
+ <tileset firstgid="1" source="terrain &amp; water.tsx"/>
+ <tileset firstgid="65" source="units.tsx"/>
+ <layer id="1" name="Ground" width="4" height="3">
+  <data encoding="csv">
+1,1,2,2,
+3,0,2,2,
+3,3,3,2147483650
+</data>
+ </layer>
+ <objectgroup id="2" name="Spawns"/>
+ <layer id="3" name="Units" width="4" height="3">
+  <data encoding="base64">
+   AAAAAEEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABCAAAA
+  </data>
+ </layer>
+</map>
+"#;
+
+#[test]
+fn test_stagger_mapping_round_trips() {
+    for &tilt in [Tilt::Flat, Tilt::Sharp].iter() {
+        for &parity in [Parity::Odd, Parity::Even].iter() {
+            let (axis, index) = tiled::stagger_from_layout(tilt, parity);
+
+            assert_eq!(
+                tiled::layout_from_stagger(axis, index),
+                Ok((tilt, parity))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_read_tmx() {
+    let map = TiledMap::from_tmx(TMX).unwrap();
+
+    assert_eq!((map.tilt, map.parity), (Tilt::Flat, Parity::Even));
+    assert_eq!((map.width, map.height), (4, 3));
+    assert_eq!((map.tile_width, map.tile_height), (32, 28));
+    assert_eq!(map.hex_side_length, 16);
+    assert_eq!(
+        map.tilesets[0],
+        Tileset {
+            first_gid: 1,
+            source: "terrain & water.tsx".to_string(),
+        }
+    );
+    assert_eq!(map.layers.len(), 2);
+
+    let ground = &map.layers[0];
+
+    assert_eq!(ground.name, "Ground");
+    assert_eq!(ground.grid.iter().count(), 11);
+    assert_eq!(ground.grid.get(MultiCoord::offset(1, 1)), None);
+    assert_eq!(ground.grid.get(MultiCoord::offset(0, 2)), Some(&3));
+    // Flip flags are kept as part of the gid.
+    assert_eq!(ground.grid.get(MultiCoord::offset(3, 2)), Some(&0x8000_0002));
+
+    let units = &map.layers[1];
+
+    assert_eq!(units.grid.iter().count(), 2);
+    assert_eq!(units.grid.get(MultiCoord::offset(1, 0)), Some(&65));
+    assert_eq!(units.grid.get(MultiCoord::offset(3, 2)), Some(&66));
+}
+
+#[test]
+fn test_tmx_and_tmj_round_trip() {
+    let map = TiledMap::from_tmx(TMX).unwrap();
+    let tmx = map.to_tmx();
+    let tmj = map.to_tmj();
+
+    assert_eq!(TiledMap::from_tmx(&tmx).unwrap().to_tmx(), tmx);
+    assert_eq!(TiledMap::from_tmj(&tmj).unwrap().to_tmj(), tmj);
+    assert_eq!(TiledMap::from_tmj(&tmj).unwrap().to_tmx(), tmx);
+    assert!(tmx.contains("<data encoding=\"csv\">\n1,1,2,2,\n3,0,2,2,\n"));
+}
+
+#[test]
+fn test_layers_are_placed_by_position() {
+    let mut map = TiledMap::new(Tilt::Sharp, Parity::Odd, 3, 3);
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Cube);
+
+    // Offset (1, 1) of an odd-row grid, plus a hex outside the map.
+    grid.set(MultiCoord::force_cube(1, -2, 1), 9);
+    grid.set(MultiCoord::force_cube(-5, 5, 0), 4);
+    map.layers.push(TileLayer {
+        name: "\"Quoted\"".to_string(),
+        grid,
+    });
+
+    let read = TiledMap::from_tmj(&map.to_tmj()).unwrap();
+
+    assert_eq!(read.layers[0].name, "\"Quoted\"");
+    assert_eq!(read.layers[0].grid.iter().count(), 1);
+    assert_eq!(read.layers[0].grid.get(MultiCoord::offset(1, 1)), Some(&9));
+}
+
+#[test]
+fn test_rejection_messages() {
+    let errors = [
+        (TMX.replace("hexagonal", "isometric"), "Unsupported orientation"),
+        (TMX.replace("infinite=\"0\"", "infinite=\"1\""), "Infinite maps"),
+        (TMX.replace("staggeraxis=\"x\"", "staggeraxis=\"z\""), "Invalid"),
+        (
+            TMX.replace("source=\"units.tsx\"", "name=\"units\""),
+            "Embedded tilesets",
+        ),
+        (
+            TMX.replace("encoding=\"base64\"", "encoding=\"base64\" \
+                         compression=\"zlib\""),
+            "Unsupported compression",
+        ),
+        (TMX.replace("3,3,3,", "3,3,"), "Layer \"Ground\" has 11 tiles"),
+        (TMX.replace("</map>", ""), "Unexpected end of XML"),
+    ];
+
+    for (text, message) in errors.iter() {
+        let error = TiledMap::from_tmx(text).unwrap_err();
+
+        assert!(error.starts_with(message), "{}", error);
+    }
+
+    assert_eq!(
+        TiledMap::from_tmj("{\"orientation\": \"hexagonal\",}").unwrap_err(),
+        "Malformed JSON at byte 28"
+    );
+}
+
+#[test]
+fn test_deep_nesting_is_rejected() {
+    let xml = "<a>".repeat(100_000);
+    let json = "[".repeat(100_000);
+
+    assert_eq!(
+        TiledMap::from_tmx(&xml).unwrap_err(),
+        "XML nested over 64 elements deep at byte 192"
+    );
+    assert_eq!(
+        TiledMap::from_tmj(&json).unwrap_err(),
+        "JSON nested over 64 values deep at byte 64"
+    );
+}
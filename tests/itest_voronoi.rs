@@ -0,0 +1,87 @@
+//! Integration tests for `chickenwire::voronoi`.
+
+use chickenwire::hexgrid::{Parity, Tilt};
+use chickenwire::prelude::*;
+
+#[test]
+fn test_voronoi_nearest_seed() {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Even, CoordSys::Offset);
+
+    for col in 0..8 {
+        for row in 0..6 {
+            grid.set(MultiCoord::offset(col, row), ());
+        }
+    }
+
+    let seeds = [
+        MultiCoord::offset(1, 1),
+        MultiCoord::offset(6, 4),
+        MultiCoord::axial(40, 40),
+    ];
+    let regions = grid.voronoi(&seeds);
+    let cubes: Vec<Cube> = seeds
+        .iter()
+        .map(|&seed| grid.cube_of(seed))
+        .collect();
+
+    assert_eq!(regions.iter().count(), 48);
+    assert_eq!(regions.sys, CoordSys::Offset);
+
+    for (coord, &index) in regions.iter() {
+        let cube = grid.cube_of(coord);
+        let best = cubes.iter().map(|&seed| cube.dist(seed)).min().unwrap();
+        let first = cubes.iter().position(|&seed| cube.dist(seed) == best);
+
+        assert_eq!(Some(index), first);
+    }
+}
+
+#[test]
+fn test_voronoi_without_seeds() {
+    let grid = HexGrid::new_radial(2, 0);
+
+    assert_eq!(grid.voronoi(&[]).iter().count(), 0);
+    assert_eq!(grid.voronoi_weighted(&[], |_, _| Some(1)).iter().count(), 0);
+}
+
+#[test]
+fn test_voronoi_weighted_matches_unweighted_on_flat_ground() {
+    let grid = HexGrid::new_radial(5, ());
+    let seeds = [
+        MultiCoord::axial(-3, 1),
+        MultiCoord::axial(2, 2),
+        MultiCoord::axial(1, -3),
+    ];
+    let plain = grid.voronoi(&seeds);
+    let grown = grid.voronoi_weighted(&seeds, |_, _| Some(1));
+
+    assert_eq!(grown.iter().count(), plain.iter().count());
+    assert!(grown.diff(&plain).is_empty());
+}
+
+#[test]
+fn test_voronoi_weighted_walls_and_duplicates() {
+    let mut grid = HexGrid::new_radial(3, 1);
+
+    // A wall of impassable hexes around the center cuts it off.
+    for cube in Cube::<i32>::ORIGIN.ring(1) {
+        grid.set(MultiCoord::from(cube), 0);
+    }
+
+    let seeds = [
+        MultiCoord::axial(3, 0),
+        MultiCoord::axial(3, 0),
+        MultiCoord::axial(-3, 0),
+        MultiCoord::axial(9, 9),
+    ];
+    let regions = grid.voronoi_weighted(&seeds, |_, &cost| match cost {
+        0 => None,
+        cost => Some(cost),
+    });
+
+    assert_eq!(regions.iter().count(), 37 - 7);
+    assert!(!regions.contains_coord(MultiCoord::axial(0, 0)));
+    assert!(regions.iter().all(|(_, &index)| index == 0 || index == 2));
+    assert_eq!(regions[MultiCoord::axial(2, 0)], 0);
+    assert_eq!(regions[MultiCoord::axial(-2, 0)], 2);
+}
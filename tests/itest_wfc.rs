@@ -0,0 +1,182 @@
+//! Integration tests for `chickenwire::wfc`.
+
+use chickenwire::hexgrid::{Compass, Parity, Tilt};
+use chickenwire::prelude::*;
+use chickenwire::wfc::{CollapseOptions, TileSet};
+
+use Compass::*;
+
+const SIDES: [Compass; 6] =
+    [Northeast, East, Southeast, Southwest, West, Northwest];
+
+/// A `Tilt::Sharp` tile set of grass, water, and shores in every rotation.
+fn shores() -> TileSet<char> {
+    let mut tiles = TileSet::new(Tilt::Sharp);
+    let uniform = |socket| -> Vec<(Compass, &str)> {
+        SIDES.iter().map(|&dir| (dir, socket)).collect()
+    };
+
+    tiles.add('.', 4.0, &uniform("grass")).unwrap();
+    tiles.add('~', 4.0, &uniform("water")).unwrap();
+
+    let shore: Vec<(Compass, &str)> = SIDES
+        .iter()
+        .enumerate()
+        .map(|(i, &dir)| (dir, if i < 2 { "grass" } else { "water" }))
+        .collect();
+
+    tiles.add_rotations('/', 1.0, &shore).unwrap();
+    tiles
+}
+
+/// Returns a `Tilt::Sharp` hexagon of hexes within `radius` of the origin.
+fn hexagon(radius: u32) -> HexGrid<()> {
+    let mut grid = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Axial);
+    let spiral = Cube::<i32>::ORIGIN.spiral(radius);
+
+    grid.fill_region(spiral.into_iter().map(MultiCoord::from), ());
+    grid
+}
+
+/// Asserts that every pair of neighboring tiles has matching sockets.
+fn assert_matches(tiles: &TileSet<char>, map: &HexGrid<usize>) {
+    for (coord, &tile) in map.iter() {
+        let cube = map.cube_of(coord);
+
+        for side in 0..6 {
+            let next = MultiCoord::from(cube.neighbor(side));
+
+            if let Some(&other) = map.get(next) {
+                assert_eq!(
+                    tiles.get(tile).unwrap().sockets()[side],
+                    tiles.get(other).unwrap().sockets()[(side + 3) % 6],
+                    "mismatch between {} and {}",
+                    coord,
+                    next
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tile_set_errors() {
+    let mut tiles: TileSet<()> = TileSet::new(Tilt::Sharp);
+    let mut sockets: Vec<(Compass, &str)> =
+        SIDES.iter().map(|&dir| (dir, "a")).collect();
+
+    assert_eq!(tiles.add((), 0.0, &sockets), Err(
+        "Tile weights must be positive".to_string()
+    ));
+
+    sockets[0] = (North, "a");
+    assert_eq!(
+        tiles.add((), 1.0, &sockets),
+        Err("North isn't a side of a Sharp hex".to_string())
+    );
+
+    sockets[0] = (East, "a");
+    assert_eq!(
+        tiles.add((), 1.0, &sockets),
+        Err("Tile has two sockets facing East".to_string())
+    );
+
+    sockets.remove(0);
+    assert_eq!(
+        tiles.add_rotations((), 1.0, &sockets),
+        Err("Tile has no socket facing Northeast".to_string())
+    );
+    assert!(tiles.is_empty());
+    assert_eq!(shores().len(), 8);
+}
+
+#[test]
+fn test_collapse_matches_sockets_and_is_seeded() {
+    let tiles = shores();
+    let mut shape = HexGrid::new(Tilt::Sharp, Parity::Odd, CoordSys::Offset);
+
+    for col in 0..9 {
+        for row in 0..7 {
+            shape.set(MultiCoord::offset(col, row), 0u8);
+        }
+    }
+
+    let options = CollapseOptions {
+        seed: 99,
+        ..CollapseOptions::default()
+    };
+    let map = shape.collapse(&tiles, &options).unwrap();
+    let again = shape.collapse(&tiles, &options).unwrap();
+
+    assert_eq!(map.iter().count(), 63);
+    assert_eq!(map.sys, CoordSys::Offset);
+    assert!(map.diff(&again).is_empty());
+    assert_matches(&tiles, &map);
+}
+
+#[test]
+fn test_collapse_respects_fixed_hexes() {
+    let tiles = shores();
+    let shape = hexagon(4);
+    let fixed = vec![
+        (MultiCoord::axial(0, 0), 1),
+        (MultiCoord::axial(-3, 0), 0),
+        (MultiCoord::axial(3, -3), 0),
+    ];
+
+    for seed in 0..5 {
+        let options = CollapseOptions {
+            seed,
+            fixed: fixed.clone(),
+            ..CollapseOptions::default()
+        };
+        let map = shape.collapse(&tiles, &options).unwrap();
+
+        for &(coord, tile) in fixed.iter() {
+            assert_eq!(map[coord], tile);
+        }
+
+        assert_matches(&tiles, &map);
+    }
+}
+
+#[test]
+fn test_collapse_errors() {
+    let tiles = shores();
+    let shape = hexagon(2);
+    let fixed = |fixed| CollapseOptions {
+        fixed,
+        ..CollapseOptions::default()
+    };
+
+    // Grass and water can't touch without a shore between them.
+    let touching = vec![
+        (MultiCoord::axial(0, 0), 0),
+        (MultiCoord::axial(1, 0), 1),
+    ];
+
+    assert_eq!(
+        shape.collapse(&tiles, &fixed(touching)).unwrap_err(),
+        "Tiles can't fill the grid"
+    );
+    assert_eq!(
+        shape
+            .collapse(&tiles, &fixed(vec![(MultiCoord::axial(5, 0), 0)]))
+            .unwrap_err(),
+        "Grid contains no value at axial(5,0)"
+    );
+    assert_eq!(
+        shape
+            .collapse(&tiles, &fixed(vec![(MultiCoord::axial(0, 0), 8)]))
+            .unwrap_err(),
+        "No tile with index 8"
+    );
+
+    let mut flat = shape;
+
+    flat.tilt = Tilt::Flat;
+    assert_eq!(
+        flat.collapse(&tiles, &CollapseOptions::default()).unwrap_err(),
+        "Tiles are for Sharp hexes, but the grid is Flat"
+    );
+}